@@ -78,6 +78,9 @@ class DeviceSettingsQuickPresetsTest {
         hasTouchTone = true,
         hasAutoPowerOff = true,
         dynamicRangeCompressionMinFirmwareVersion = FirmwareVersion(0u, 1u),
+        hasGameMode = true,
+        hasLdac = true,
+        hasVoicePrompt = true,
     )
     private var deviceModel = "0123"
 
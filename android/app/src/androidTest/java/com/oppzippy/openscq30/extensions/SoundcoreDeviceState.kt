@@ -19,6 +19,9 @@ fun DeviceState.Companion.empty(): DeviceState = DeviceState(
         hasTouchTone = false,
         hasAutoPowerOff = false,
         dynamicRangeCompressionMinFirmwareVersion = null,
+        hasGameMode = false,
+        hasLdac = false,
+        hasVoicePrompt = false,
     ),
     serialNumber = null,
     firmwareVersion = null,
@@ -31,4 +34,5 @@ fun DeviceState.Companion.empty(): DeviceState = DeviceState(
     buttonConfiguration = null,
     hearId = null,
     ambientSoundModeCycle = null,
+    autoPowerOff = null,
 )
@@ -1,9 +1,12 @@
 package com.oppzippy.openscq30.features.soundcoredevice.impl
 
 import android.annotation.SuppressLint
+import com.oppzippy.openscq30.lib.bindings.NativeConnectionStatus
+import com.oppzippy.openscq30.lib.bindings.NativeConnectionStatusObserver
 import com.oppzippy.openscq30.lib.bindings.NativeDeviceStateObserver
 import com.oppzippy.openscq30.lib.bindings.NativeSoundcoreDevice
 import com.oppzippy.openscq30.lib.wrapper.AmbientSoundModeCycle
+import com.oppzippy.openscq30.lib.wrapper.AutoPowerOff
 import com.oppzippy.openscq30.lib.wrapper.DeviceState
 import com.oppzippy.openscq30.lib.wrapper.EqualizerConfiguration
 import com.oppzippy.openscq30.lib.wrapper.HearId
@@ -31,6 +34,8 @@ class SoundcoreDevice(
     val stateFlow: StateFlow<DeviceState> = _stateFlow.asStateFlow()
     private val _isDisconnected = MutableStateFlow(false)
     val isDisconnected = _isDisconnected.asStateFlow()
+    private val _isReconnecting = MutableStateFlow(false)
+    val isReconnecting = _isReconnecting.asStateFlow()
 
     init {
         coroutineScope.launch {
@@ -42,6 +47,20 @@ class SoundcoreDevice(
                 },
             )
         }
+        coroutineScope.launch {
+            nativeDevice.connectionStatus(
+                object : NativeConnectionStatusObserver {
+                    override fun onStatusChanged(connectionStatus: NativeConnectionStatus) {
+                        // While reconnecting, the device is kept around rather than closed
+                        _isReconnecting.value =
+                            connectionStatus == NativeConnectionStatus.RECONNECTING
+                        if (connectionStatus == NativeConnectionStatus.DISCONNECTED) {
+                            _isDisconnected.value = true
+                        }
+                    }
+                },
+            )
+        }
     }
 
     override fun close() {
@@ -73,4 +92,8 @@ class SoundcoreDevice(
     suspend fun setMultiButtonConfiguration(buttonConfiguration: MultiButtonConfiguration) {
         nativeDevice.setMultiButtonConfiguration(buttonConfiguration)
     }
+
+    suspend fun setAutoPowerOff(autoPowerOff: AutoPowerOff) {
+        nativeDevice.setAutoPowerOff(autoPowerOff)
+    }
 }
@@ -0,0 +1,40 @@
+package com.oppzippy.openscq30.lib.wrapper
+
+import com.oppzippy.openscq30.lib.protobuf.autoPowerOff
+
+data class AutoPowerOff(
+    val isEnabled: Boolean,
+    val duration: AutoPowerOffDuration,
+) {
+    fun toProtobuf(): com.oppzippy.openscq30.lib.protobuf.AutoPowerOff = autoPowerOff {
+        isEnabled = this@AutoPowerOff.isEnabled
+        duration = this@AutoPowerOff.duration.toProtobuf()
+    }
+}
+
+fun com.oppzippy.openscq30.lib.protobuf.AutoPowerOff.toKotlin(): AutoPowerOff = AutoPowerOff(
+    isEnabled = isEnabled,
+    duration = duration.toKotlin(),
+)
+
+enum class AutoPowerOffDuration {
+    ThirtyMinutes,
+    SixtyMinutes,
+    NinetyMinutes,
+    OneHundredTwentyMinutes,
+    ;
+
+    fun toProtobuf(): com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration = when (this) {
+        ThirtyMinutes -> com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration.AUTO_POWER_OFF_DURATION_THIRTY_MINUTES
+        SixtyMinutes -> com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration.AUTO_POWER_OFF_DURATION_SIXTY_MINUTES
+        NinetyMinutes -> com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration.AUTO_POWER_OFF_DURATION_NINETY_MINUTES
+        OneHundredTwentyMinutes -> com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration.AUTO_POWER_OFF_DURATION_ONE_HUNDRED_TWENTY_MINUTES
+    }
+}
+
+fun com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration.toKotlin(): AutoPowerOffDuration = when (this) {
+    com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration.AUTO_POWER_OFF_DURATION_THIRTY_MINUTES -> AutoPowerOffDuration.ThirtyMinutes
+    com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration.AUTO_POWER_OFF_DURATION_SIXTY_MINUTES -> AutoPowerOffDuration.SixtyMinutes
+    com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration.AUTO_POWER_OFF_DURATION_NINETY_MINUTES -> AutoPowerOffDuration.NinetyMinutes
+    com.oppzippy.openscq30.lib.protobuf.AutoPowerOffDuration.AUTO_POWER_OFF_DURATION_ONE_HUNDRED_TWENTY_MINUTES -> AutoPowerOffDuration.OneHundredTwentyMinutes
+}
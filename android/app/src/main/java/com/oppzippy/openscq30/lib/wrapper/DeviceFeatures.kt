@@ -20,6 +20,9 @@ data class DeviceFeatures(
     val hasTouchTone: Boolean,
     val hasAutoPowerOff: Boolean,
     val dynamicRangeCompressionMinFirmwareVersion: FirmwareVersion?,
+    val hasGameMode: Boolean,
+    val hasLdac: Boolean,
+    val hasVoicePrompt: Boolean,
 ) {
     fun toProtobuf(): ProtobufDeviceFeatures = deviceFeatures {
         this@DeviceFeatures.availableSoundModes?.let { availableSoundModes = it.toProtobuf() }
@@ -34,6 +37,9 @@ data class DeviceFeatures(
         this@DeviceFeatures.dynamicRangeCompressionMinFirmwareVersion?.let {
             dynamicRangeCompressionMinFirmwareVersion = it.toProtobuf()
         }
+        hasGameMode = this@DeviceFeatures.hasGameMode
+        hasLdac = this@DeviceFeatures.hasLdac
+        hasVoicePrompt = this@DeviceFeatures.hasVoicePrompt
     }
 }
 
@@ -48,6 +54,9 @@ fun ProtobufDeviceFeatures.toKotlin(): DeviceFeatures = DeviceFeatures(
     hasTouchTone = hasTouchTone,
     hasAutoPowerOff = hasAutoPowerOff,
     dynamicRangeCompressionMinFirmwareVersion = dynamicRangeCompressionMinFirmwareVersionOrNull?.toKotlin(),
+    hasGameMode = hasGameMode,
+    hasLdac = hasLdac,
+    hasVoicePrompt = hasVoicePrompt,
 )
 
 data class AvailableSoundModes(
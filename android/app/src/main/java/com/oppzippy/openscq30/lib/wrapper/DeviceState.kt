@@ -1,6 +1,7 @@
 package com.oppzippy.openscq30.lib.wrapper
 
 import com.oppzippy.openscq30.lib.protobuf.ambientSoundModeCycleOrNull
+import com.oppzippy.openscq30.lib.protobuf.autoPowerOffOrNull
 import com.oppzippy.openscq30.lib.protobuf.buttonConfigurationOrNull
 import com.oppzippy.openscq30.lib.protobuf.deviceState
 import com.oppzippy.openscq30.lib.protobuf.firmwareVersionOrNull
@@ -21,6 +22,7 @@ data class DeviceState(
     val firmwareVersion: FirmwareVersion?,
     val serialNumber: String?,
     val ambientSoundModeCycle: AmbientSoundModeCycle?,
+    val autoPowerOff: AutoPowerOff?,
 ) {
     companion object // used for static extension methods in tests
 
@@ -54,6 +56,7 @@ data class DeviceState(
         this@DeviceState.firmwareVersion?.let { firmwareVersion = it.toProtobuf() }
         this@DeviceState.serialNumber?.let { serialNumber = it }
         this@DeviceState.ambientSoundModeCycle?.let { ambientSoundModeCycle = it.toProtobuf() }
+        this@DeviceState.autoPowerOff?.let { autoPowerOff = it.toProtobuf() }
     }
 }
 
@@ -70,4 +73,5 @@ fun com.oppzippy.openscq30.lib.protobuf.DeviceState.toKotlin(): DeviceState = De
     firmwareVersion = firmwareVersionOrNull?.toKotlin(),
     serialNumber = if (hasSerialNumber()) serialNumber else null,
     ambientSoundModeCycle = ambientSoundModeCycleOrNull?.toKotlin(),
+    autoPowerOff = autoPowerOffOrNull?.toKotlin(),
 )
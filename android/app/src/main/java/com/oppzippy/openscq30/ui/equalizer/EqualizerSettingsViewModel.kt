@@ -10,6 +10,8 @@ import com.oppzippy.openscq30.lib.bindings.volumeAdjustmentsMinVolume
 import com.oppzippy.openscq30.lib.wrapper.EqualizerConfiguration
 import com.oppzippy.openscq30.ui.devicesettings.models.UiDeviceState
 import com.oppzippy.openscq30.ui.equalizer.models.EqualizerProfile
+import com.oppzippy.openscq30.ui.equalizer.models.PRESET_PROFILE_BANDS
+import com.oppzippy.openscq30.ui.equalizer.models.withExtraBandsFrom
 import dagger.hilt.android.lifecycle.HiltViewModel
 import java.math.BigDecimal
 import java.math.RoundingMode
@@ -36,7 +38,7 @@ class EqualizerSettingsViewModel @Inject constructor(private val customProfileDa
     val selectedCustomProfile = _selectedCustomProfile.asStateFlow()
     private val _customProfiles = MutableStateFlow<List<CustomProfile>>(listOf())
     val customProfiles = _customProfiles.asStateFlow()
-    private val _valueTexts = MutableStateFlow(List(8) { "" })
+    private val _valueTexts = MutableStateFlow(List(PRESET_PROFILE_BANDS) { "" })
     val valueTexts = _valueTexts.asStateFlow()
 
     var setRealEqualizerConfiguration: (
@@ -105,7 +107,8 @@ class EqualizerSettingsViewModel @Inject constructor(private val customProfileDa
     fun selectCustomProfile(customProfile: CustomProfile) {
         setDisplayedEqualizerConfiguration(
             EqualizerProfile.Custom,
-            customProfile.getVolumeAdjustments(),
+            customProfile.getVolumeAdjustments()
+                .withExtraBandsFrom(_displayedEqualizerConfiguration.value?.volumeAdjustments),
         )
     }
 
@@ -180,7 +183,8 @@ class EqualizerSettingsViewModel @Inject constructor(private val customProfileDa
         _selectedCustomProfile.value =
             if (equalizerConfiguration.presetProfile == null) {
                 _customProfiles.value.find {
-                    it.getVolumeAdjustments() == equalizerConfiguration.volumeAdjustments
+                    it.getVolumeAdjustments() ==
+                        equalizerConfiguration.volumeAdjustments.take(PRESET_PROFILE_BANDS)
                 }
             } else {
                 null
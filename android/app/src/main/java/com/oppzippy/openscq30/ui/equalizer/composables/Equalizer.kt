@@ -9,9 +9,13 @@ import androidx.compose.runtime.mutableStateOf
 import androidx.compose.runtime.remember
 import androidx.compose.runtime.setValue
 import androidx.compose.ui.Modifier
+import androidx.compose.ui.res.stringResource
 import androidx.compose.ui.tooling.preview.Preview
 import androidx.compose.ui.unit.dp
+import com.oppzippy.openscq30.R
+import com.oppzippy.openscq30.ui.equalizer.models.PRESET_PROFILE_BANDS
 import com.oppzippy.openscq30.ui.theme.OpenSCQ30Theme
+import java.math.BigDecimal
 import kotlin.math.pow
 import kotlin.math.roundToInt
 
@@ -22,8 +26,8 @@ fun Equalizer(
     texts: List<String>,
     onTextChanged: (index: Int, value: String) -> Unit,
 ) {
-    if (values.size != 8 || texts.size != 8) {
-        throw IllegalArgumentException("There must be exactly 8 values")
+    if (values.size != texts.size) {
+        throw IllegalArgumentException("There must be a text for each value")
     }
     LazyColumn(
         userScrollEnabled = true,
@@ -31,7 +35,7 @@ fun Equalizer(
         values.forEachIndexed { index, value ->
             item {
                 EqualizerSlider(
-                    hz = (100 * 2F.pow(index)).roundToInt(),
+                    label = bandLabel(index),
                     value = value,
                     onValueChange = {
                         onValueChange(index, it)
@@ -47,6 +51,21 @@ fun Equalizer(
     }
 }
 
+@Composable
+private fun bandLabel(index: Int): String {
+    // The official app only displays 8 bands, so the frequencies of any past that are unknown
+    if (index >= PRESET_PROFILE_BANDS) {
+        return stringResource(R.string.band_number, index + 1)
+    }
+    // Starts at 100hz
+    val hz = (100 * 2F.pow(index)).roundToInt()
+    return if (hz < 1000) {
+        stringResource(R.string.hz, hz)
+    } else {
+        stringResource(R.string.khz, BigDecimal(hz).divide(BigDecimal(1000)).toString())
+    }
+}
+
 @Preview(showBackground = true)
 @Composable
 private fun PreviewEqualizer() {
@@ -10,21 +10,18 @@ import androidx.compose.material3.TextField
 import androidx.compose.runtime.Composable
 import androidx.compose.ui.Modifier
 import androidx.compose.ui.platform.testTag
-import androidx.compose.ui.res.stringResource
 import androidx.compose.ui.text.input.KeyboardType
 import androidx.compose.ui.tooling.preview.Preview
 import androidx.compose.ui.unit.dp
-import com.oppzippy.openscq30.R
 import com.oppzippy.openscq30.lib.bindings.volumeAdjustmentsMaxVolume
 import com.oppzippy.openscq30.lib.bindings.volumeAdjustmentsMinVolume
 import com.oppzippy.openscq30.lib.bindings.volumeAdjustmentsStep
 import com.oppzippy.openscq30.ui.theme.OpenSCQ30Theme
-import java.math.BigDecimal
 import kotlin.math.roundToInt
 
 @Composable
 fun EqualizerSlider(
-    hz: Int,
+    label: String,
     value: Double,
     onValueChange: (value: Double) -> Unit,
     text: String,
@@ -39,18 +36,7 @@ fun EqualizerSlider(
                     .testTag("equalizerInput")
                     .width(100.dp),
                 singleLine = true,
-                label = {
-                    if (hz < 1000) {
-                        Text(stringResource(R.string.hz, hz))
-                    } else {
-                        Text(
-                            stringResource(
-                                R.string.khz,
-                                BigDecimal(hz).divide(BigDecimal(1000)).toString(),
-                            ),
-                        )
-                    }
-                },
+                label = { Text(label) },
                 onValueChange = onTextChange,
             )
             Slider(
@@ -73,6 +59,6 @@ fun EqualizerSlider(
 @Composable
 private fun PreviewEqualizerSlider() {
     OpenSCQ30Theme {
-        EqualizerSlider(hz = 100, value = 0.0, onValueChange = {}, text = "0", onTextChange = {})
+        EqualizerSlider(label = "100 Hz", value = 0.0, onValueChange = {}, text = "0", onTextChange = {})
     }
 }
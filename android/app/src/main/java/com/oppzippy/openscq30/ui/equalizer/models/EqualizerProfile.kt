@@ -7,6 +7,15 @@ import com.oppzippy.openscq30.lib.extensions.resources.toStringResource
 import com.oppzippy.openscq30.lib.wrapper.EqualizerConfiguration
 import com.oppzippy.openscq30.lib.wrapper.PresetEqualizerProfile
 
+/**
+ * Preset and custom profiles only cover the first 8 bands, so devices with more than that keep their own values for the
+ * rest.
+ */
+const val PRESET_PROFILE_BANDS = 8
+
+fun List<Double>.withExtraBandsFrom(current: List<Double>?): List<Double> =
+    this + (current?.drop(PRESET_PROFILE_BANDS) ?: emptyList())
+
 enum class EqualizerProfile(val presetProfile: PresetEqualizerProfile?) {
     Custom(null),
     SoundcoreSignature(PresetEqualizerProfile.SoundcoreSignature),
@@ -39,7 +48,9 @@ enum class EqualizerProfile(val presetProfile: PresetEqualizerProfile?) {
         }
 
     fun toEqualizerConfiguration(volumeAdjustments: List<Double>?): EqualizerConfiguration =
-        presetProfile?.toEqualizerConfiguration()
+        presetProfile?.toEqualizerConfiguration()?.let {
+            it.copy(volumeAdjustments = it.volumeAdjustments.withExtraBandsFrom(volumeAdjustments))
+        }
             ?: if (volumeAdjustments != null) {
                 EqualizerConfiguration(volumeAdjustments = volumeAdjustments)
             } else {
@@ -2,13 +2,13 @@ use std::sync::Arc;
 
 use macaddr::MacAddr6;
 use openscq30_lib::{
-    api::device::Device,
+    api::{connection::ConnectionStatus, device::Device},
     demo::device::DemoDevice,
     devices::standard::{
         state::DeviceState,
         structures::{
-            AmbientSoundModeCycle, EqualizerConfiguration, HearId, MultiButtonConfiguration,
-            SoundModes, SoundModesTypeTwo,
+            AmbientSoundModeCycle, AutoPowerOff, EqualizerConfiguration, HearId,
+            MultiButtonConfiguration, SoundModes, SoundModesTypeTwo,
         },
     },
     futures::TokioFutures,
@@ -36,9 +36,27 @@ pub trait NativeDeviceStateObserver: Send + Sync {
     fn on_state_changed(&self, device_state: DeviceState);
 }
 
+#[derive(uniffi::Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NativeConnectionStatus {
+    Connected,
+    Disconnected,
+    /// The connection was lost and is being re-established, so the device should not be closed yet.
+    Reconnecting,
+}
+
+impl From<ConnectionStatus> for NativeConnectionStatus {
+    fn from(connection_status: ConnectionStatus) -> Self {
+        match connection_status {
+            ConnectionStatus::Connected => Self::Connected,
+            ConnectionStatus::Disconnected => Self::Disconnected,
+            ConnectionStatus::Reconnecting => Self::Reconnecting,
+        }
+    }
+}
+
 #[uniffi::export(callback_interface)]
 pub trait NativeConnectionStatusObserver: Send + Sync {
-    fn on_status_changed(&self, connection_status: Vec<u8>);
+    fn on_status_changed(&self, connection_status: NativeConnectionStatus);
 }
 
 #[derive(uniffi::Object)]
@@ -123,7 +141,15 @@ impl NativeSoundcoreDevice {
             .unwrap()
     }
 
-    pub fn connection_status(&self, _observer: Box<dyn NativeConnectionStatusObserver>) {}
+    pub fn connection_status(&self, observer: Box<dyn NativeConnectionStatusObserver>) {
+        let mut receiver = self.device.connection_status();
+        self.runtime.spawn(async move {
+            observer.on_status_changed((*receiver.borrow_and_update()).into());
+            while receiver.changed().await.is_ok() {
+                observer.on_status_changed((*receiver.borrow_and_update()).into());
+            }
+        });
+    }
 
     pub async fn state(&self) -> DeviceState {
         let device = self.device.clone();
@@ -224,6 +250,23 @@ impl NativeSoundcoreDevice {
             .await
             .unwrap()
     }
+
+    pub async fn set_auto_power_off(
+        &self,
+        auto_power_off: AutoPowerOff,
+    ) -> Result<(), DeviceError> {
+        let device = self.device.clone();
+
+        self.runtime
+            .spawn(async move {
+                device
+                    .set_auto_power_off(auto_power_off)
+                    .await
+                    .map_err(DeviceError::from)
+            })
+            .await
+            .unwrap()
+    }
 }
 
 // Dynamic dispatch does not work with async functions in traits
@@ -254,6 +297,13 @@ impl DeviceImplementation {
         }
     }
 
+    pub fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
+        match self {
+            DeviceImplementation::Manual(device) => device.connection_status(),
+            DeviceImplementation::Demo(device) => device.connection_status(),
+        }
+    }
+
     pub fn service_uuid(&self) -> Uuid {
         match self {
             DeviceImplementation::Manual(device) => device.service_uuid(),
@@ -338,4 +388,14 @@ impl DeviceImplementation {
             }
         }
     }
+
+    pub async fn set_auto_power_off(
+        &self,
+        auto_power_off: AutoPowerOff,
+    ) -> openscq30_lib::Result<()> {
+        match self {
+            DeviceImplementation::Manual(device) => device.set_auto_power_off(auto_power_off).await,
+            DeviceImplementation::Demo(device) => device.set_auto_power_off(auto_power_off).await,
+        }
+    }
 }
@@ -10,15 +10,17 @@ use macaddr::MacAddr6;
 use openscq30_lib::devices::standard::{
     state::DeviceState,
     structures::{
-        AmbientSoundModeCycle, EqualizerConfiguration, HearId, MultiButtonConfiguration,
-        PresetEqualizerProfile, SoundModes, SoundModesTypeTwo,
+        AmbientSoundModeCycle, AutoPowerOff, EqualizerConfiguration, HearId,
+        MultiButtonConfiguration, PresetEqualizerProfile, SoundModes, SoundModesTypeTwo,
     },
 };
 use openscq30_lib_protobuf::{
-    deserialize_ambient_sound_mode_cycle, deserialize_equalizer_configuration, deserialize_hear_id,
+    deserialize_ambient_sound_mode_cycle, deserialize_auto_power_off,
+    deserialize_equalizer_configuration, deserialize_hear_id,
     deserialize_multi_button_configuration, deserialize_preset_equalizer_profile,
     deserialize_sound_modes, deserialize_sound_modes_type_two, serialize_ambient_sound_mode_cycle,
-    serialize_device_state, serialize_equalizer_configuration, serialize_preset_equalizer_profile,
+    serialize_auto_power_off, serialize_device_state, serialize_equalizer_configuration,
+    serialize_preset_equalizer_profile,
 };
 use uuid::Uuid;
 
@@ -131,6 +133,22 @@ impl UniffiCustomTypeConverter for AmbientSoundModeCycle {
     }
 }
 
+uniffi::custom_type!(AutoPowerOff, Vec<u8>);
+impl UniffiCustomTypeConverter for AutoPowerOff {
+    type Builtin = Vec<u8>;
+
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(deserialize_auto_power_off(&val)?)
+    }
+
+    fn from_custom(obj: Self) -> Self::Builtin {
+        serialize_auto_power_off(obj)
+    }
+}
+
 uniffi::custom_type!(EqualizerConfiguration, Vec<u8>);
 impl UniffiCustomTypeConverter for EqualizerConfiguration {
     type Builtin = Vec<u8>;
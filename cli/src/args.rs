@@ -1,5 +1,9 @@
+mod auto_power_off;
 mod sound_modes;
+mod voice_prompt;
 mod volume_adjustments;
 
+pub use auto_power_off::*;
 pub use sound_modes::*;
+pub use voice_prompt::*;
 pub use volume_adjustments::*;
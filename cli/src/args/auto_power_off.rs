@@ -0,0 +1,25 @@
+use clap::ValueEnum;
+use openscq30_lib::devices::standard::structures::AutoPowerOffDuration as LibAutoPowerOffDuration;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum AutoPowerOff {
+    Disabled,
+    ThirtyMinutes,
+    SixtyMinutes,
+    NinetyMinutes,
+    OneHundredTwentyMinutes,
+}
+
+impl AutoPowerOff {
+    pub fn duration(&self) -> Option<LibAutoPowerOffDuration> {
+        match self {
+            AutoPowerOff::Disabled => None,
+            AutoPowerOff::ThirtyMinutes => Some(LibAutoPowerOffDuration::ThirtyMinutes),
+            AutoPowerOff::SixtyMinutes => Some(LibAutoPowerOffDuration::SixtyMinutes),
+            AutoPowerOff::NinetyMinutes => Some(LibAutoPowerOffDuration::NinetyMinutes),
+            AutoPowerOff::OneHundredTwentyMinutes => {
+                Some(LibAutoPowerOffDuration::OneHundredTwentyMinutes)
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use clap::ValueEnum;
+use openscq30_lib::devices::standard::structures::VoicePrompt as LibVoicePrompt;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum VoicePrompt {
+    English,
+    Chinese,
+}
+
+impl From<VoicePrompt> for LibVoicePrompt {
+    fn from(value: VoicePrompt) -> Self {
+        match value {
+            VoicePrompt::English => LibVoicePrompt::English,
+            VoicePrompt::Chinese => LibVoicePrompt::Chinese,
+        }
+    }
+}
@@ -11,6 +11,17 @@ impl VolumeAdjustments {
         let max = (LibVolumeAdjustments::MAX_VOLUME * 10.0).round() as i64;
         min..=max
     }
+
+    /// Devices have either the 8 bands covered by preset profiles or those plus two extra bands.
+    /// clap can only limit the number of values to a range, so [`Self::is_valid_number_of_bands`]
+    /// rules out 9 after parsing.
+    pub fn valid_number_of_bands() -> RangeInclusive<usize> {
+        8..=10
+    }
+
+    pub fn is_valid_number_of_bands(number_of_bands: usize) -> bool {
+        number_of_bands == 8 || number_of_bands == 10
+    }
 }
 
 impl From<VolumeAdjustments> for LibVolumeAdjustments {
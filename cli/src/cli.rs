@@ -1,6 +1,7 @@
 use crate::args::*;
 use clap::{command, Parser, Subcommand, ValueEnum};
 use macaddr::MacAddr6;
+use std::path::PathBuf;
 use tracing::Level;
 
 #[derive(Parser)]
@@ -10,6 +11,8 @@ pub struct Cli {
     pub mac_address: Option<MacAddr6>,
     #[arg(short, long, default_value_t = Level::WARN)]
     pub logging_level: Level,
+    #[arg(long, help = "Use demo devices instead of bluetooth devices")]
+    pub demo: bool,
     #[command(subcommand)]
     pub command: Command,
 }
@@ -21,6 +24,15 @@ pub enum Command {
     #[command(subcommand)]
     Get(GetCommand),
     ListDevices,
+    #[command(
+        about = "Breaks inbound packets down field by field, either from hex or from a capture file"
+    )]
+    Dissect {
+        #[arg(required_unless_present = "capture", conflicts_with = "capture")]
+        hex: Option<String>,
+        #[arg(long)]
+        capture: Option<PathBuf>,
+    },
     Completions {
         #[arg(required = true)]
         shell: Shell,
@@ -71,11 +83,55 @@ pub enum SetCommand {
     Equalizer {
         #[arg(
             required=true,
-            num_args = 8,
+            num_args = VolumeAdjustments::valid_number_of_bands(),
             value_parser = clap::value_parser!(i16).range(VolumeAdjustments::range()),
         )]
         volume_adjustments: Vec<i16>,
     },
+    #[command(about = "Sets each channel separately on devices with stereo equalizers")]
+    StereoEqualizer {
+        #[arg(
+            long,
+            required = true,
+            num_args = VolumeAdjustments::valid_number_of_bands(),
+            allow_negative_numbers = true,
+            value_parser = clap::value_parser!(i16).range(VolumeAdjustments::range()),
+        )]
+        left: Vec<i16>,
+        #[arg(
+            long,
+            required = true,
+            num_args = VolumeAdjustments::valid_number_of_bands(),
+            allow_negative_numbers = true,
+            value_parser = clap::value_parser!(i16).range(VolumeAdjustments::range()),
+        )]
+        right: Vec<i16>,
+    },
+    WearDetection {
+        #[arg(action = clap::ArgAction::Set)]
+        is_enabled: bool,
+    },
+    TouchTone {
+        #[arg(action = clap::ArgAction::Set)]
+        is_enabled: bool,
+    },
+    AutoPowerOff {
+        #[arg(value_enum)]
+        duration: AutoPowerOff,
+    },
+    GameMode {
+        #[arg(action = clap::ArgAction::Set)]
+        is_enabled: bool,
+    },
+    Ldac {
+        #[arg(action = clap::ArgAction::Set)]
+        is_enabled: bool,
+    },
+    #[command(about = "Sets the language of the earbuds' voice prompts")]
+    VoicePrompt {
+        #[arg(value_enum)]
+        language: VoicePrompt,
+    },
 }
 
 #[derive(Subcommand)]
@@ -88,4 +144,17 @@ pub enum GetCommand {
     #[command(about = "Only meaningful if noise-canceling-mode is manual")]
     ManualNoiseCanceling,
     Equalizer,
+    #[command(about = "Prints the left channel followed by the right channel")]
+    StereoEqualizer,
+    WearDetection,
+    TouchTone,
+    AutoPowerOff,
+    GameMode,
+    Ldac,
+    ChargingCaseBattery,
+    #[command(about = "Prints left and right versions separately when the device reports both")]
+    FirmwareVersion,
+    #[command(about = "Prints whether both earbuds are connected and which one is the host")]
+    TwsStatus,
+    VoicePrompt,
 }
@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use anyhow::Context;
+use openscq30_lib::{capture::Capture, dissector};
+
+pub fn dissect(hex: Option<&str>, capture: Option<&Path>) -> anyhow::Result<()> {
+    match (hex, capture) {
+        (Some(hex), _) => {
+            let dissections = dissector::dissect_hex(hex)?;
+            let output = dissections
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n");
+            print!("{output}");
+        }
+        (None, Some(path)) => {
+            let capture = std::fs::read_to_string(path)
+                .with_context(|| format!("reading {}", path.display()))?
+                .parse::<Capture>()?;
+            let output = dissector::dissect_capture(&capture)
+                .into_iter()
+                .map(|(entry, dissection)| {
+                    format!(
+                        "{}ms {}\n{dissection}",
+                        entry.timestamp.as_millis(),
+                        entry.direction.as_ref(),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            print!("{output}");
+        }
+        (None, None) => unreachable!("clap requires either hex or a capture"),
+    }
+    Ok(())
+}
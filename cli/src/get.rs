@@ -1,7 +1,10 @@
 use anyhow::bail;
 use heck::AsKebabCase;
 use itertools::Itertools;
-use openscq30_lib::{api::device::Device, devices::standard::structures::VolumeAdjustments};
+use openscq30_lib::{
+    api::device::Device,
+    devices::standard::structures::{HostDevice, VolumeAdjustments},
+};
 
 use crate::cli::GetCommand;
 
@@ -62,6 +65,84 @@ pub async fn get(get_command: GetCommand, device: &impl Device) -> anyhow::Resul
         GetCommand::Equalizer => {
             print_volume_adjustments(device_state.equalizer_configuration.volume_adjustments())
         }
+        GetCommand::StereoEqualizer => {
+            let Some(stereo) = device_state.stereo_equalizer_configuration() else {
+                bail!("stereo equalizer not supported by device");
+            };
+            print_volume_adjustments(stereo.left.volume_adjustments());
+            print_volume_adjustments(stereo.right.volume_adjustments());
+        }
+        GetCommand::WearDetection => {
+            let Some(wear_detection) = device_state.wear_detection else {
+                bail!("wear detection not supported by device");
+            };
+            println!("{wear_detection}");
+        }
+        GetCommand::TouchTone => {
+            let Some(touch_tone) = device_state.touch_tone else {
+                bail!("touch tone not supported by device");
+            };
+            println!("{touch_tone}");
+        }
+        GetCommand::AutoPowerOff => {
+            let Some(auto_power_off) = device_state.auto_power_off else {
+                bail!("auto power off not supported by device");
+            };
+            if auto_power_off.is_enabled {
+                println!("{}", AsKebabCase(auto_power_off.duration.as_ref()));
+            } else {
+                println!("disabled");
+            }
+        }
+        GetCommand::GameMode => {
+            let Some(game_mode) = device_state.game_mode else {
+                bail!("game mode not supported by device");
+            };
+            println!("{game_mode}");
+        }
+        GetCommand::Ldac => {
+            let Some(ldac) = device_state.ldac else {
+                bail!("LDAC not supported by device");
+            };
+            println!("{ldac}");
+        }
+        GetCommand::ChargingCaseBattery => {
+            let Some(level) = device_state.charging_case_battery_level else {
+                bail!("charging case battery level not reported by device");
+            };
+            println!("{}", level.0);
+        }
+        GetCommand::FirmwareVersion => {
+            let Some(version) = device_state.dual_firmware_version else {
+                bail!("firmware version not reported by device");
+            };
+            if version.left == version.right {
+                println!("{}", version.left);
+            } else {
+                println!("{} {}", version.left, version.right);
+            }
+        }
+        GetCommand::TwsStatus => {
+            let Some(tws_status) = device_state.tws_status else {
+                bail!("tws status not reported by device");
+            };
+            let connection = if tws_status.is_connected {
+                "connected"
+            } else {
+                "disconnected"
+            };
+            let host_device = match tws_status.host_device {
+                HostDevice::Left => "left",
+                HostDevice::Right => "right",
+            };
+            println!("{connection} {host_device}");
+        }
+        GetCommand::VoicePrompt => {
+            let Some(voice_prompt) = device_state.voice_prompt else {
+                bail!("voice prompt not reported by device");
+            };
+            println!("{}", AsKebabCase(voice_prompt.as_ref()));
+        }
     };
     Ok(())
 }
@@ -7,6 +7,7 @@ use openscq30_lib::api::device::{DeviceDescriptor, DeviceRegistry};
 
 mod args;
 mod cli;
+mod dissect;
 mod get;
 mod list_devices;
 mod set;
@@ -25,12 +26,17 @@ fn main() -> Result<(), Box<dyn Error>> {
         );
         return Ok(());
     }
+    if let Command::Dissect { hex, capture } = &args.command {
+        dissect::dissect(hex.as_deref(), capture.as_deref())?;
+        return Ok(());
+    }
 
     let runtime = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .unwrap();
-    runtime.block_on(async {
+    // Some device features spawn tasks that aren't Send, which requires a LocalSet
+    tokio::task::LocalSet::new().block_on(&runtime, async {
         tracing_subscriber::fmt()
             .with_file(true)
             .with_line_number(true)
@@ -40,10 +46,19 @@ fn main() -> Result<(), Box<dyn Error>> {
             .pretty()
             .init();
 
-        let registry =
-            openscq30_lib::api::new_soundcore_device_registry(runtime.handle().to_owned())
-                .await
-                .unwrap_or_else(|err| panic!("failed to initialize device registry: {err}"));
+        #[cfg(feature = "bluetooth")]
+        let registry = openscq30_lib::api::new_soundcore_device_registry(
+            runtime.handle().to_owned(),
+            args.demo,
+        )
+        .await
+        .unwrap_or_else(|err| panic!("failed to initialize device registry: {err}"));
+        #[cfg(not(feature = "bluetooth"))]
+        let registry = if args.demo {
+            openscq30_lib::api::new_demo_device_registry::<openscq30_lib::futures::TokioFutures>()
+        } else {
+            return Err("built without bluetooth support, so only --demo is available".into());
+        };
 
         let descriptors = registry.device_descriptors().await?;
         let selected_descriptor = args
@@ -66,7 +81,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                 let device = get_device_or_err(&registry, descriptor).await?;
                 get::get(get_command, device.as_ref()).await?;
             }
-            (Command::Completions { .. }, Some(_)) => unreachable!(),
+            (Command::Completions { .. } | Command::Dissect { .. }, Some(_)) => unreachable!(),
             (_, None) => eprintln!("No device found."),
         };
         Ok(())
@@ -2,11 +2,15 @@ use anyhow::bail;
 use openscq30_lib::{
     api::device::Device,
     devices::standard::structures::{
-        EqualizerConfiguration, SoundModes, SoundModesTypeTwo, VolumeAdjustments,
+        AutoPowerOff, EqualizerConfiguration, SoundModes, SoundModesTypeTwo,
+        StereoEqualizerConfiguration, VolumeAdjustments,
     },
 };
 
-use crate::{args::NoiseCancelingModeKind, cli::SetCommand};
+use crate::{
+    args::{self, NoiseCancelingModeKind},
+    cli::SetCommand,
+};
 
 pub async fn set(set_command: SetCommand, device: &impl Device) -> anyhow::Result<()> {
     let device_state = device.state().await;
@@ -89,23 +93,81 @@ pub async fn set(set_command: SetCommand, device: &impl Device) -> anyhow::Resul
                 .await?;
         }
         SetCommand::Equalizer { volume_adjustments } => {
-            let adjustment_array: [i16; 8] =
-                volume_adjustments
-                    .try_into()
-                    .unwrap_or_else(|values: Vec<_>| {
-                        panic!(
-                            "error converting vec of volume adjustments to array: expected len 8, got {}",
-                            values.len(),
-                        )
-                    });
-            let float_adjustments = adjustment_array.map(|adjustment| (adjustment as f64) / 10.0);
-
             device
                 .set_equalizer_configuration(EqualizerConfiguration::new_custom_profile(
-                    VolumeAdjustments::new(float_adjustments)?,
+                    to_volume_adjustments(volume_adjustments)?,
                 ))
                 .await?
         }
+        SetCommand::StereoEqualizer { left, right } => {
+            if device_state.right_equalizer_configuration.is_none() {
+                bail!("stereo equalizer not supported by device");
+            }
+            device
+                .set_stereo_equalizer_configuration(StereoEqualizerConfiguration {
+                    left: EqualizerConfiguration::new_custom_profile(to_volume_adjustments(left)?),
+                    right: EqualizerConfiguration::new_custom_profile(to_volume_adjustments(
+                        right,
+                    )?),
+                })
+                .await?
+        }
+        SetCommand::WearDetection { is_enabled } => {
+            if device_state.wear_detection.is_none() {
+                bail!("wear detection not supported by device");
+            }
+            device.set_wear_detection(is_enabled).await?
+        }
+        SetCommand::TouchTone { is_enabled } => {
+            if device_state.touch_tone.is_none() {
+                bail!("touch tone not supported by device");
+            }
+            device.set_touch_tone(is_enabled).await?
+        }
+        SetCommand::AutoPowerOff { duration } => {
+            let Some(auto_power_off) = device_state.auto_power_off else {
+                bail!("auto power off not supported by device");
+            };
+            device
+                .set_auto_power_off(match duration.duration() {
+                    Some(duration) => AutoPowerOff {
+                        is_enabled: true,
+                        duration,
+                    },
+                    None => AutoPowerOff {
+                        is_enabled: false,
+                        ..auto_power_off
+                    },
+                })
+                .await?
+        }
+        SetCommand::GameMode { is_enabled } => {
+            if device_state.game_mode.is_none() {
+                bail!("game mode not supported by device");
+            }
+            device.set_game_mode(is_enabled).await?
+        }
+        SetCommand::Ldac { is_enabled } => {
+            if device_state.ldac.is_none() {
+                bail!("LDAC not supported by device");
+            }
+            device.set_ldac(is_enabled).await?
+        }
+        SetCommand::VoicePrompt { language } => device.set_voice_prompt(language.into()).await?,
     };
     Ok(())
 }
+
+fn to_volume_adjustments(volume_adjustments: Vec<i16>) -> anyhow::Result<VolumeAdjustments> {
+    if !args::VolumeAdjustments::is_valid_number_of_bands(volume_adjustments.len()) {
+        bail!(
+            "expected 8 or 10 equalizer bands, got {}",
+            volume_adjustments.len()
+        );
+    }
+    Ok(VolumeAdjustments::new(
+        volume_adjustments
+            .into_iter()
+            .map(|adjustment| (adjustment as f64) / 10.0),
+    )?)
+}
@@ -0,0 +1,49 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn test_dissect_hex() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("dissect").arg("09ff00000101020c00010019");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "   9  TwsStatusUpdatePacket > host device  01\n",
+        ))
+        .stdout(predicate::str::contains("host_device: Right"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_dissect_capture() {
+    let path = std::env::temp_dir().join(format!("openscq30-dissect-{}.txt", std::process::id()));
+    std::fs::write(
+        &path,
+        "name Test\nmac_address 00:00:00:00:00:00\n\
+        0 outbound-with-response 08ee00000001010a0002\n\
+        12 inbound 09ff000001010f0b000125\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("dissect").arg("--capture").arg(&path);
+    let assert = cmd.assert();
+    std::fs::remove_file(&path).unwrap();
+    assert
+        .success()
+        .stdout(predicate::str::contains("0ms outbound-with-response\n"))
+        .stdout(predicate::str::contains("outbound RequestState\n"))
+        .stdout(predicate::str::contains("error:").not())
+        .stdout(predicate::str::contains("12ms inbound\n"))
+        .stdout(predicate::str::contains(
+            "ChineseVoicePromptStateUpdatePacket",
+        ))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_dissect_requires_input() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("dissect");
+    cmd.assert().failure();
+}
@@ -4,29 +4,131 @@ use predicates::prelude::*;
 #[test]
 fn test_get_ambient_sound_mode() {
     let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
-    cmd.arg("get").arg("ambient-sound-mode");
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:30:28"])
+        .arg("get")
+        .arg("ambient-sound-mode");
     cmd.assert()
         .success()
-        .stdout(predicate::eq("normal\n"))
+        .stdout(predicate::eq("noise-canceling\n"))
         .stderr(predicate::str::is_empty());
 }
 
 #[test]
 fn test_get_noise_canceling_mode() {
     let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
-    cmd.arg("get").arg("noise-canceling-mode");
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:30:28"])
+        .arg("get")
+        .arg("noise-canceling-mode");
     cmd.assert()
         .success()
-        .stdout(predicate::eq("indoor\n"))
+        .stdout(predicate::eq("transport\n"))
         .stderr(predicate::str::is_empty());
 }
 
 #[test]
 fn test_get_equalizer() {
     let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
-    cmd.arg("get").arg("equalizer");
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:30:28"])
+        .arg("get")
+        .arg("equalizer");
     cmd.assert()
         .success()
         .stdout(predicate::eq("0 0 0 0 0 0 0 0\n"))
         .stderr(predicate::str::is_empty());
 }
+
+#[test]
+fn test_get_wear_detection() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:33"])
+        .arg("get")
+        .arg("wear-detection");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("false\n"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_get_auto_power_off() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("get")
+        .arg("auto-power-off");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("disabled\n"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_get_ldac() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("get")
+        .arg("ldac");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("false\n"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_get_charging_case_battery() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("get")
+        .arg("charging-case-battery");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("5\n"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_get_firmware_version() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("get")
+        .arg("firmware-version");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("02.00\n"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_get_stereo_equalizer() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("get")
+        .arg("stereo-equalizer");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("0 0 0 0 0 0 0 0 0 0\n0 0 0 0 0 0 0 0 0 0\n"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_get_tws_status() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("get")
+        .arg("tws-status");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("connected left\n"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_get_voice_prompt() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("get")
+        .arg("voice-prompt");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("english\n"))
+        .stderr(predicate::str::is_empty());
+}
@@ -4,9 +4,25 @@ use predicates::prelude::*;
 #[test]
 fn test_list_devices() {
     let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
-    cmd.arg("list-devices");
+    cmd.arg("--demo").arg("list-devices");
     cmd.assert()
         .success()
-        .stdout(predicate::eq("00:00:00:00:00:00\n"))
+        .stdout(predicate::eq(concat!(
+            // Every model but the A3029, in order, with the model number in the mac address
+            "00:00:00:00:30:27\n",
+            "00:00:00:00:30:28\n",
+            "00:00:00:00:30:30\n",
+            "00:00:00:00:30:31\n",
+            "00:00:00:00:30:33\n",
+            "00:00:00:00:39:26\n",
+            "00:00:00:00:39:30\n",
+            "00:00:00:00:39:31\n",
+            "00:00:00:00:39:33\n",
+            "00:00:00:00:39:36\n",
+            "00:00:00:00:39:45\n",
+            "00:00:00:00:39:51\n",
+            "00:00:00:00:39:39\n",
+            "00:00:00:00:39:35\n"
+        )))
         .stderr(predicate::str::is_empty());
 }
@@ -4,7 +4,8 @@ use predicates::prelude::*;
 #[test]
 fn test_set_ambient_sound_mode() {
     let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
-    cmd.arg("set")
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:30:28"])
+        .arg("set")
         .arg("ambient-sound-mode")
         .arg("noise-canceling");
     cmd.assert()
@@ -16,7 +17,10 @@ fn test_set_ambient_sound_mode() {
 #[test]
 fn test_set_noise_canceling_mode() {
     let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
-    cmd.arg("set").arg("noise-canceling-mode").arg("transport");
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:30:28"])
+        .arg("set")
+        .arg("noise-canceling-mode")
+        .arg("transport");
     cmd.assert()
         .success()
         .stdout(predicate::str::is_empty())
@@ -26,7 +30,8 @@ fn test_set_noise_canceling_mode() {
 #[test]
 fn test_set_equalizer() {
     let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
-    cmd.arg("set")
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:30:28"])
+        .arg("set")
         .arg("equalizer")
         .arg("--")
         .args(["-120", "-60", "0", "0", "0", "60", "120", "135"]);
@@ -35,3 +40,97 @@ fn test_set_equalizer() {
         .stdout(predicate::str::is_empty())
         .stderr(predicate::str::is_empty());
 }
+
+#[test]
+fn test_set_equalizer_with_more_bands_than_device_has() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:30:28"])
+        .arg("set")
+        .arg("equalizer")
+        .arg("--")
+        .args(["0", "0", "0", "0", "0", "0", "0", "0", "10", "20"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("wrong number of equalizer bands"));
+}
+
+#[test]
+fn test_set_equalizer_with_nine_bands() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("set")
+        .arg("equalizer")
+        .arg("--")
+        .args(["0", "0", "0", "0", "0", "0", "0", "0", "10"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("expected 8 or 10 equalizer bands"));
+}
+
+#[test]
+fn test_set_touch_tone() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("set")
+        .arg("touch-tone")
+        .arg("true");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_set_auto_power_off() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("set")
+        .arg("auto-power-off")
+        .arg("disabled");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_set_game_mode() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("set")
+        .arg("game-mode")
+        .arg("true");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_set_stereo_equalizer() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("set")
+        .arg("stereo-equalizer")
+        .arg("--left")
+        .args(["-120", "-60", "0", "0", "0", "60", "120", "135", "0", "0"])
+        .arg("--right")
+        .args(["0", "0", "0", "0", "0", "0", "0", "-10", "0", "0"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_set_voice_prompt() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.args(["--demo", "--mac-address", "00:00:00:00:39:36"])
+        .arg("set")
+        .arg("voice-prompt")
+        .arg("english");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::is_empty());
+}
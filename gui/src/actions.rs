@@ -11,16 +11,23 @@ mod select_custom_equalizer_configuration;
 mod set_ambient_sound_mode;
 mod set_ambient_sound_mode_cycle;
 mod set_ambient_sound_mode_type_two;
+mod set_auto_power_off;
 mod set_button_configuration;
 mod set_custom_noise_canceling;
 mod set_device;
 mod set_equalizer_configuration;
+mod set_game_mode;
 mod set_hear_id;
+mod set_ldac;
 mod set_manual_noise_canceling;
 mod set_noise_canceling_mode;
 mod set_noise_canceling_mode_type_two;
+mod set_stereo_equalizer_configuration;
+mod set_touch_tone;
 mod set_transparency_mode;
 mod set_transparency_mode_type_two;
+mod set_voice_prompt;
+mod set_wear_detection;
 mod state;
 
 use std::sync::Arc;
@@ -35,9 +42,10 @@ use macaddr::MacAddr6;
 use openscq30_lib::devices::standard::{
     state::DeviceState,
     structures::{
-        AmbientSoundMode, AmbientSoundModeCycle, CustomNoiseCanceling, EqualizerConfiguration,
-        HearId, ManualNoiseCanceling, MultiButtonConfiguration, NoiseCancelingMode,
-        NoiseCancelingModeTypeTwo, TransparencyMode,
+        AmbientSoundMode, AmbientSoundModeCycle, AutoPowerOff, CustomNoiseCanceling,
+        EqualizerConfiguration, HearId, ManualNoiseCanceling, MultiButtonConfiguration,
+        NoiseCancelingMode, NoiseCancelingModeTypeTwo, StereoEqualizerConfiguration,
+        TransparencyMode, VoicePrompt,
     },
 };
 pub use refresh_custom_equalizer_profiles::*;
@@ -47,16 +55,23 @@ pub use select_custom_equalizer_configuration::*;
 pub use set_ambient_sound_mode::*;
 pub use set_ambient_sound_mode_cycle::*;
 pub use set_ambient_sound_mode_type_two::*;
+pub use set_auto_power_off::*;
 pub use set_button_configuration::*;
 pub use set_custom_noise_canceling::*;
 pub use set_device::*;
 pub use set_equalizer_configuration::*;
+pub use set_game_mode::*;
 pub use set_hear_id::*;
+pub use set_ldac::*;
 pub use set_manual_noise_canceling::*;
 pub use set_noise_canceling_mode::*;
 pub use set_noise_canceling_mode_type_two::*;
+pub use set_stereo_equalizer_configuration::*;
+pub use set_touch_tone::*;
 pub use set_transparency_mode::*;
 pub use set_transparency_mode_type_two::*;
+pub use set_voice_prompt::*;
+pub use set_wear_detection::*;
 pub use state::*;
 
 use crate::objects::{GlibCustomEqualizerProfile, GlibDevice, GlibNamedQuickPresetValue};
@@ -84,6 +99,7 @@ pub enum Action {
     CreateCustomEqualizerProfile(GlibCustomEqualizerProfile),
     DeleteCustomEqualizerProfile(GlibCustomEqualizerProfile),
     SetEqualizerConfiguration(EqualizerConfiguration),
+    SetStereoEqualizerConfiguration(StereoEqualizerConfiguration),
     SetCustomNoiseCanceling(CustomNoiseCanceling),
     SetTransparencyMode(TransparencyMode),
     CreateQuickPreset(GlibNamedQuickPresetValue),
@@ -100,4 +116,10 @@ pub enum Action {
     SetManualNoiseCanceling(ManualNoiseCanceling),
     SetAmbientSoundModeTypeTwo(AmbientSoundMode),
     SetTransparencyModeTypeTwo(TransparencyMode),
+    SetWearDetection(bool),
+    SetTouchTone(bool),
+    SetAutoPowerOff(AutoPowerOff),
+    SetGameMode(bool),
+    SetLdac(bool),
+    SetVoicePrompt(VoicePrompt),
 }
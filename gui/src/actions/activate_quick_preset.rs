@@ -1,6 +1,6 @@
 use anyhow::Context;
 use openscq30_lib::{
-    api::device::{Device, DeviceRegistry},
+    api::device::{Device, DeviceRegistry, SettingChange},
     devices::standard::{
         state::DeviceState,
         structures::{EqualizerConfiguration, SoundModes, VolumeAdjustments},
@@ -21,19 +21,24 @@ where
 {
     if let Some(device) = state.selected_device() {
         let device_state = device.state().await;
-        set_sound_modes_from_quick_preset(device.as_ref(), &device_state, quick_preset).await?;
-        set_equalizer_configuration_from_quick_preset(device.as_ref(), settings_file, quick_preset)
-            .await?;
+        let changes = [
+            sound_modes_from_quick_preset(&device_state, quick_preset),
+            equalizer_configuration_from_quick_preset(settings_file, quick_preset)?,
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        // Applied as one batch so that a failure doesn't leave the device half configured
+        device.apply(changes).await.context("apply quick preset")?;
     }
     Ok(())
 }
 
-async fn set_sound_modes_from_quick_preset(
-    device: &impl Device,
+fn sound_modes_from_quick_preset(
     device_state: &DeviceState,
     quick_preset: &QuickPreset,
-) -> anyhow::Result<()> {
-    if let Some(sound_modes) = device_state.sound_modes {
+) -> Option<SettingChange> {
+    device_state.sound_modes.map(|sound_modes| {
         let new_sound_modes = SoundModes {
             ambient_sound_mode: quick_preset
                 .ambient_sound_mode
@@ -48,19 +53,14 @@ async fn set_sound_modes_from_quick_preset(
                 .custom_noise_canceling
                 .unwrap_or(sound_modes.custom_noise_canceling),
         };
-        device
-            .set_sound_modes(new_sound_modes)
-            .await
-            .context("set sound modes")?;
-    }
-    Ok(())
+        SettingChange::SoundModes(new_sound_modes)
+    })
 }
 
-async fn set_equalizer_configuration_from_quick_preset(
-    device: &impl Device,
+fn equalizer_configuration_from_quick_preset(
     settings_file: &SettingsFile<Config>,
     quick_preset: &QuickPreset,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Option<SettingChange>> {
     let new_equalizer_configuration = match &quick_preset.equalizer_profile {
         Some(PresetOrCustomEqualizerProfile::Preset(profile)) => {
             Some(EqualizerConfiguration::new_from_preset_profile(*profile))
@@ -88,13 +88,7 @@ async fn set_equalizer_configuration_from_quick_preset(
         None => None,
     };
 
-    if let Some(new_equalizer_configuration) = new_equalizer_configuration {
-        device
-            .set_equalizer_configuration(new_equalizer_configuration)
-            .await
-            .context("set equalizer configuration")?;
-    }
-    Ok(())
+    Ok(new_equalizer_configuration.map(SettingChange::EqualizerConfiguration))
 }
 
 #[cfg(test)]
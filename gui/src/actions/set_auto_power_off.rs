@@ -0,0 +1,22 @@
+use openscq30_lib::{
+    api::device::{Device, DeviceRegistry},
+    devices::standard::structures::AutoPowerOff,
+};
+
+use super::State;
+
+#[tracing::instrument(level = "trace", skip(state))]
+pub async fn set_auto_power_off<T>(
+    state: &State<T>,
+    auto_power_off: AutoPowerOff,
+) -> anyhow::Result<()>
+where
+    T: DeviceRegistry + 'static,
+{
+    let device = state
+        .selected_device()
+        .ok_or_else(|| anyhow::anyhow!("no device is selected"))?;
+
+    device.set_auto_power_off(auto_power_off).await?;
+    Ok(())
+}
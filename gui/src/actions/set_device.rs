@@ -1,15 +1,18 @@
 use std::rc::Rc;
 
 use anyhow::anyhow;
-use gtk::glib::{clone, MainContext};
+use gtk::glib::{self, clone, MainContext};
 use macaddr::MacAddr6;
-use openscq30_lib::api::{
-    connection::ConnectionStatus,
-    device::{Device, DeviceRegistry},
+use openscq30_lib::{
+    api::{
+        connection::ConnectionStatus,
+        device::{Device, DeviceRegistry},
+    },
+    devices::standard::state::DeviceState,
 };
 use tokio::sync::{mpsc::UnboundedSender, oneshot, watch};
 
-use crate::{actions, settings::SettingsFile};
+use crate::{actions, settings::SettingsFile, APPLICATION_ID_STR};
 use crate::{objects::GlibDevice, settings::Config};
 
 use super::{State, StateUpdate};
@@ -85,15 +88,20 @@ where
 
     *state.selected_device.borrow_mut() = Some(device.to_owned());
     let receiver = device.subscribe_to_state_updates().await;
+    let tws_status_receiver = receiver.clone();
     state
         .state_update_receiver
         .replace_receiver(Some(receiver))
         .await;
+    MainContext::default().spawn_local(notify_of_tws_status_changes(
+        tws_status_receiver,
+        state.state_update_sender.clone(),
+    ));
 
     let connection_status_receiver = device.connection_status();
     let state_update_sender = state.state_update_sender.clone();
     MainContext::default().spawn_local(async move {
-        wait_for_disconnect(connection_status_receiver).await;
+        wait_for_disconnect(connection_status_receiver, &state_update_sender).await;
         handle_disconnect(state_update_sender);
     });
 
@@ -126,14 +134,38 @@ where
     Ok(())
 }
 
-async fn wait_for_disconnect(mut connection_status_receiver: watch::Receiver<ConnectionStatus>) {
+/// Shows a toast while the device is reconnecting, and returns once it is disconnected for good.
+async fn wait_for_disconnect(
+    mut connection_status_receiver: watch::Receiver<ConnectionStatus>,
+    state_update_sender: &UnboundedSender<StateUpdate>,
+) {
+    let mut previous_connection_status = *connection_status_receiver.borrow_and_update();
     loop {
         match connection_status_receiver.changed().await {
             Ok(_) => {
-                let connection_status = *connection_status_receiver.borrow();
-                if connection_status == ConnectionStatus::Disconnected {
-                    return;
+                let connection_status = *connection_status_receiver.borrow_and_update();
+                let message = match (previous_connection_status, connection_status) {
+                    (_, ConnectionStatus::Disconnected) => return,
+                    (ConnectionStatus::Connected, ConnectionStatus::Reconnecting) => {
+                        Some(glib::dpgettext2(
+                            Some(APPLICATION_ID_STR),
+                            "toast",
+                            "Connection Lost. Reconnecting...",
+                        ))
+                    }
+                    (ConnectionStatus::Reconnecting, ConnectionStatus::Connected) => Some(
+                        glib::dpgettext2(Some(APPLICATION_ID_STR), "toast", "Device Reconnected"),
+                    ),
+                    _ => None,
+                };
+                if let Some(message) = message {
+                    if let Err(err) =
+                        state_update_sender.send(StateUpdate::AddToast(message.to_string()))
+                    {
+                        tracing::error!("error sending toast: {err:?}");
+                    }
                 }
+                previous_connection_status = connection_status;
             }
             Err(err) => {
                 tracing::debug!("connection status sender destroyed, exiting loop: {err:?}");
@@ -143,10 +175,52 @@ async fn wait_for_disconnect(mut connection_status_receiver: watch::Receiver<Con
     }
 }
 
+async fn notify_of_tws_status_changes(
+    mut state_receiver: watch::Receiver<DeviceState>,
+    state_update_sender: UnboundedSender<StateUpdate>,
+) {
+    let mut previous_tws_status = state_receiver.borrow_and_update().tws_status;
+    // Ends when the device is dropped
+    while state_receiver.changed().await.is_ok() {
+        let tws_status = state_receiver.borrow_and_update().tws_status;
+        if let (Some(previous), Some(current)) = (previous_tws_status, tws_status) {
+            let message = if previous.is_connected && !current.is_connected {
+                Some(glib::dpgettext2(
+                    Some(APPLICATION_ID_STR),
+                    "toast",
+                    "Earbud Disconnected",
+                ))
+            } else if !previous.is_connected && current.is_connected {
+                Some(glib::dpgettext2(
+                    Some(APPLICATION_ID_STR),
+                    "toast",
+                    "Earbud Connected",
+                ))
+            } else if previous.host_device != current.host_device {
+                Some(glib::dpgettext2(
+                    Some(APPLICATION_ID_STR),
+                    "toast",
+                    "Host Earbud Changed",
+                ))
+            } else {
+                None
+            };
+            if let Some(message) = message {
+                if let Err(err) =
+                    state_update_sender.send(StateUpdate::AddToast(message.to_string()))
+                {
+                    tracing::error!("error sending toast: {err:?}");
+                }
+            }
+        }
+        previous_tws_status = tws_status;
+    }
+}
+
 fn handle_disconnect(state_update_sender: UnboundedSender<StateUpdate>) {
-    if let Err(err) =
-        state_update_sender.send(StateUpdate::AddToast("Device Disconnected".to_string()))
-    {
+    if let Err(err) = state_update_sender.send(StateUpdate::AddToast(
+        glib::dpgettext2(Some(APPLICATION_ID_STR), "toast", "Device Disconnected").to_string(),
+    )) {
         tracing::error!("error sending toast: {err:?}");
     }
     if let Err(err) = state_update_sender.send(StateUpdate::SetSelectedDevice(None)) {
@@ -165,8 +239,8 @@ mod tests {
         devices::standard::{
             state::DeviceState,
             structures::{
-                AmbientSoundMode, EqualizerConfiguration, NoiseCancelingMode,
-                PresetEqualizerProfile, SerialNumber, SoundModes,
+                AmbientSoundMode, EqualizerConfiguration, HostDevice, NoiseCancelingMode,
+                PresetEqualizerProfile, SerialNumber, SoundModes, TwsStatus,
             },
         },
     };
@@ -338,4 +412,127 @@ mod tests {
             .unwrap();
         sender.send_replace(ConnectionStatus::Disconnected);
     }
+
+    #[gtk::test]
+    async fn test_shows_toast_when_earbud_disconnects() {
+        crate::load_resources();
+        let (_connection_status_sender, connection_status_receiver) =
+            watch::channel(ConnectionStatus::Connected);
+        let device_state = DeviceState {
+            serial_number: Some(SerialNumber("0123".into())),
+            tws_status: Some(TwsStatus {
+                is_connected: true,
+                host_device: HostDevice::Left,
+            }),
+            ..Default::default()
+        };
+        let (state_sender, state_receiver) = watch::channel(device_state.clone());
+        let mut registry = MockDeviceRegistry::new();
+        registry
+            .expect_device()
+            .with(predicate::eq(MacAddr6::nil()))
+            .return_once(|_mac_address| {
+                let mut device = MockDevice::new();
+                device
+                    .expect_subscribe_to_state_updates()
+                    .once()
+                    .return_once(|| state_receiver);
+                device
+                    .expect_name()
+                    .once()
+                    .returning(|| Ok("Test Device".into()));
+                device
+                    .expect_mac_address()
+                    .once()
+                    .returning(|| Ok(MacAddr6::nil()));
+                device
+                    .expect_connection_status()
+                    .once()
+                    .return_const(connection_status_receiver);
+                device.expect_service_uuid().return_const(Uuid::default());
+                device.expect_state().return_const(device_state.clone());
+
+                Ok(Some(Rc::new(device)))
+            });
+
+        let (state, mut receiver) = State::new(registry);
+
+        let dir = tempfile::tempdir().unwrap();
+        let settings_file = Rc::new(SettingsFile::new(dir.path().join("config.toml")));
+        set_device(&state, settings_file, Some(MacAddr6::nil()))
+            .await
+            .unwrap();
+        state_sender.send_modify(|state| {
+            state.tws_status = Some(TwsStatus {
+                is_connected: false,
+                host_device: HostDevice::Left,
+            })
+        });
+
+        loop {
+            if let Some(StateUpdate::AddToast(text)) = receiver.recv().await {
+                assert_eq!("Earbud Disconnected", text);
+                break;
+            }
+        }
+    }
+
+    #[gtk::test]
+    async fn test_shows_toast_when_reconnecting() {
+        crate::load_resources();
+        let (connection_status_sender, connection_status_receiver) =
+            watch::channel(ConnectionStatus::Connected);
+        let device_state = DeviceState {
+            serial_number: Some(SerialNumber("0123".into())),
+            tws_status: Some(TwsStatus {
+                is_connected: true,
+                host_device: HostDevice::Left,
+            }),
+            ..Default::default()
+        };
+        let (_state_sender, state_receiver) = watch::channel(device_state.clone());
+        let mut registry = MockDeviceRegistry::new();
+        registry
+            .expect_device()
+            .with(predicate::eq(MacAddr6::nil()))
+            .return_once(|_mac_address| {
+                let mut device = MockDevice::new();
+                device
+                    .expect_subscribe_to_state_updates()
+                    .once()
+                    .return_once(|| state_receiver);
+                device
+                    .expect_name()
+                    .once()
+                    .returning(|| Ok("Test Device".into()));
+                device
+                    .expect_mac_address()
+                    .once()
+                    .returning(|| Ok(MacAddr6::nil()));
+                device
+                    .expect_connection_status()
+                    .once()
+                    .return_const(connection_status_receiver);
+                device.expect_service_uuid().return_const(Uuid::default());
+                device.expect_state().return_const(device_state.clone());
+
+                Ok(Some(Rc::new(device)))
+            });
+
+        let (state, mut receiver) = State::new(registry);
+
+        let dir = tempfile::tempdir().unwrap();
+        let settings_file = Rc::new(SettingsFile::new(dir.path().join("config.toml")));
+        set_device(&state, settings_file, Some(MacAddr6::nil()))
+            .await
+            .unwrap();
+        connection_status_sender.send_replace(ConnectionStatus::Reconnecting);
+
+        loop {
+            if let Some(StateUpdate::AddToast(text)) = receiver.recv().await {
+                assert_eq!("Connection Lost. Reconnecting...", text);
+                break;
+            }
+        }
+    }
 }
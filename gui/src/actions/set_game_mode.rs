@@ -0,0 +1,16 @@
+use openscq30_lib::api::device::{Device, DeviceRegistry};
+
+use super::State;
+
+#[tracing::instrument(level = "trace", skip(state))]
+pub async fn set_game_mode<T>(state: &State<T>, is_enabled: bool) -> anyhow::Result<()>
+where
+    T: DeviceRegistry + 'static,
+{
+    let device = state
+        .selected_device()
+        .ok_or_else(|| anyhow::anyhow!("no device is selected"))?;
+
+    device.set_game_mode(is_enabled).await?;
+    Ok(())
+}
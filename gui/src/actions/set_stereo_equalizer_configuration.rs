@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use gtk::glib::{self, clone, timeout_future, MainContext};
+use openscq30_lib::{
+    api::device::{Device, DeviceRegistry},
+    devices::standard::structures::StereoEqualizerConfiguration,
+};
+use tokio::sync::oneshot;
+
+use super::State;
+
+#[tracing::instrument(level = "trace", skip(state))]
+pub async fn set_stereo_equalizer_configuration<T>(
+    state: &State<T>,
+    configuration: StereoEqualizerConfiguration,
+) -> anyhow::Result<()>
+where
+    T: DeviceRegistry + 'static,
+{
+    let device = state
+        .selected_device()
+        .ok_or_else(|| anyhow::anyhow!("no device is selected"))?;
+
+    // Debounce, sharing the handle with set_equalizer_configuration since both write the same setting
+    let (result_sender, result_receiver) = oneshot::channel::<openscq30_lib::Result<()>>();
+    let new_handle = MainContext::default().spawn_local(clone!(
+        #[weak]
+        device,
+        async move {
+            timeout_future(Duration::from_millis(500)).await;
+            let result = device
+                .set_stereo_equalizer_configuration(configuration)
+                .await;
+            result_sender.send(result).expect("receiver dropped");
+        }
+    ));
+    if let Some(old_handle) = state
+        .set_equalizer_configuration_handle
+        .replace(Some(new_handle))
+    {
+        old_handle.abort();
+    }
+
+    match result_receiver.await {
+        Ok(Ok(())) => Ok(()),
+        Err(_sender_dropped) => Ok(()),
+        Ok(Err(err)) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use mockall::predicate;
+    use openscq30_lib::devices::standard::structures::{
+        EqualizerConfiguration, PresetEqualizerProfile, StereoEqualizerConfiguration,
+    };
+
+    use crate::{
+        actions::State,
+        mock::{MockDevice, MockDeviceRegistry},
+    };
+
+    use super::set_stereo_equalizer_configuration;
+
+    #[gtk::test]
+    async fn it_works() {
+        crate::load_resources();
+        let registry = MockDeviceRegistry::new();
+        let (state, _receiver) = State::new(registry);
+        let configuration = StereoEqualizerConfiguration {
+            left: EqualizerConfiguration::new_from_preset_profile(PresetEqualizerProfile::Acoustic),
+            right: EqualizerConfiguration::new_from_preset_profile(
+                PresetEqualizerProfile::BassReducer,
+            ),
+        };
+        let mut selected_device = MockDevice::new();
+        selected_device
+            .expect_set_stereo_equalizer_configuration()
+            .once()
+            .with(predicate::eq(configuration.clone()))
+            .return_once(|_configuration| Ok(()));
+        *state.selected_device.borrow_mut() = Some(Rc::new(selected_device));
+
+        set_stereo_equalizer_configuration(&state, configuration)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,19 @@
+use openscq30_lib::{
+    api::device::{Device, DeviceRegistry},
+    devices::standard::structures::VoicePrompt,
+};
+
+use super::State;
+
+#[tracing::instrument(level = "trace", skip(state))]
+pub async fn set_voice_prompt<T>(state: &State<T>, voice_prompt: VoicePrompt) -> anyhow::Result<()>
+where
+    T: DeviceRegistry + 'static,
+{
+    let device = state
+        .selected_device()
+        .ok_or_else(|| anyhow::anyhow!("no device is selected"))?;
+
+    device.set_voice_prompt(voice_prompt).await?;
+    Ok(())
+}
@@ -1,8 +1,11 @@
 use std::{
+    cell::Cell,
     env,
     path::{Path, PathBuf},
+    rc::Rc,
     str::FromStr,
     sync::Once,
+    time::Duration,
 };
 
 use actions::{State, StateUpdate};
@@ -114,9 +117,15 @@ fn run_application() {
     let app = adw::Application::builder()
         .application_id(APPLICATION_ID_STR)
         .build();
+    // Set while handling command line args, which happens before activation
+    let demo = Rc::new(Cell::new(false));
     app.connect_startup(|_| load_css());
-    app.connect_activate(build_ui);
-    handle_command_line_args(&app);
+    app.connect_activate(clone!(
+        #[strong]
+        demo,
+        move |application| build_ui(application, demo.get())
+    ));
+    handle_command_line_args(&app, demo);
 
     app.run();
 }
@@ -131,7 +140,7 @@ fn load_css() {
     );
 }
 
-fn handle_command_line_args<T>(application: &T)
+fn handle_command_line_args<T>(application: &T, demo: Rc<Cell<bool>>)
 where
     T: IsA<gtk::Application> + IsA<gtk::gio::Application>,
 {
@@ -144,8 +153,17 @@ where
         &format!("Logging Level {}", LoggingLevel::allowed_values_string()),
         Some("LEVEL"),
     );
+    application.add_main_option(
+        "demo",
+        b'd'.into(),
+        OptionFlags::NONE,
+        glib::OptionArg::None,
+        "Use demo devices instead of bluetooth devices",
+        None,
+    );
 
-    application.connect_handle_local_options(|_application, options| {
+    application.connect_handle_local_options(move |_application, options| {
+        demo.set(options.contains("demo"));
         let maybe_logging_level = options
             .lookup::<String>("logging-level")
             .expect("logging-level must be a string")
@@ -185,7 +203,7 @@ where
     });
 }
 
-fn build_ui(application: &adw::Application) {
+fn build_ui(application: &adw::Application, demo: bool) {
     // Display ui while initializing DeviceRegistry asynchronously
     #[cfg(target_os = "windows")]
     if let Err(err) = set_ui_theme(application) {
@@ -216,9 +234,12 @@ fn build_ui(application: &adw::Application) {
         let application = application.to_owned();
         let main_window = main_window.to_owned();
         MainContext::default().spawn_local(async move {
-            let registry = new_soundcore_device_registry_with_custom_runtime::<GtkFutures>()
-                .await
-                .expect("failed to initialize device registry");
+            let registry = new_soundcore_device_registry_with_custom_runtime::<GtkFutures>(
+                demo,
+                Some(Duration::from_secs(60)),
+            )
+            .await
+            .expect("failed to initialize device registry");
             // Async initialization done, now set up event handlers and such
             delayed_initialize_application(&application, &main_window, registry, settings);
         });
@@ -286,7 +307,24 @@ fn delayed_initialize_application(
         };
 
         tracing::error!("{err:?}");
-        match err.downcast_ref::<openscq30_lib::Error>() {
+        let mut err = err.downcast_ref::<openscq30_lib::Error>();
+        // Report whether the rest of the batch was undone, then handle the underlying error so that
+        // disconnects and timeouts still deselect the device
+        if let Some(openscq30_lib::Error::ApplyFailed {
+            setting,
+            rolled_back,
+            source,
+            ..
+        }) = err
+        {
+            send_toast(if *rolled_back {
+                format!("Failed to apply {setting}. Other changes were undone.")
+            } else {
+                format!("Failed to apply {setting}. Not all other changes could be undone.")
+            });
+            err = Some(source.as_ref());
+        }
+        match err {
             Some(openscq30_lib::Error::TimedOut { action }) => {
                 deselect_device();
                 send_toast(format!("Action Timed Out: {action}"));
@@ -327,10 +365,15 @@ fn delayed_initialize_application(
             Some(openscq30_lib::Error::IncompleteStateError { message }) => {
                 send_toast(format!("Action failed due to incomplete state: {message}"))
             }
+            Some(openscq30_lib::Error::VerificationFailed { .. }) => {
+                send_toast("Device Did Not Apply Change".to_string())
+            }
             Some(openscq30_lib::Error::BluetoothAdapterNotAvailable { .. }) => {
                 send_toast("bluetooth adapter not available".to_string())
             }
-            Some(openscq30_lib::Error::Other { .. }) | None => {
+            Some(openscq30_lib::Error::Other { .. })
+            | Some(openscq30_lib::Error::ApplyFailed { .. })
+            | None => {
                 state
                     .state_update_sender
                     .send(StateUpdate::AddToast(
@@ -393,6 +436,34 @@ fn delayed_initialize_application(
                                 .await
                                 .context("transparency mode type two selected")
                         }
+                        Action::SetWearDetection(is_enabled) => {
+                            actions::set_wear_detection(&state, is_enabled)
+                                .await
+                                .context("set wear detection")
+                        }
+                        Action::SetTouchTone(is_enabled) => {
+                            actions::set_touch_tone(&state, is_enabled)
+                                .await
+                                .context("set touch tone")
+                        }
+                        Action::SetAutoPowerOff(auto_power_off) => {
+                            actions::set_auto_power_off(&state, auto_power_off)
+                                .await
+                                .context("set auto power off")
+                        }
+                        Action::SetGameMode(is_enabled) => {
+                            actions::set_game_mode(&state, is_enabled)
+                                .await
+                                .context("set game mode")
+                        }
+                        Action::SetLdac(is_enabled) => actions::set_ldac(&state, is_enabled)
+                            .await
+                            .context("set ldac"),
+                        Action::SetVoicePrompt(voice_prompt) => {
+                            actions::set_voice_prompt(&state, voice_prompt)
+                                .await
+                                .context("set voice prompt")
+                        }
                         Action::Connect(mac_address) => actions::set_device(
                             &state,
                             settings.config.to_owned(),
@@ -435,6 +506,11 @@ fn delayed_initialize_application(
                                 .await
                                 .context("apply equalizer settings")
                         }
+                        Action::SetStereoEqualizerConfiguration(configuration) => {
+                            actions::set_stereo_equalizer_configuration(&state, configuration)
+                                .await
+                                .context("apply stereo equalizer settings")
+                        }
                         Action::CreateQuickPreset(named_quick_preset) => {
                             actions::create_quick_preset(
                                 &state,
@@ -6,20 +6,23 @@ use mockall::mock;
 use openscq30_lib::{
     api::{connection::ConnectionStatus, device::Device},
     devices::standard::{
+        device_event::DeviceEvent,
         state::DeviceState,
         structures::{
-            AmbientSoundModeCycle, EqualizerConfiguration, HearId, MultiButtonConfiguration,
-            SoundModes, SoundModesTypeTwo,
+            AmbientSoundModeCycle, AutoPowerOff, EqualizerConfiguration, HearId,
+            MultiButtonConfiguration, SoundModes, SoundModesTypeTwo, StereoEqualizerConfiguration,
+            VoicePrompt,
         },
     },
 };
-use tokio::sync::watch;
+use tokio::sync::{broadcast, watch};
 use uuid::Uuid;
 
 mock! {
     #[derive(Debug)]
     pub Device {
         pub fn subscribe_to_state_updates(&self) -> watch::Receiver<DeviceState>;
+        pub fn subscribe_to_events(&self) -> broadcast::Receiver<DeviceEvent>;
         pub fn connection_status(&self) -> watch::Receiver<ConnectionStatus>;
         pub fn mac_address(&self) -> openscq30_lib::Result<MacAddr6>;
         pub fn name(&self) -> openscq30_lib::Result<String>;
@@ -41,6 +44,10 @@ mock! {
             &self,
             configuration: EqualizerConfiguration,
         ) -> openscq30_lib::Result<()>;
+        pub fn set_stereo_equalizer_configuration(
+            &self,
+            configuration: StereoEqualizerConfiguration,
+        ) -> openscq30_lib::Result<()>;
         pub fn set_hear_id(
             &self,
             hear_id: HearId,
@@ -49,6 +56,12 @@ mock! {
             &self,
             button_configuration: MultiButtonConfiguration,
         ) -> openscq30_lib::Result<()>;
+        pub fn set_wear_detection(&self, is_enabled: bool) -> openscq30_lib::Result<()>;
+        pub fn set_touch_tone(&self, is_enabled: bool) -> openscq30_lib::Result<()>;
+        pub fn set_auto_power_off(&self, auto_power_off: AutoPowerOff) -> openscq30_lib::Result<()>;
+        pub fn set_game_mode(&self, is_enabled: bool) -> openscq30_lib::Result<()>;
+        pub fn set_ldac(&self, is_enabled: bool) -> openscq30_lib::Result<()>;
+        pub fn set_voice_prompt(&self, voice_prompt: VoicePrompt) -> openscq30_lib::Result<()>;
     }
 }
 
@@ -56,6 +69,9 @@ impl Device for MockDevice {
     async fn subscribe_to_state_updates(&self) -> watch::Receiver<DeviceState> {
         self.subscribe_to_state_updates()
     }
+    fn subscribe_to_events(&self) -> broadcast::Receiver<DeviceEvent> {
+        self.subscribe_to_events()
+    }
     fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
         self.connection_status()
     }
@@ -99,6 +115,13 @@ impl Device for MockDevice {
         timeout_future(Duration::from_millis(10)).await;
         self.set_equalizer_configuration(equalizer_configuration)
     }
+    async fn set_stereo_equalizer_configuration(
+        &self,
+        configuration: StereoEqualizerConfiguration,
+    ) -> openscq30_lib::Result<()> {
+        timeout_future(Duration::from_millis(10)).await;
+        self.set_stereo_equalizer_configuration(configuration)
+    }
     async fn set_hear_id(&self, hear_id: HearId) -> openscq30_lib::Result<()> {
         timeout_future(Duration::from_millis(10)).await;
         self.set_hear_id(hear_id)
@@ -110,4 +133,28 @@ impl Device for MockDevice {
         timeout_future(Duration::from_millis(10)).await;
         self.set_multi_button_configuration(button_configuration)
     }
+    async fn set_wear_detection(&self, is_enabled: bool) -> openscq30_lib::Result<()> {
+        timeout_future(Duration::from_millis(10)).await;
+        self.set_wear_detection(is_enabled)
+    }
+    async fn set_touch_tone(&self, is_enabled: bool) -> openscq30_lib::Result<()> {
+        timeout_future(Duration::from_millis(10)).await;
+        self.set_touch_tone(is_enabled)
+    }
+    async fn set_auto_power_off(&self, auto_power_off: AutoPowerOff) -> openscq30_lib::Result<()> {
+        timeout_future(Duration::from_millis(10)).await;
+        self.set_auto_power_off(auto_power_off)
+    }
+    async fn set_game_mode(&self, is_enabled: bool) -> openscq30_lib::Result<()> {
+        timeout_future(Duration::from_millis(10)).await;
+        self.set_game_mode(is_enabled)
+    }
+    async fn set_ldac(&self, is_enabled: bool) -> openscq30_lib::Result<()> {
+        timeout_future(Duration::from_millis(10)).await;
+        self.set_ldac(is_enabled)
+    }
+    async fn set_voice_prompt(&self, voice_prompt: VoicePrompt) -> openscq30_lib::Result<()> {
+        timeout_future(Duration::from_millis(10)).await;
+        self.set_voice_prompt(voice_prompt)
+    }
 }
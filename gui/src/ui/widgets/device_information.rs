@@ -30,7 +30,9 @@ mod imp {
         },
         template_callbacks, CompositeTemplate,
     };
-    use openscq30_lib::devices::standard::state::DeviceState;
+    use openscq30_lib::devices::standard::{state::DeviceState, structures::HostDevice};
+
+    use crate::APPLICATION_ID_STR;
 
     #[derive(Default, CompositeTemplate)]
     #[template(resource = "/com/oppzippy/OpenSCQ30/ui/widgets/device_information.ui")]
@@ -50,6 +52,16 @@ mod imp {
         #[template_child]
         age_range: TemplateChild<gtk::Label>,
 
+        #[template_child]
+        charging_case_battery_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        charging_case_battery: TemplateChild<gtk::Label>,
+
+        #[template_child]
+        tws_status_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        tws_status: TemplateChild<gtk::Label>,
+
         #[template_child]
         feature_flags_label: TemplateChild<gtk::Label>,
         #[template_child]
@@ -63,8 +75,14 @@ mod imp {
                 .set_text(state.serial_number.to_owned().unwrap_or_default().as_str());
             self.firmware_version.set_text(
                 &state
-                    .firmware_version
-                    .map(|version| version.to_string())
+                    .dual_firmware_version
+                    .map(|version| {
+                        if version.left == version.right {
+                            version.left.to_string()
+                        } else {
+                            format!("{} / {}", version.left, version.right)
+                        }
+                    })
                     .unwrap_or_default(),
             );
             self.age_range.set_text(
@@ -73,6 +91,42 @@ mod imp {
                     .map(|age_range| age_range.0.to_string())
                     .unwrap_or_default(),
             );
+            self.charging_case_battery.set_text(
+                &state
+                    .charging_case_battery_level
+                    .map(|level| format!("{}/5", level.0))
+                    .unwrap_or_default(),
+            );
+            self.tws_status.set_text(
+                &state
+                    .tws_status
+                    .map(|tws_status| {
+                        match (tws_status.is_connected, tws_status.host_device) {
+                            (true, HostDevice::Left) => glib::dpgettext2(
+                                Some(APPLICATION_ID_STR),
+                                "device information",
+                                "Both (left is host)",
+                            ),
+                            (true, HostDevice::Right) => glib::dpgettext2(
+                                Some(APPLICATION_ID_STR),
+                                "device information",
+                                "Both (right is host)",
+                            ),
+                            (false, HostDevice::Left) => glib::dpgettext2(
+                                Some(APPLICATION_ID_STR),
+                                "device information",
+                                "Left only",
+                            ),
+                            (false, HostDevice::Right) => glib::dpgettext2(
+                                Some(APPLICATION_ID_STR),
+                                "device information",
+                                "Right only",
+                            ),
+                        }
+                        .to_string()
+                    })
+                    .unwrap_or_default(),
+            );
             // TODO display as JSON or something
             self.feature_flags
                 .set_text(&format!("{:?}", state.device_features));
@@ -103,6 +157,11 @@ mod imp {
                 ),
                 (&self.serial_number_label.get(), &self.serial_number.get()),
                 (&self.age_range_label.get(), &self.age_range.get()),
+                (
+                    &self.charging_case_battery_label.get(),
+                    &self.charging_case_battery.get(),
+                ),
+                (&self.tws_status_label.get(), &self.tws_status.get()),
                 (&self.feature_flags_label.get(), &self.feature_flags.get()),
             ]
             .into_iter()
@@ -25,7 +25,7 @@ impl Equalizer {
 }
 
 mod imp {
-    use std::cell::{Cell, RefCell};
+    use std::cell::Cell;
     use std::sync::LazyLock;
 
     use gtk::glib::clone;
@@ -40,7 +40,7 @@ mod imp {
     };
     use openscq30_lib::devices::standard::structures::VolumeAdjustments;
 
-    use crate::ui::widgets::equalizer_settings::volume_slider::VolumeSlider;
+    use crate::{ui::widgets::equalizer_settings::volume_slider::VolumeSlider, APPLICATION_ID_STR};
 
     #[derive(Default, CompositeTemplate)]
     #[template(resource = "/com/oppzippy/OpenSCQ30/ui/widgets/equalizer_settings/equalizer.ui")]
@@ -61,9 +61,13 @@ mod imp {
         pub band_6400: TemplateChild<VolumeSlider>,
         #[template_child]
         pub band_12800: TemplateChild<VolumeSlider>,
+        // The official app only displays 8 bands, so the frequencies of bands 9 and 10 are unknown
+        #[template_child]
+        pub band_9: TemplateChild<VolumeSlider>,
+        #[template_child]
+        pub band_10: TemplateChild<VolumeSlider>,
 
         dont_fire_events: Cell<bool>,
-        bands_past_8: RefCell<Vec<f64>>,
     }
 
     impl Equalizer {
@@ -71,25 +75,24 @@ mod imp {
             VolumeAdjustments::new(
                 self.get_volume_sliders()
                     .iter()
-                    .map(|slider| slider.volume())
-                    .chain(self.bands_past_8.borrow().iter().cloned()),
+                    .filter(|slider| slider.is_visible())
+                    .map(|slider| slider.volume()),
             )
             .expect("we should not allow displaying an invalid number of bands")
         }
 
         pub fn set_volumes(&self, volumes: &[f64]) {
-            // TODO set number of visible sliders once a number other than 8 is needed by a device
-            {
-                let mut bands_past_8 = self.bands_past_8.borrow_mut();
-                bands_past_8.clear();
-                bands_past_8.extend(volumes[8..].iter());
-            }
-
             self.dont_fire_events.set(true);
             self.get_volume_sliders()
                 .iter()
-                .zip(volumes)
-                .for_each(|(slider, volume)| slider.set_volume(*volume));
+                .enumerate()
+                .for_each(|(index, slider)| match volumes.get(index) {
+                    Some(volume) => {
+                        slider.set_volume(*volume);
+                        slider.set_visible(true);
+                    }
+                    None => slider.set_visible(false),
+                });
             self.dont_fire_events.set(false);
         }
 
@@ -109,6 +112,8 @@ mod imp {
                 &self.band_3200,
                 &self.band_6400,
                 &self.band_12800,
+                &self.band_9,
+                &self.band_10,
             ]
             .to_vec()
         }
@@ -131,6 +136,16 @@ mod imp {
 
     impl ObjectImpl for Equalizer {
         fn constructed(&self) {
+            self.band_9.set_band_label(&glib::dpgettext2(
+                Some(APPLICATION_ID_STR),
+                "equalizer band",
+                "Band 9",
+            ));
+            self.band_10.set_band_label(&glib::dpgettext2(
+                Some(APPLICATION_ID_STR),
+                "equalizer band",
+                "Band 10",
+            ));
             for band in self.get_volume_sliders() {
                 band.connect_notify_local(
                     Some("volume-slider-value"),
@@ -161,7 +176,7 @@ mod tests {
 
     use gtk::{
         glib::{self, closure_local},
-        prelude::ObjectExt,
+        prelude::{ObjectExt, WidgetExt},
         subclass::prelude::ObjectSubclassIsExt,
     };
 
@@ -244,4 +259,17 @@ mod tests {
         assert_eq!(8.0, volume_adjustments[8]);
         assert_eq!(9.0, volume_adjustments[9]);
     }
+
+    #[gtk::test]
+    async fn test_only_shows_sliders_for_bands_that_exist() {
+        load_resources();
+        let equalizer = Equalizer::new();
+        equalizer.set_volumes(&[0.0; 8]);
+        assert!(!equalizer.imp().band_9.is_visible());
+        assert!(!equalizer.imp().band_10.is_visible());
+
+        equalizer.set_volumes(&[0.0; 10]);
+        assert!(equalizer.imp().band_9.is_visible());
+        assert!(equalizer.imp().band_10.is_visible());
+    }
 }
@@ -31,6 +31,15 @@ impl EqualizerSettingsScreen {
         self.imp().equalizer_configuration()
     }
 
+    /// Shows a second equalizer for the right channel when set, or hides it when `None`.
+    pub fn set_right_equalizer_configuration(
+        &self,
+        equalizer_configuration: Option<&EqualizerConfiguration>,
+    ) {
+        self.imp()
+            .set_right_equalizer_configuration(equalizer_configuration);
+    }
+
     pub fn set_custom_profiles(&self, custom_profiles: Vec<GlibCustomEqualizerProfile>) {
         self.imp().set_custom_profiles(custom_profiles)
     }
@@ -51,7 +60,8 @@ mod imp {
         SignalListItemFactory, TemplateChild,
     };
     use openscq30_lib::devices::standard::structures::{
-        EqualizerConfiguration, PresetEqualizerProfile, VolumeAdjustments,
+        EqualizerConfiguration, PresetEqualizerProfile, StereoEqualizerConfiguration,
+        VolumeAdjustments,
     };
     use strum::IntoEnumIterator;
     use tokio::sync::mpsc::UnboundedSender;
@@ -84,6 +94,10 @@ mod imp {
         pub delete_custom_profile_button: TemplateChild<gtk::Button>,
         #[template_child]
         pub custom_profile_buttons: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub right_channel: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub right_equalizer: TemplateChild<Equalizer>,
 
         profiles: OnceCell<gio::ListStore>,
         custom_profiles: OnceCell<gio::ListStore>,
@@ -149,17 +163,55 @@ mod imp {
             if !volume_adjustments_match_preset_profile {
                 if let Some(custom_profile_index) = self.custom_profile_index.get() {
                     self.profile_dropdown.set_selected(custom_profile_index);
-                    self.sender
-                        .get()
-                        .unwrap()
-                        .send(Action::SetEqualizerConfiguration(
-                            self.equalizer_configuration(),
-                        ))
-                        .unwrap();
+                    self.send_equalizer_configuration(
+                        self.sender.get().unwrap(),
+                        self.equalizer_configuration(),
+                    );
                 }
             }
         }
 
+        #[template_callback]
+        fn handle_right_volumes_changed(&self, _equalizer: &Equalizer) {
+            if let Some(sender) = self.sender.get() {
+                self.send_equalizer_configuration(sender, self.equalizer_configuration());
+            }
+        }
+
+        // Devices with a right channel get both channels sent together so that editing one doesn't overwrite the
+        // other.
+        fn send_equalizer_configuration(
+            &self,
+            sender: &UnboundedSender<Action>,
+            equalizer_configuration: EqualizerConfiguration,
+        ) {
+            let action = if self.right_channel.is_visible() {
+                Action::SetStereoEqualizerConfiguration(StereoEqualizerConfiguration {
+                    left: equalizer_configuration,
+                    right: EqualizerConfiguration::new_custom_profile(
+                        self.right_equalizer.volume_adjustments(),
+                    ),
+                })
+            } else {
+                Action::SetEqualizerConfiguration(equalizer_configuration)
+            };
+            sender.send(action).unwrap();
+        }
+
+        pub fn set_right_equalizer_configuration(
+            &self,
+            equalizer_configuration: Option<&EqualizerConfiguration>,
+        ) {
+            match equalizer_configuration {
+                Some(equalizer_configuration) => {
+                    self.right_equalizer
+                        .set_volumes(&equalizer_configuration.volume_adjustments().adjustments());
+                    self.right_channel.set_visible(true);
+                }
+                None => self.right_channel.set_visible(false),
+            }
+        }
+
         pub fn equalizer_configuration(&self) -> EqualizerConfiguration {
             if self.is_custom_profile() {
                 EqualizerConfiguration::new_custom_profile(self.equalizer.volume_adjustments())
@@ -170,7 +222,7 @@ mod imp {
                     .expect("an item must be selected")
                     .downcast::<GlibEqualizerProfile>()
                     .expect("selected item must be an EqualizerProfileObject");
-                EqualizerConfiguration::new_from_preset_profile(
+                self.with_current_extra_bands(EqualizerConfiguration::new_from_preset_profile(
                     PresetEqualizerProfile::from_id(selection.profile_id() as u16).unwrap_or_else(
                         || {
                             panic!(
@@ -179,10 +231,27 @@ mod imp {
                             );
                         },
                     ),
-                )
+                ))
             }
         }
 
+        // Preset profiles only cover the first 8 bands, so keep whatever is displayed for the rest
+        fn with_current_extra_bands(
+            &self,
+            equalizer_configuration: EqualizerConfiguration,
+        ) -> EqualizerConfiguration {
+            equalizer_configuration
+                .with_extra_bands(
+                    self.equalizer
+                        .volume_adjustments()
+                        .adjustments()
+                        .iter()
+                        .skip(8)
+                        .cloned(),
+                )
+                .expect("the number of bands displayed must already be valid")
+        }
+
         pub fn set_equalizer_configuration(
             &self,
             equalizer_configuration: &EqualizerConfiguration,
@@ -490,7 +559,9 @@ mod imp {
                                 .unwrap_or_else(|| {
                                     panic!("invalid preset profile id {profile_id}");
                                 });
-                            EqualizerConfiguration::new_from_preset_profile(preset_profile)
+                            this.with_current_extra_bands(
+                                EqualizerConfiguration::new_from_preset_profile(preset_profile),
+                            )
                         } else {
                             EqualizerConfiguration::new_custom_profile(
                                 this.equalizer.volume_adjustments(),
@@ -501,9 +572,7 @@ mod imp {
                     // TODO this is needed because this runs once during construction (before sender is set)
                     // see if we can have sender get set before construction maybe?
                     if let Some(sender) = this.sender.get() {
-                        sender
-                            .send(Action::SetEqualizerConfiguration(equalizer_configuration))
-                            .unwrap();
+                        this.send_equalizer_configuration(sender, equalizer_configuration);
                     }
                 }
             ));
@@ -572,11 +641,12 @@ mod tests {
 
     use gtk::{prelude::*, subclass::prelude::*};
     use openscq30_lib::devices::standard::structures::{
-        EqualizerConfiguration, PresetEqualizerProfile, VolumeAdjustments,
+        EqualizerConfiguration, PresetEqualizerProfile, StereoEqualizerConfiguration,
+        VolumeAdjustments,
     };
     use tokio::sync::mpsc;
 
-    use crate::objects::GlibCustomEqualizerProfile;
+    use crate::{actions::Action, objects::GlibCustomEqualizerProfile};
 
     use super::EqualizerSettingsScreen;
 
@@ -622,4 +692,34 @@ mod tests {
         assert!(!settings.imp().create_custom_profile_button.is_visible(),);
         assert!(settings.imp().delete_custom_profile_button.is_visible(),);
     }
+
+    #[gtk::test]
+    fn test_sends_both_channels_when_right_channel_is_shown() {
+        crate::load_resources();
+        let settings = EqualizerSettingsScreen::new();
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        settings.set_sender(sender);
+        let right = EqualizerConfiguration::new_custom_profile(
+            VolumeAdjustments::new([0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]).unwrap(),
+        );
+        settings.set_right_equalizer_configuration(Some(&right));
+        settings.set_equalizer_configuration(&EqualizerConfiguration::new_from_preset_profile(
+            PresetEqualizerProfile::Acoustic,
+        ));
+        settings.imp().profile_dropdown.set_selected(2);
+
+        let mut last_action = None;
+        while let Ok(action) = receiver.try_recv() {
+            last_action = Some(action);
+        }
+        assert_eq!(
+            Some(Action::SetStereoEqualizerConfiguration(
+                StereoEqualizerConfiguration {
+                    left: settings.equalizer_configuration(),
+                    right,
+                }
+            )),
+            last_action,
+        );
+    }
 }
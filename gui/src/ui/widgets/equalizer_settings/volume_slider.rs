@@ -1,4 +1,7 @@
-use gtk::glib::{self, Object};
+use gtk::{
+    glib::{self, Object},
+    subclass::prelude::ObjectSubclassIsExt,
+};
 
 glib::wrapper! {
     pub struct VolumeSlider(ObjectSubclass<imp::VolumeSlider>)
@@ -21,6 +24,11 @@ impl VolumeSlider {
     pub fn set_volume(&self, volume: f64) {
         self.set_volume_slider_value(volume);
     }
+
+    /// Replaces the frequency label, for bands where the frequency isn't known.
+    pub fn set_band_label(&self, label: &str) {
+        self.imp().band_label.set_label(label);
+    }
 }
 
 mod imp {
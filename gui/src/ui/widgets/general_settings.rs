@@ -2,6 +2,7 @@ mod adaptive_noise_canceling_selection;
 mod ambient_sound_mode_cycle_selection;
 mod ambient_sound_mode_selection;
 mod custom_noise_canceling_selection;
+mod device_settings;
 mod general_settings_screen;
 mod manual_noise_canceling_selection;
 mod noise_canceling_mode_selection;
@@ -0,0 +1,215 @@
+use gtk::{
+    glib::{self, Object},
+    subclass::prelude::ObjectSubclassIsExt,
+};
+use openscq30_lib::devices::standard::state::DeviceState;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::actions::Action;
+
+glib::wrapper! {
+    pub struct DeviceSettings(ObjectSubclass<imp::DeviceSettings>)
+        @extends gtk::Box, gtk::Widget,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl DeviceSettings {
+    pub fn new() -> Self {
+        Object::builder().build()
+    }
+
+    pub fn set_sender(&self, sender: UnboundedSender<Action>) {
+        self.imp().set_sender(sender);
+    }
+
+    pub fn set_device_state(&self, state: &DeviceState) {
+        self.imp().set_device_state(state);
+    }
+}
+
+mod imp {
+    use std::cell::{Cell, OnceCell};
+
+    use adw::prelude::*;
+    use gtk::{
+        glib::{self, clone},
+        subclass::{
+            prelude::*,
+            widget::{CompositeTemplateClass, CompositeTemplateInitializingExt, WidgetImpl},
+        },
+        CompositeTemplate, TemplateChild,
+    };
+    use openscq30_lib::devices::standard::{
+        state::DeviceState,
+        structures::{AutoPowerOff, AutoPowerOffDuration, VoicePrompt},
+    };
+    use strum::IntoEnumIterator;
+    use tokio::sync::mpsc::UnboundedSender;
+
+    use crate::actions::Action;
+
+    #[derive(Default, CompositeTemplate)]
+    #[template(resource = "/com/oppzippy/OpenSCQ30/ui/widgets/general_settings/device_settings.ui")]
+    pub struct DeviceSettings {
+        #[template_child]
+        pub wear_detection: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub touch_tone: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub auto_power_off: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub auto_power_off_duration: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub game_mode: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub ldac: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub voice_prompt: TemplateChild<adw::ComboRow>,
+
+        sender: OnceCell<UnboundedSender<Action>>,
+        ignore_events: Cell<bool>,
+    }
+
+    impl DeviceSettings {
+        pub fn set_sender(&self, sender: UnboundedSender<Action>) {
+            self.sender.set(sender).unwrap();
+        }
+
+        pub fn set_device_state(&self, state: &DeviceState) {
+            self.ignore_events.set(true);
+
+            self.wear_detection.set_visible(
+                state.device_features.has_wear_detection && state.wear_detection.is_some(),
+            );
+            self.wear_detection
+                .set_active(state.wear_detection.unwrap_or_default());
+            self.touch_tone
+                .set_visible(state.device_features.has_touch_tone && state.touch_tone.is_some());
+            self.touch_tone
+                .set_active(state.touch_tone.unwrap_or_default());
+
+            let auto_power_off = state
+                .auto_power_off
+                .filter(|_| state.device_features.has_auto_power_off);
+            self.auto_power_off.set_visible(auto_power_off.is_some());
+            self.auto_power_off_duration
+                .set_visible(auto_power_off.is_some());
+            if let Some(auto_power_off) = auto_power_off {
+                self.auto_power_off.set_active(auto_power_off.is_enabled);
+                let index = AutoPowerOffDuration::iter()
+                    .position(|duration| duration == auto_power_off.duration)
+                    .unwrap_or_default();
+                self.auto_power_off_duration.set_selected(index as u32);
+            }
+            self.game_mode
+                .set_visible(state.device_features.has_game_mode && state.game_mode.is_some());
+            self.game_mode
+                .set_active(state.game_mode.unwrap_or_default());
+            self.ldac
+                .set_visible(state.device_features.has_ldac && state.ldac.is_some());
+            self.ldac.set_active(state.ldac.unwrap_or_default());
+            // Devices don't report the voice prompt until it changes, so leave nothing selected
+            // rather than hiding the row. That way the language can still be switched back.
+            self.voice_prompt
+                .set_visible(state.device_features.has_voice_prompt);
+            self.voice_prompt.set_selected(
+                state
+                    .voice_prompt
+                    .and_then(|voice_prompt| {
+                        VoicePrompt::iter().position(|value| value == voice_prompt)
+                    })
+                    .map(|index| index as u32)
+                    .unwrap_or(gtk::INVALID_LIST_POSITION),
+            );
+
+            self.obj().set_visible(
+                self.wear_detection.is_visible()
+                    || self.touch_tone.is_visible()
+                    || self.auto_power_off.is_visible()
+                    || self.game_mode.is_visible()
+                    || self.ldac.is_visible()
+                    || self.voice_prompt.is_visible(),
+            );
+
+            self.ignore_events.set(false);
+        }
+
+        fn auto_power_off_value(&self) -> AutoPowerOff {
+            AutoPowerOff {
+                is_enabled: self.auto_power_off.is_active(),
+                duration: AutoPowerOffDuration::iter()
+                    .nth(self.auto_power_off_duration.selected() as usize)
+                    .unwrap_or_default(),
+            }
+        }
+
+        fn send_action(&self, action: Action) {
+            if !self.ignore_events.get() {
+                self.sender.get().unwrap().send(action).unwrap();
+            }
+        }
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for DeviceSettings {
+        const NAME: &'static str = "OpenSCQ30DeviceSettings";
+        type Type = super::DeviceSettings;
+        type ParentType = gtk::Box;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for DeviceSettings {
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            self.wear_detection.connect_active_notify(clone!(
+                #[weak(rename_to=this)]
+                self,
+                move |row| this.send_action(Action::SetWearDetection(row.is_active()))
+            ));
+            self.touch_tone.connect_active_notify(clone!(
+                #[weak(rename_to=this)]
+                self,
+                move |row| this.send_action(Action::SetTouchTone(row.is_active()))
+            ));
+            self.auto_power_off.connect_active_notify(clone!(
+                #[weak(rename_to=this)]
+                self,
+                move |_| this.send_action(Action::SetAutoPowerOff(this.auto_power_off_value()))
+            ));
+            self.auto_power_off_duration.connect_selected_notify(clone!(
+                #[weak(rename_to=this)]
+                self,
+                move |_| this.send_action(Action::SetAutoPowerOff(this.auto_power_off_value()))
+            ));
+            self.game_mode.connect_active_notify(clone!(
+                #[weak(rename_to=this)]
+                self,
+                move |row| this.send_action(Action::SetGameMode(row.is_active()))
+            ));
+            self.ldac.connect_active_notify(clone!(
+                #[weak(rename_to=this)]
+                self,
+                move |row| this.send_action(Action::SetLdac(row.is_active()))
+            ));
+            self.voice_prompt.connect_selected_notify(clone!(
+                #[weak(rename_to=this)]
+                self,
+                move |row| {
+                    if let Some(voice_prompt) = VoicePrompt::iter().nth(row.selected() as usize) {
+                        this.send_action(Action::SetVoicePrompt(voice_prompt))
+                    }
+                }
+            ));
+        }
+    }
+    impl WidgetImpl for DeviceSettings {}
+    impl BoxImpl for DeviceSettings {}
+}
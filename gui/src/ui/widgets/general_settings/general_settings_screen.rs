@@ -30,7 +30,8 @@ mod imp {
     use crate::{
         actions::Action,
         ui::widgets::general_settings::{
-            sound_modes::SoundModes, sound_modes_type_two::SoundModesTypeTwo,
+            device_settings::DeviceSettings, sound_modes::SoundModes,
+            sound_modes_type_two::SoundModesTypeTwo,
         },
     };
     use gtk::{
@@ -58,6 +59,8 @@ mod imp {
         pub sound_modes: TemplateChild<SoundModes>,
         #[template_child]
         pub sound_modes_type_two: TemplateChild<SoundModesTypeTwo>,
+        #[template_child]
+        pub device_settings: TemplateChild<DeviceSettings>,
 
         sender: OnceCell<UnboundedSender<Action>>,
     }
@@ -67,6 +70,7 @@ mod imp {
         pub fn set_sender(&self, sender: UnboundedSender<Action>) {
             self.sound_modes.set_sender(sender.clone());
             self.sound_modes_type_two.set_sender(sender.clone());
+            self.device_settings.set_sender(sender.clone());
             self.sender.set(sender).unwrap();
         }
 
@@ -76,6 +80,7 @@ mod imp {
             self.sound_modes_type_two.set_device_state(state);
             self.sound_modes_type_two
                 .set_visible(state.sound_modes_type_two.is_some());
+            self.device_settings.set_device_state(state);
         }
 
         fn send_action(&self, action: Action) {
@@ -120,6 +120,8 @@ mod imp {
             self.general_settings.set_device_state(state);
             self.equalizer_settings
                 .set_equalizer_configuration(&state.equalizer_configuration);
+            self.equalizer_settings
+                .set_right_equalizer_configuration(state.right_equalizer_configuration.as_ref());
             self.device_information.set_device_state(state);
             self.quick_presets.set_device_state(state);
 
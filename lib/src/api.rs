@@ -1,48 +1,60 @@
 pub mod connection;
 pub mod device;
 
-#[cfg(any(feature = "bluetooth", feature = "demo"))]
-use crate::futures::Futures;
+use crate::{demo::device::DemoDeviceRegistry, futures::Futures};
 
-#[cfg(any(feature = "bluetooth", feature = "demo"))]
 use self::device::DeviceRegistry;
 
-#[cfg(all(feature = "bluetooth", not(feature = "demo")))]
-pub async fn new_soundcore_device_registry(
-    handle: tokio::runtime::Handle,
-) -> crate::Result<impl DeviceRegistry> {
-    use crate::{futures::TokioFutures, soundcore_device::device::SoundcoreDeviceRegistry};
-    let connection_registry =
-        crate::soundcore_device::connection::new_connection_registry(Some(handle)).await?;
-    SoundcoreDeviceRegistry::<_, TokioFutures>::new(connection_registry).await
-}
-
-#[cfg(all(feature = "bluetooth", not(feature = "demo")))]
-pub async fn new_soundcore_device_registry_with_custom_runtime<FuturesType>(
-) -> crate::Result<impl DeviceRegistry>
+/// Offers demo devices only. Unlike the other constructors, this doesn't need bluetooth support.
+pub fn new_demo_device_registry<FuturesType>() -> impl DeviceRegistry
 where
-    FuturesType: Futures,
+    FuturesType: Futures + 'static,
 {
-    use crate::soundcore_device::device::SoundcoreDeviceRegistry;
-    let connection_registry =
-        crate::soundcore_device::connection::new_connection_registry(None).await?;
-    SoundcoreDeviceRegistry::<_, FuturesType>::new(connection_registry).await
+    DemoDeviceRegistry::<FuturesType>::new()
 }
 
-#[cfg(feature = "demo")]
+/// With `demo` set, demo devices are offered instead of the ones found over bluetooth.
+#[cfg(feature = "bluetooth")]
 pub async fn new_soundcore_device_registry(
-    _handle: tokio::runtime::Handle,
+    handle: tokio::runtime::Handle,
+    demo: bool,
 ) -> crate::Result<impl DeviceRegistry> {
-    use crate::{demo::device::DemoDeviceRegistry, futures::TokioFutures};
-    Ok(DemoDeviceRegistry::<TokioFutures>::new())
+    use self::device::EitherDeviceRegistry;
+    use crate::{futures::TokioFutures, soundcore_device::device::SoundcoreDeviceRegistry};
+    Ok(if demo {
+        EitherDeviceRegistry::Right(new_demo_device_registry::<TokioFutures>())
+    } else {
+        let connection_registry =
+            crate::soundcore_device::connection::new_connection_registry(Some(handle)).await?;
+        EitherDeviceRegistry::Left(
+            SoundcoreDeviceRegistry::<_, TokioFutures>::new(connection_registry).await?,
+        )
+    })
 }
 
-#[cfg(feature = "demo")]
+/// With `demo` set, demo devices are offered instead of the ones found over bluetooth. Otherwise,
+/// if `battery_polling_interval` is set, devices poll their battery level in the background, so
+/// [`Futures::spawn_local`] must then work wherever devices are fetched.
+#[cfg(feature = "bluetooth")]
 pub async fn new_soundcore_device_registry_with_custom_runtime<FuturesType>(
+    demo: bool,
+    battery_polling_interval: Option<std::time::Duration>,
 ) -> crate::Result<impl DeviceRegistry>
 where
-    FuturesType: Futures,
+    FuturesType: Futures + 'static,
 {
-    use crate::demo::device::DemoDeviceRegistry;
-    Ok(DemoDeviceRegistry::<FuturesType>::new())
+    use self::device::EitherDeviceRegistry;
+    use crate::soundcore_device::device::SoundcoreDeviceRegistry;
+    Ok(if demo {
+        EitherDeviceRegistry::Right(new_demo_device_registry::<FuturesType>())
+    } else {
+        let connection_registry =
+            crate::soundcore_device::connection::new_connection_registry(None).await?;
+        let mut registry =
+            SoundcoreDeviceRegistry::<_, FuturesType>::new(connection_registry).await?;
+        if let Some(interval) = battery_polling_interval {
+            registry = registry.with_battery_polling(interval);
+        }
+        EitherDeviceRegistry::Left(registry)
+    })
 }
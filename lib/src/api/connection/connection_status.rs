@@ -2,4 +2,7 @@
 pub enum ConnectionStatus {
     Connected,
     Disconnected,
+    /// The connection was lost and is being re-established. Only reported by devices that have a
+    /// reconnect supervisor running, in place of [`ConnectionStatus::Disconnected`].
+    Reconnecting,
 }
@@ -1,9 +1,25 @@
+mod composite_device_descriptor;
+mod composite_device_registry;
 mod device;
 mod device_descriptor;
 mod device_registry;
+mod dyn_device;
+mod dyn_device_registry;
+mod either_device;
+mod either_device_descriptor;
+mod either_device_registry;
 mod generic_device_descriptor;
+mod setting_change;
 
+pub use composite_device_descriptor::*;
+pub use composite_device_registry::*;
 pub use device::*;
 pub use device_descriptor::*;
 pub use device_registry::*;
+pub use dyn_device::*;
+pub use dyn_device_registry::*;
+pub use either_device::*;
+pub use either_device_descriptor::*;
+pub use either_device_registry::*;
 pub use generic_device_descriptor::*;
+pub use setting_change::*;
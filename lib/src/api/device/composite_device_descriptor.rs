@@ -0,0 +1,45 @@
+use macaddr::MacAddr6;
+
+use super::{DeviceDescriptor, EitherDeviceDescriptor};
+
+/// A descriptor from a [`CompositeDeviceRegistry`](super::CompositeDeviceRegistry), tagged with
+/// the source of the registry that it came from.
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub struct CompositeDeviceDescriptor<LeftType, RightType> {
+    source: String,
+    descriptor: EitherDeviceDescriptor<LeftType, RightType>,
+}
+
+impl<LeftType, RightType> CompositeDeviceDescriptor<LeftType, RightType> {
+    pub fn new(
+        source: impl Into<String>,
+        descriptor: EitherDeviceDescriptor<LeftType, RightType>,
+    ) -> Self {
+        Self {
+            source: source.into(),
+            descriptor,
+        }
+    }
+
+    pub fn descriptor(&self) -> &EitherDeviceDescriptor<LeftType, RightType> {
+        &self.descriptor
+    }
+}
+
+impl<LeftType, RightType> DeviceDescriptor for CompositeDeviceDescriptor<LeftType, RightType>
+where
+    LeftType: DeviceDescriptor,
+    RightType: DeviceDescriptor,
+{
+    fn name(&self) -> &str {
+        self.descriptor.name()
+    }
+
+    fn mac_address(&self) -> MacAddr6 {
+        self.descriptor.mac_address()
+    }
+
+    fn source(&self) -> Option<&str> {
+        Some(&self.source)
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    rc::Rc,
+};
+
+use macaddr::MacAddr6;
+use tokio::sync::Mutex;
+
+use super::{
+    CompositeDeviceDescriptor, DeviceDescriptor, DeviceRegistry, EitherDevice,
+    EitherDeviceDescriptor,
+};
+
+/// Offers the devices of two registries as one list, for example real devices alongside demo
+/// devices. Each descriptor is tagged with the source of the registry it came from, and devices
+/// are fetched from that same registry. Composite registries can be nested to combine more than
+/// two, in which case descriptors keep the source of the innermost registry.
+///
+/// If both registries list a device with the same mac address, only the left one is offered. If one
+/// registry fails to list its devices, the failure is logged and the other registry's devices are
+/// still offered. An error is only returned when both fail.
+pub struct CompositeDeviceRegistry<LeftType, RightType> {
+    left_source: String,
+    left: LeftType,
+    right_source: String,
+    right: RightType,
+    backends: Mutex<HashMap<MacAddr6, Backend>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backend {
+    Left,
+    Right,
+}
+
+impl<LeftType, RightType> CompositeDeviceRegistry<LeftType, RightType>
+where
+    LeftType: DeviceRegistry,
+    RightType: DeviceRegistry,
+{
+    pub fn new(
+        left_source: impl Into<String>,
+        left: LeftType,
+        right_source: impl Into<String>,
+        right: RightType,
+    ) -> Self {
+        Self {
+            left_source: left_source.into(),
+            left,
+            right_source: right_source.into(),
+            right,
+            backends: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn descriptors_or_log<T>(&self, source: &str, descriptors: crate::Result<Vec<T>>) -> Vec<T> {
+        descriptors.unwrap_or_else(|err| {
+            tracing::warn!("failed to get device descriptors from {source}: {err:?}");
+            Vec::new()
+        })
+    }
+
+    async fn backend(&self, mac_address: MacAddr6) -> crate::Result<Option<Backend>> {
+        if let Some(backend) = self.backends.lock().await.get(&mac_address) {
+            return Ok(Some(*backend));
+        }
+        // The device may have shown up since descriptors were last fetched
+        self.device_descriptors().await?;
+        Ok(self.backends.lock().await.get(&mac_address).copied())
+    }
+}
+
+impl<LeftType, RightType> DeviceRegistry for CompositeDeviceRegistry<LeftType, RightType>
+where
+    LeftType: DeviceRegistry,
+    RightType: DeviceRegistry,
+{
+    type DeviceType = EitherDevice<LeftType::DeviceType, RightType::DeviceType>;
+    type DescriptorType =
+        CompositeDeviceDescriptor<LeftType::DescriptorType, RightType::DescriptorType>;
+
+    async fn device_descriptors(&self) -> crate::Result<Vec<Self::DescriptorType>> {
+        let (left, right) = futures::join!(
+            self.left.device_descriptors(),
+            self.right.device_descriptors(),
+        );
+        let (left, right) = match (left, right) {
+            (Err(left_err), Err(right_err)) => {
+                tracing::warn!(
+                    "failed to get device descriptors from {}: {right_err:?}",
+                    self.right_source,
+                );
+                return Err(left_err);
+            }
+            (left, right) => (
+                self.descriptors_or_log(&self.left_source, left),
+                self.descriptors_or_log(&self.right_source, right),
+            ),
+        };
+
+        let mut backends = self.backends.lock().await;
+        backends.clear();
+        let descriptors = left
+            .into_iter()
+            .map(|descriptor| (Backend::Left, EitherDeviceDescriptor::Left(descriptor)))
+            .chain(
+                right
+                    .into_iter()
+                    .map(|descriptor| (Backend::Right, EitherDeviceDescriptor::Right(descriptor))),
+            )
+            .filter(
+                |(backend, descriptor)| match backends.entry(descriptor.mac_address()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(*backend);
+                        true
+                    }
+                    Entry::Occupied(_) => false,
+                },
+            )
+            .map(|(backend, descriptor)| {
+                let source = match backend {
+                    Backend::Left => &self.left_source,
+                    Backend::Right => &self.right_source,
+                };
+                CompositeDeviceDescriptor::new(
+                    descriptor.source().unwrap_or(source).to_owned(),
+                    descriptor,
+                )
+            })
+            .collect();
+        Ok(descriptors)
+    }
+
+    async fn device(&self, mac_address: MacAddr6) -> crate::Result<Option<Rc<Self::DeviceType>>> {
+        Ok(match self.backend(mac_address).await? {
+            Some(Backend::Left) => self
+                .left
+                .device(mac_address)
+                .await?
+                .map(|device| Rc::new(EitherDevice::Left(device))),
+            Some(Backend::Right) => self
+                .right
+                .device(mac_address)
+                .await?
+                .map(|device| Rc::new(EitherDevice::Right(device))),
+            None => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use macaddr::MacAddr6;
+
+    use crate::{
+        api::device::{Device, DeviceDescriptor, DeviceRegistry, GenericDeviceDescriptor},
+        demo::device::{DemoDevice, DemoDeviceRegistry},
+        emulator::{EmulatedConnection, EmulatedConnectionRegistry},
+        futures::TokioFutures,
+        soundcore_device::{device::SoundcoreDeviceRegistry, device_model::DeviceModel},
+    };
+
+    use super::CompositeDeviceRegistry;
+
+    async fn emulated_registry(
+        connections: impl IntoIterator<Item = EmulatedConnection>,
+    ) -> SoundcoreDeviceRegistry<EmulatedConnectionRegistry, TokioFutures> {
+        SoundcoreDeviceRegistry::new(EmulatedConnectionRegistry::new(connections))
+            .await
+            .unwrap()
+    }
+
+    struct FailingRegistry;
+
+    impl DeviceRegistry for FailingRegistry {
+        type DeviceType = DemoDevice<TokioFutures>;
+        type DescriptorType = GenericDeviceDescriptor;
+
+        async fn device_descriptors(&self) -> crate::Result<Vec<Self::DescriptorType>> {
+            Err(crate::Error::BluetoothAdapterNotAvailable { source: None })
+        }
+
+        async fn device(
+            &self,
+            _mac_address: MacAddr6,
+        ) -> crate::Result<Option<Rc<Self::DeviceType>>> {
+            Err(crate::Error::BluetoothAdapterNotAvailable { source: None })
+        }
+    }
+
+    fn sources(descriptors: &[impl DeviceDescriptor]) -> Vec<(MacAddr6, Option<&str>)> {
+        descriptors
+            .iter()
+            .map(|descriptor| (descriptor.mac_address(), descriptor.source()))
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_tags_descriptors_with_their_source() {
+        let registry = CompositeDeviceRegistry::new(
+            "emulated",
+            emulated_registry(EmulatedConnection::new(
+                DeviceModel::A3028,
+                MacAddr6::new(0x02, 0, 0, 0, 0, 1),
+            ))
+            .await,
+            "demo",
+            DemoDeviceRegistry::<TokioFutures>::new(),
+        );
+        let descriptors = registry.device_descriptors().await.unwrap();
+
+        assert_eq!(
+            Some(&(MacAddr6::new(0x02, 0, 0, 0, 0, 1), Some("emulated"))),
+            sources(&descriptors).first(),
+        );
+        assert_eq!(
+            DemoDeviceRegistry::<TokioFutures>::new()
+                .device_descriptors()
+                .await
+                .unwrap()
+                .len(),
+            sources(&descriptors)
+                .iter()
+                .filter(|(_, source)| *source == Some("demo"))
+                .count(),
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_keeps_the_innermost_source_when_nested() {
+        let registry = CompositeDeviceRegistry::new(
+            "outer",
+            CompositeDeviceRegistry::new(
+                "first",
+                emulated_registry(EmulatedConnection::new(
+                    DeviceModel::A3028,
+                    MacAddr6::new(0x02, 0, 0, 0, 0, 1),
+                ))
+                .await,
+                "second",
+                emulated_registry(EmulatedConnection::new(
+                    DeviceModel::A3951,
+                    MacAddr6::new(0x02, 0, 0, 0, 0, 2),
+                ))
+                .await,
+            ),
+            "third",
+            emulated_registry(EmulatedConnection::new(
+                DeviceModel::A3936,
+                MacAddr6::new(0x02, 0, 0, 0, 0, 3),
+            ))
+            .await,
+        );
+        let descriptors = registry.device_descriptors().await.unwrap();
+
+        assert_eq!(
+            vec![
+                (MacAddr6::new(0x02, 0, 0, 0, 0, 1), Some("first")),
+                (MacAddr6::new(0x02, 0, 0, 0, 0, 2), Some("second")),
+                (MacAddr6::new(0x02, 0, 0, 0, 0, 3), Some("third")),
+            ],
+            sources(&descriptors),
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_routes_devices_to_the_registry_that_listed_them() {
+        let registry = CompositeDeviceRegistry::new(
+            "emulated",
+            emulated_registry(EmulatedConnection::new(
+                DeviceModel::A3028,
+                MacAddr6::new(0x02, 0, 0, 0, 0, 1),
+            ))
+            .await,
+            "demo",
+            DemoDeviceRegistry::<TokioFutures>::new(),
+        );
+
+        // Descriptors haven't been fetched yet, so the registry has to find the backend itself
+        let emulated = registry
+            .device(MacAddr6::new(0x02, 0, 0, 0, 0, 1))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!("Emulated A3028", emulated.name().await.unwrap());
+
+        let demo = registry
+            .device(MacAddr6::new(0, 0, 0, 0, 0x30, 0x28))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!("Demo A3028", demo.name().await.unwrap());
+
+        assert!(registry
+            .device(MacAddr6::broadcast())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_prefers_the_left_registry_for_duplicate_mac_addresses() {
+        let mac_address = MacAddr6::new(0x02, 0, 0, 0, 0, 1);
+        let registry = CompositeDeviceRegistry::new(
+            "left",
+            emulated_registry(EmulatedConnection::new(DeviceModel::A3028, mac_address)).await,
+            "right",
+            emulated_registry(EmulatedConnection::new(DeviceModel::A3951, mac_address)).await,
+        );
+        let descriptors = registry.device_descriptors().await.unwrap();
+
+        assert_eq!(vec![(mac_address, Some("left"))], sources(&descriptors));
+        let device = registry.device(mac_address).await.unwrap().unwrap();
+        assert_eq!("Emulated A3028", device.name().await.unwrap());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_offers_the_devices_of_one_registry_when_the_other_fails() {
+        let registry = CompositeDeviceRegistry::new(
+            "failing",
+            FailingRegistry,
+            "emulated",
+            emulated_registry(EmulatedConnection::new(
+                DeviceModel::A3028,
+                MacAddr6::new(0x02, 0, 0, 0, 0, 1),
+            ))
+            .await,
+        );
+        let descriptors = registry.device_descriptors().await.unwrap();
+
+        assert_eq!(
+            vec![(MacAddr6::new(0x02, 0, 0, 0, 0, 1), Some("emulated"))],
+            sources(&descriptors),
+        );
+        let device = registry
+            .device(MacAddr6::new(0x02, 0, 0, 0, 0, 1))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!("Emulated A3028", device.name().await.unwrap());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_fails_when_both_registries_fail() {
+        let registry =
+            CompositeDeviceRegistry::new("first", FailingRegistry, "second", FailingRegistry);
+        assert!(registry.device_descriptors().await.is_err());
+    }
+}
@@ -1,14 +1,17 @@
 use macaddr::MacAddr6;
-use tokio::sync::watch;
+use tokio::sync::{broadcast, watch};
 use uuid::Uuid;
 
+use super::{apply_with_rollback, DeviceSetters, SettingChange};
 use crate::{
     api::connection::ConnectionStatus,
     devices::standard::{
+        device_event::DeviceEvent,
         state::DeviceState,
         structures::{
-            AmbientSoundModeCycle, EqualizerConfiguration, HearId, MultiButtonConfiguration,
-            SoundModes, SoundModesTypeTwo,
+            AmbientSoundModeCycle, AutoPowerOff, EqualizerConfiguration, HearId,
+            MultiButtonConfiguration, SoundModes, SoundModesTypeTwo, StereoEqualizerConfiguration,
+            VoicePrompt,
         },
     },
 };
@@ -16,6 +19,11 @@ use crate::{
 pub trait Device {
     async fn subscribe_to_state_updates(&self) -> watch::Receiver<DeviceState>;
 
+    /// Unlike [`Device::subscribe_to_state_updates`], every change is delivered, even if several
+    /// happen in quick succession. Subscribers that fall too far behind receive
+    /// [`broadcast::error::RecvError::Lagged`].
+    fn subscribe_to_events(&self) -> broadcast::Receiver<DeviceEvent>;
+
     async fn mac_address(&self) -> crate::Result<MacAddr6>;
 
     fn service_uuid(&self) -> Uuid;
@@ -37,9 +45,35 @@ pub trait Device {
         configuration: EqualizerConfiguration,
     ) -> crate::Result<()>;
 
+    async fn set_stereo_equalizer_configuration(
+        &self,
+        configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<()>;
+
     async fn set_hear_id(&self, hear_id: HearId) -> crate::Result<()>;
     async fn set_multi_button_configuration(
         &self,
         button_configuration: MultiButtonConfiguration,
     ) -> crate::Result<()>;
+
+    async fn set_wear_detection(&self, is_enabled: bool) -> crate::Result<()>;
+    async fn set_touch_tone(&self, is_enabled: bool) -> crate::Result<()>;
+    async fn set_auto_power_off(&self, auto_power_off: AutoPowerOff) -> crate::Result<()>;
+
+    async fn set_game_mode(&self, is_enabled: bool) -> crate::Result<()>;
+    async fn set_ldac(&self, is_enabled: bool) -> crate::Result<()>;
+
+    async fn set_voice_prompt(&self, voice_prompt: VoicePrompt) -> crate::Result<()>;
+
+    /// Makes every change in `changes`, in order. If one fails, it and the changes before it are
+    /// reverted to their previous values, and the returned
+    /// [`Error::ApplyFailed`](crate::Error::ApplyFailed) says which change failed and whether
+    /// reverting worked.
+    ///
+    /// The default implementation goes through the individual setters, so other changes can happen
+    /// in between, and a setter that fails partway is only reverted if the device's state reflects
+    /// what was applied. Implementations should override it to apply the whole batch at once.
+    async fn apply(&self, changes: Vec<SettingChange>) -> crate::Result<()> {
+        apply_with_rollback(&mut DeviceSetters(self), changes).await
+    }
 }
@@ -3,4 +3,27 @@ use macaddr::MacAddr6;
 pub trait DeviceDescriptor {
     fn name(&self) -> &str;
     fn mac_address(&self) -> MacAddr6;
+
+    /// The backend the device was found by, if the descriptor came from a
+    /// [`CompositeDeviceRegistry`](super::CompositeDeviceRegistry).
+    fn source(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl<T> DeviceDescriptor for Box<T>
+where
+    T: DeviceDescriptor + ?Sized,
+{
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn mac_address(&self) -> MacAddr6 {
+        (**self).mac_address()
+    }
+
+    fn source(&self) -> Option<&str> {
+        (**self).source()
+    }
 }
@@ -0,0 +1,197 @@
+use std::fmt::Debug;
+
+use futures::future::LocalBoxFuture;
+use macaddr::MacAddr6;
+use tokio::sync::{broadcast, watch};
+use uuid::Uuid;
+
+use super::{Device, SettingChange};
+use crate::{
+    api::connection::ConnectionStatus,
+    devices::standard::{
+        device_event::DeviceEvent,
+        state::DeviceState,
+        structures::{
+            AmbientSoundModeCycle, AutoPowerOff, EqualizerConfiguration, HearId,
+            MultiButtonConfiguration, SoundModes, SoundModesTypeTwo, StereoEqualizerConfiguration,
+            VoicePrompt,
+        },
+    },
+};
+
+/// An object safe version of [`Device`], so that devices of different types can be used as
+/// `dyn DynDevice`. Every [`Device`] implements it, with the futures boxed.
+pub trait DynDevice: Debug {
+    fn subscribe_to_state_updates(&self) -> LocalBoxFuture<'_, watch::Receiver<DeviceState>>;
+
+    fn subscribe_to_events(&self) -> broadcast::Receiver<DeviceEvent>;
+
+    fn mac_address(&self) -> LocalBoxFuture<'_, crate::Result<MacAddr6>>;
+
+    fn service_uuid(&self) -> Uuid;
+
+    fn name(&self) -> LocalBoxFuture<'_, crate::Result<String>>;
+
+    fn connection_status(&self) -> watch::Receiver<ConnectionStatus>;
+
+    fn state(&self) -> LocalBoxFuture<'_, DeviceState>;
+
+    fn set_sound_modes(&self, sound_modes: SoundModes) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_sound_modes_type_two(
+        &self,
+        sound_modes: SoundModesTypeTwo,
+    ) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_ambient_sound_mode_cycle(
+        &self,
+        cycle: AmbientSoundModeCycle,
+    ) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_equalizer_configuration(
+        &self,
+        configuration: EqualizerConfiguration,
+    ) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_stereo_equalizer_configuration(
+        &self,
+        configuration: StereoEqualizerConfiguration,
+    ) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_hear_id(&self, hear_id: HearId) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_multi_button_configuration(
+        &self,
+        button_configuration: MultiButtonConfiguration,
+    ) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_wear_detection(&self, is_enabled: bool) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_touch_tone(&self, is_enabled: bool) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_auto_power_off(
+        &self,
+        auto_power_off: AutoPowerOff,
+    ) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_game_mode(&self, is_enabled: bool) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_ldac(&self, is_enabled: bool) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn set_voice_prompt(&self, voice_prompt: VoicePrompt) -> LocalBoxFuture<'_, crate::Result<()>>;
+
+    fn apply(&self, changes: Vec<SettingChange>) -> LocalBoxFuture<'_, crate::Result<()>>;
+}
+
+impl<T> DynDevice for T
+where
+    T: Device + Debug,
+{
+    fn subscribe_to_state_updates(&self) -> LocalBoxFuture<'_, watch::Receiver<DeviceState>> {
+        Box::pin(Device::subscribe_to_state_updates(self))
+    }
+
+    fn subscribe_to_events(&self) -> broadcast::Receiver<DeviceEvent> {
+        Device::subscribe_to_events(self)
+    }
+
+    fn mac_address(&self) -> LocalBoxFuture<'_, crate::Result<MacAddr6>> {
+        Box::pin(Device::mac_address(self))
+    }
+
+    fn service_uuid(&self) -> Uuid {
+        Device::service_uuid(self)
+    }
+
+    fn name(&self) -> LocalBoxFuture<'_, crate::Result<String>> {
+        Box::pin(Device::name(self))
+    }
+
+    fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
+        Device::connection_status(self)
+    }
+
+    fn state(&self) -> LocalBoxFuture<'_, DeviceState> {
+        Box::pin(Device::state(self))
+    }
+
+    fn set_sound_modes(&self, sound_modes: SoundModes) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_sound_modes(self, sound_modes))
+    }
+
+    fn set_sound_modes_type_two(
+        &self,
+        sound_modes: SoundModesTypeTwo,
+    ) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_sound_modes_type_two(self, sound_modes))
+    }
+
+    fn set_ambient_sound_mode_cycle(
+        &self,
+        cycle: AmbientSoundModeCycle,
+    ) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_ambient_sound_mode_cycle(self, cycle))
+    }
+
+    fn set_equalizer_configuration(
+        &self,
+        configuration: EqualizerConfiguration,
+    ) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_equalizer_configuration(self, configuration))
+    }
+
+    fn set_stereo_equalizer_configuration(
+        &self,
+        configuration: StereoEqualizerConfiguration,
+    ) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_stereo_equalizer_configuration(
+            self,
+            configuration,
+        ))
+    }
+
+    fn set_hear_id(&self, hear_id: HearId) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_hear_id(self, hear_id))
+    }
+
+    fn set_multi_button_configuration(
+        &self,
+        button_configuration: MultiButtonConfiguration,
+    ) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_multi_button_configuration(
+            self,
+            button_configuration,
+        ))
+    }
+
+    fn set_wear_detection(&self, is_enabled: bool) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_wear_detection(self, is_enabled))
+    }
+
+    fn set_touch_tone(&self, is_enabled: bool) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_touch_tone(self, is_enabled))
+    }
+
+    fn set_auto_power_off(
+        &self,
+        auto_power_off: AutoPowerOff,
+    ) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_auto_power_off(self, auto_power_off))
+    }
+
+    fn set_game_mode(&self, is_enabled: bool) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_game_mode(self, is_enabled))
+    }
+
+    fn set_ldac(&self, is_enabled: bool) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_ldac(self, is_enabled))
+    }
+
+    fn set_voice_prompt(&self, voice_prompt: VoicePrompt) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::set_voice_prompt(self, voice_prompt))
+    }
+
+    fn apply(&self, changes: Vec<SettingChange>) -> LocalBoxFuture<'_, crate::Result<()>> {
+        Box::pin(Device::apply(self, changes))
+    }
+}
@@ -0,0 +1,113 @@
+use std::rc::Rc;
+
+use futures::future::LocalBoxFuture;
+use macaddr::MacAddr6;
+
+use super::{DeviceDescriptor, DeviceRegistry, DynDevice};
+
+/// An object safe version of [`DeviceRegistry`], so that the backend can be chosen at runtime and
+/// registries of different types can be used as `dyn DynDeviceRegistry`. Every [`DeviceRegistry`]
+/// implements it, with the futures, descriptors, and devices boxed.
+pub trait DynDeviceRegistry {
+    fn device_descriptors(
+        &self,
+    ) -> LocalBoxFuture<'_, crate::Result<Vec<Box<dyn DeviceDescriptor + Send + Sync>>>>;
+
+    fn device(
+        &self,
+        mac_address: MacAddr6,
+    ) -> LocalBoxFuture<'_, crate::Result<Option<Rc<dyn DynDevice>>>>;
+}
+
+impl<T> DynDeviceRegistry for T
+where
+    T: DeviceRegistry,
+    T::DeviceType: 'static,
+    T::DescriptorType: 'static,
+{
+    fn device_descriptors(
+        &self,
+    ) -> LocalBoxFuture<'_, crate::Result<Vec<Box<dyn DeviceDescriptor + Send + Sync>>>> {
+        Box::pin(async move {
+            Ok(DeviceRegistry::device_descriptors(self)
+                .await?
+                .into_iter()
+                .map(|descriptor| Box::new(descriptor) as Box<dyn DeviceDescriptor + Send + Sync>)
+                .collect())
+        })
+    }
+
+    fn device(
+        &self,
+        mac_address: MacAddr6,
+    ) -> LocalBoxFuture<'_, crate::Result<Option<Rc<dyn DynDevice>>>> {
+        Box::pin(async move {
+            Ok(DeviceRegistry::device(self, mac_address)
+                .await?
+                .map(|device| device as Rc<dyn DynDevice>))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use macaddr::MacAddr6;
+
+    use crate::{
+        api::device::{DeviceDescriptor, DynDevice},
+        demo::device::DemoDeviceRegistry,
+        devices::standard::structures::{AmbientSoundMode, SoundModes},
+        emulator::{EmulatedConnection, EmulatedConnectionRegistry},
+        futures::TokioFutures,
+        soundcore_device::{device::SoundcoreDeviceRegistry, device_model::DeviceModel},
+    };
+
+    use super::DynDeviceRegistry;
+
+    async fn registries() -> Vec<Rc<dyn DynDeviceRegistry>> {
+        vec![
+            Rc::new(
+                SoundcoreDeviceRegistry::<_, TokioFutures>::new(EmulatedConnectionRegistry::new(
+                    EmulatedConnection::new(DeviceModel::A3028, MacAddr6::new(0x02, 0, 0, 0, 0, 1)),
+                ))
+                .await
+                .unwrap(),
+            ),
+            Rc::new(DemoDeviceRegistry::<TokioFutures>::new()),
+        ]
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_lists_devices_from_registries_of_different_types() {
+        let mut names = Vec::new();
+        for registry in registries().await {
+            for descriptor in registry.device_descriptors().await.unwrap() {
+                names.push(descriptor.name().to_owned());
+            }
+        }
+        assert!(names.contains(&"Emulated A3028".to_owned()));
+        assert!(names.contains(&"Demo A3028".to_owned()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_holds_devices_of_different_types_together() {
+        let mut devices: Vec<Rc<dyn DynDevice>> = Vec::new();
+        for (registry, mac_address) in registries().await.into_iter().zip([
+            MacAddr6::new(0x02, 0, 0, 0, 0, 1),
+            MacAddr6::new(0, 0, 0, 0, 0x30, 0x28),
+        ]) {
+            devices.push(registry.device(mac_address).await.unwrap().unwrap());
+        }
+
+        let sound_modes = SoundModes {
+            ambient_sound_mode: AmbientSoundMode::Transparency,
+            ..Default::default()
+        };
+        for device in devices {
+            device.set_sound_modes(sound_modes).await.unwrap();
+            assert_eq!(Some(sound_modes), device.state().await.sound_modes);
+        }
+    }
+}
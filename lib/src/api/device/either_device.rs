@@ -0,0 +1,208 @@
+use std::rc::Rc;
+
+use macaddr::MacAddr6;
+use tokio::sync::{broadcast, watch};
+use uuid::Uuid;
+
+use super::{Device, SettingChange};
+use crate::{
+    api::connection::ConnectionStatus,
+    devices::standard::{
+        device_event::DeviceEvent,
+        state::DeviceState,
+        structures::{
+            AmbientSoundModeCycle, AutoPowerOff, EqualizerConfiguration, HearId,
+            MultiButtonConfiguration, SoundModes, SoundModesTypeTwo, StereoEqualizerConfiguration,
+            VoicePrompt,
+        },
+    },
+};
+
+/// A device from one of two [`DeviceRegistry`](super::DeviceRegistry) implementations. Every
+/// method, including [`Device::apply`], is passed through to the wrapped device.
+#[derive(Debug)]
+pub enum EitherDevice<LeftType, RightType> {
+    Left(Rc<LeftType>),
+    Right(Rc<RightType>),
+}
+
+impl<LeftType, RightType> Device for EitherDevice<LeftType, RightType>
+where
+    LeftType: Device,
+    RightType: Device,
+{
+    async fn subscribe_to_state_updates(&self) -> watch::Receiver<DeviceState> {
+        match self {
+            Self::Left(device) => device.subscribe_to_state_updates().await,
+            Self::Right(device) => device.subscribe_to_state_updates().await,
+        }
+    }
+
+    fn subscribe_to_events(&self) -> broadcast::Receiver<DeviceEvent> {
+        match self {
+            Self::Left(device) => device.subscribe_to_events(),
+            Self::Right(device) => device.subscribe_to_events(),
+        }
+    }
+
+    async fn mac_address(&self) -> crate::Result<MacAddr6> {
+        match self {
+            Self::Left(device) => device.mac_address().await,
+            Self::Right(device) => device.mac_address().await,
+        }
+    }
+
+    fn service_uuid(&self) -> Uuid {
+        match self {
+            Self::Left(device) => device.service_uuid(),
+            Self::Right(device) => device.service_uuid(),
+        }
+    }
+
+    async fn name(&self) -> crate::Result<String> {
+        match self {
+            Self::Left(device) => device.name().await,
+            Self::Right(device) => device.name().await,
+        }
+    }
+
+    fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
+        match self {
+            Self::Left(device) => device.connection_status(),
+            Self::Right(device) => device.connection_status(),
+        }
+    }
+
+    async fn state(&self) -> DeviceState {
+        match self {
+            Self::Left(device) => device.state().await,
+            Self::Right(device) => device.state().await,
+        }
+    }
+
+    async fn set_sound_modes(&self, sound_modes: SoundModes) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_sound_modes(sound_modes).await,
+            Self::Right(device) => device.set_sound_modes(sound_modes).await,
+        }
+    }
+
+    async fn set_sound_modes_type_two(&self, sound_modes: SoundModesTypeTwo) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_sound_modes_type_two(sound_modes).await,
+            Self::Right(device) => device.set_sound_modes_type_two(sound_modes).await,
+        }
+    }
+
+    async fn set_ambient_sound_mode_cycle(
+        &self,
+        cycle: AmbientSoundModeCycle,
+    ) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_ambient_sound_mode_cycle(cycle).await,
+            Self::Right(device) => device.set_ambient_sound_mode_cycle(cycle).await,
+        }
+    }
+
+    async fn set_equalizer_configuration(
+        &self,
+        configuration: EqualizerConfiguration,
+    ) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_equalizer_configuration(configuration).await,
+            Self::Right(device) => device.set_equalizer_configuration(configuration).await,
+        }
+    }
+
+    async fn set_stereo_equalizer_configuration(
+        &self,
+        configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => {
+                device
+                    .set_stereo_equalizer_configuration(configuration)
+                    .await
+            }
+            Self::Right(device) => {
+                device
+                    .set_stereo_equalizer_configuration(configuration)
+                    .await
+            }
+        }
+    }
+
+    async fn set_hear_id(&self, hear_id: HearId) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_hear_id(hear_id).await,
+            Self::Right(device) => device.set_hear_id(hear_id).await,
+        }
+    }
+
+    async fn set_multi_button_configuration(
+        &self,
+        button_configuration: MultiButtonConfiguration,
+    ) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => {
+                device
+                    .set_multi_button_configuration(button_configuration)
+                    .await
+            }
+            Self::Right(device) => {
+                device
+                    .set_multi_button_configuration(button_configuration)
+                    .await
+            }
+        }
+    }
+
+    async fn set_wear_detection(&self, is_enabled: bool) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_wear_detection(is_enabled).await,
+            Self::Right(device) => device.set_wear_detection(is_enabled).await,
+        }
+    }
+
+    async fn set_touch_tone(&self, is_enabled: bool) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_touch_tone(is_enabled).await,
+            Self::Right(device) => device.set_touch_tone(is_enabled).await,
+        }
+    }
+
+    async fn set_auto_power_off(&self, auto_power_off: AutoPowerOff) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_auto_power_off(auto_power_off).await,
+            Self::Right(device) => device.set_auto_power_off(auto_power_off).await,
+        }
+    }
+
+    async fn set_game_mode(&self, is_enabled: bool) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_game_mode(is_enabled).await,
+            Self::Right(device) => device.set_game_mode(is_enabled).await,
+        }
+    }
+
+    async fn set_ldac(&self, is_enabled: bool) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_ldac(is_enabled).await,
+            Self::Right(device) => device.set_ldac(is_enabled).await,
+        }
+    }
+
+    async fn set_voice_prompt(&self, voice_prompt: VoicePrompt) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.set_voice_prompt(voice_prompt).await,
+            Self::Right(device) => device.set_voice_prompt(voice_prompt).await,
+        }
+    }
+
+    async fn apply(&self, changes: Vec<SettingChange>) -> crate::Result<()> {
+        match self {
+            Self::Left(device) => device.apply(changes).await,
+            Self::Right(device) => device.apply(changes).await,
+        }
+    }
+}
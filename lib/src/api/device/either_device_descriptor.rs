@@ -0,0 +1,37 @@
+use macaddr::MacAddr6;
+
+use super::DeviceDescriptor;
+
+/// A descriptor from one of two [`DeviceRegistry`](super::DeviceRegistry) implementations.
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub enum EitherDeviceDescriptor<LeftType, RightType> {
+    Left(LeftType),
+    Right(RightType),
+}
+
+impl<LeftType, RightType> DeviceDescriptor for EitherDeviceDescriptor<LeftType, RightType>
+where
+    LeftType: DeviceDescriptor,
+    RightType: DeviceDescriptor,
+{
+    fn name(&self) -> &str {
+        match self {
+            Self::Left(descriptor) => descriptor.name(),
+            Self::Right(descriptor) => descriptor.name(),
+        }
+    }
+
+    fn mac_address(&self) -> MacAddr6 {
+        match self {
+            Self::Left(descriptor) => descriptor.mac_address(),
+            Self::Right(descriptor) => descriptor.mac_address(),
+        }
+    }
+
+    fn source(&self) -> Option<&str> {
+        match self {
+            Self::Left(descriptor) => descriptor.source(),
+            Self::Right(descriptor) => descriptor.source(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use macaddr::MacAddr6;
+
+use super::{DeviceRegistry, EitherDevice, EitherDeviceDescriptor};
+
+/// Lets the [`DeviceRegistry`] implementation be chosen at runtime, since each implementation has
+/// its own device and descriptor types.
+#[derive(Debug)]
+pub enum EitherDeviceRegistry<LeftType, RightType> {
+    Left(LeftType),
+    Right(RightType),
+}
+
+impl<LeftType, RightType> DeviceRegistry for EitherDeviceRegistry<LeftType, RightType>
+where
+    LeftType: DeviceRegistry,
+    RightType: DeviceRegistry,
+{
+    type DeviceType = EitherDevice<LeftType::DeviceType, RightType::DeviceType>;
+    type DescriptorType =
+        EitherDeviceDescriptor<LeftType::DescriptorType, RightType::DescriptorType>;
+
+    async fn device_descriptors(&self) -> crate::Result<Vec<Self::DescriptorType>> {
+        Ok(match self {
+            Self::Left(registry) => registry
+                .device_descriptors()
+                .await?
+                .into_iter()
+                .map(EitherDeviceDescriptor::Left)
+                .collect(),
+            Self::Right(registry) => registry
+                .device_descriptors()
+                .await?
+                .into_iter()
+                .map(EitherDeviceDescriptor::Right)
+                .collect(),
+        })
+    }
+
+    async fn device(&self, mac_address: MacAddr6) -> crate::Result<Option<Rc<Self::DeviceType>>> {
+        Ok(match self {
+            Self::Left(registry) => registry
+                .device(mac_address)
+                .await?
+                .map(|device| Rc::new(EitherDevice::Left(device))),
+            Self::Right(registry) => registry
+                .device(mac_address)
+                .await?
+                .map(|device| Rc::new(EitherDevice::Right(device))),
+        })
+    }
+}
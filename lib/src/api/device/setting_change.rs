@@ -0,0 +1,292 @@
+use strum::IntoStaticStr;
+
+use crate::devices::standard::{
+    state::DeviceState,
+    structures::{
+        AmbientSoundModeCycle, AutoPowerOff, EqualizerConfiguration, HearId,
+        MultiButtonConfiguration, SoundModes, SoundModesTypeTwo, StereoEqualizerConfiguration,
+        VoicePrompt,
+    },
+};
+
+use super::Device;
+
+/// One of the changes that make up a batch passed to [`Device::apply`]. There is a variant for
+/// each of the setters on [`Device`].
+#[derive(Debug, Clone, PartialEq, IntoStaticStr)]
+pub enum SettingChange {
+    SoundModes(SoundModes),
+    SoundModesTypeTwo(SoundModesTypeTwo),
+    AmbientSoundModeCycle(AmbientSoundModeCycle),
+    EqualizerConfiguration(EqualizerConfiguration),
+    StereoEqualizerConfiguration(StereoEqualizerConfiguration),
+    HearId(HearId),
+    MultiButtonConfiguration(MultiButtonConfiguration),
+    WearDetection(bool),
+    TouchTone(bool),
+    AutoPowerOff(AutoPowerOff),
+    GameMode(bool),
+    Ldac(bool),
+    VoicePrompt(VoicePrompt),
+}
+
+impl SettingChange {
+    pub fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    /// The change that would put this setting back to its value in `state`. Returns `None` if
+    /// `state` doesn't know the value.
+    pub fn revert(&self, state: &DeviceState) -> Option<SettingChange> {
+        match self {
+            Self::SoundModes(_) => state.sound_modes.map(Self::SoundModes),
+            Self::SoundModesTypeTwo(_) => state.sound_modes_type_two.map(Self::SoundModesTypeTwo),
+            Self::AmbientSoundModeCycle(_) => state
+                .ambient_sound_mode_cycle
+                .map(Self::AmbientSoundModeCycle),
+            // Setting a single configuration on a stereo device changes both channels, so both
+            // need to be restored
+            Self::EqualizerConfiguration(_) | Self::StereoEqualizerConfiguration(_) => {
+                Some(match state.stereo_equalizer_configuration() {
+                    Some(stereo) => Self::StereoEqualizerConfiguration(stereo),
+                    None => Self::EqualizerConfiguration(state.equalizer_configuration.to_owned()),
+                })
+            }
+            Self::HearId(_) => state.hear_id.to_owned().map(Self::HearId),
+            Self::MultiButtonConfiguration(_) => state
+                .button_configuration
+                .map(Self::MultiButtonConfiguration),
+            Self::WearDetection(_) => state.wear_detection.map(Self::WearDetection),
+            Self::TouchTone(_) => state.touch_tone.map(Self::TouchTone),
+            Self::AutoPowerOff(_) => state.auto_power_off.map(Self::AutoPowerOff),
+            Self::GameMode(_) => state.game_mode.map(Self::GameMode),
+            Self::Ldac(_) => state.ldac.map(Self::Ldac),
+            Self::VoicePrompt(_) => state.voice_prompt.map(Self::VoicePrompt),
+        }
+    }
+
+    /// Whether `expected` and `reported` agree on the setting this change is for, where `reported`
+    /// holds only what the device sent back. Returns `None` if the device didn't send this setting,
+    /// so it can't be verified. Everything else, such as battery levels, may change on its own at
+    /// any time, so it is not compared.
+    pub fn is_same_in(&self, expected: &DeviceState, reported: &DeviceState) -> Option<bool> {
+        fn compare<T: PartialEq>(expected: &Option<T>, reported: &Option<T>) -> Option<bool> {
+            reported
+                .as_ref()
+                .map(|reported| expected.as_ref() == Some(reported))
+        }
+
+        match self {
+            Self::SoundModes(_) => compare(&expected.sound_modes, &reported.sound_modes),
+            Self::SoundModesTypeTwo(_) => compare(
+                &expected.sound_modes_type_two,
+                &reported.sound_modes_type_two,
+            ),
+            Self::AmbientSoundModeCycle(_) => compare(
+                &expected.ambient_sound_mode_cycle,
+                &reported.ambient_sound_mode_cycle,
+            ),
+            // Every state update includes the equalizer configuration
+            Self::EqualizerConfiguration(_) | Self::StereoEqualizerConfiguration(_) => Some(
+                expected.equalizer_configuration == reported.equalizer_configuration
+                    && expected.right_equalizer_configuration
+                        == reported.right_equalizer_configuration,
+            ),
+            Self::HearId(_) => compare(&expected.hear_id, &reported.hear_id),
+            Self::MultiButtonConfiguration(_) => compare(
+                &expected.button_configuration,
+                &reported.button_configuration,
+            ),
+            Self::WearDetection(_) => compare(&expected.wear_detection, &reported.wear_detection),
+            Self::TouchTone(_) => compare(&expected.touch_tone, &reported.touch_tone),
+            Self::AutoPowerOff(_) => compare(&expected.auto_power_off, &reported.auto_power_off),
+            Self::GameMode(_) => compare(&expected.game_mode, &reported.game_mode),
+            Self::Ldac(_) => compare(&expected.ldac, &reported.ldac),
+            Self::VoicePrompt(_) => compare(&expected.voice_prompt, &reported.voice_prompt),
+        }
+    }
+}
+
+/// Where [`apply_with_rollback`] sends changes, and the state it reads their previous values from.
+pub(crate) trait ChangeTarget {
+    async fn state(&self) -> DeviceState;
+    async fn send(&mut self, change: SettingChange) -> crate::Result<()>;
+}
+
+/// Sends each of `changes` to `target`, in order. If one fails, it and the changes before it are
+/// reverted newest first. Changes that can't be reverted are skipped so that the rest still are.
+/// The failed change is included since settings that take more than one packet may have been
+/// partly applied.
+pub(crate) async fn apply_with_rollback(
+    target: &mut impl ChangeTarget,
+    changes: Vec<SettingChange>,
+) -> crate::Result<()> {
+    let mut reverts = Vec::new();
+    for (index, change) in changes.into_iter().enumerate() {
+        let setting = change.name();
+        let revert = change.revert(&target.state().await);
+        if let Err(err) = target.send(change).await {
+            reverts.push(revert);
+            return Err(crate::Error::ApplyFailed {
+                index,
+                setting,
+                rolled_back: roll_back(target, reverts).await,
+                source: Box::new(err),
+            });
+        }
+        reverts.push(revert);
+    }
+    Ok(())
+}
+
+/// Returns whether every change was reverted.
+async fn roll_back(target: &mut impl ChangeTarget, reverts: Vec<Option<SettingChange>>) -> bool {
+    let mut is_complete = true;
+    for revert in reverts.into_iter().rev() {
+        let Some(revert) = revert else {
+            tracing::warn!("can't roll back a change since its previous value is unknown");
+            is_complete = false;
+            continue;
+        };
+        let setting = revert.name();
+        if let Err(err) = target.send(revert).await {
+            tracing::warn!("failed to roll back {setting}: {err:?}");
+            is_complete = false;
+        }
+    }
+    is_complete
+}
+
+/// Sends changes through the individual setters of a [`Device`].
+pub(super) struct DeviceSetters<'a, DeviceType: ?Sized>(pub &'a DeviceType);
+
+impl<DeviceType> ChangeTarget for DeviceSetters<'_, DeviceType>
+where
+    DeviceType: Device + ?Sized,
+{
+    async fn state(&self) -> DeviceState {
+        self.0.state().await
+    }
+
+    async fn send(&mut self, change: SettingChange) -> crate::Result<()> {
+        set_setting(self.0, change).await
+    }
+}
+
+/// Makes `change` using the matching setter on `device`.
+async fn set_setting(device: &(impl Device + ?Sized), change: SettingChange) -> crate::Result<()> {
+    match change {
+        SettingChange::SoundModes(sound_modes) => device.set_sound_modes(sound_modes).await,
+        SettingChange::SoundModesTypeTwo(sound_modes) => {
+            device.set_sound_modes_type_two(sound_modes).await
+        }
+        SettingChange::AmbientSoundModeCycle(cycle) => {
+            device.set_ambient_sound_mode_cycle(cycle).await
+        }
+        SettingChange::EqualizerConfiguration(configuration) => {
+            device.set_equalizer_configuration(configuration).await
+        }
+        SettingChange::StereoEqualizerConfiguration(configuration) => {
+            device
+                .set_stereo_equalizer_configuration(configuration)
+                .await
+        }
+        SettingChange::HearId(hear_id) => device.set_hear_id(hear_id).await,
+        SettingChange::MultiButtonConfiguration(button_configuration) => {
+            device
+                .set_multi_button_configuration(button_configuration)
+                .await
+        }
+        SettingChange::WearDetection(is_enabled) => device.set_wear_detection(is_enabled).await,
+        SettingChange::TouchTone(is_enabled) => device.set_touch_tone(is_enabled).await,
+        SettingChange::AutoPowerOff(auto_power_off) => {
+            device.set_auto_power_off(auto_power_off).await
+        }
+        SettingChange::GameMode(is_enabled) => device.set_game_mode(is_enabled).await,
+        SettingChange::Ldac(is_enabled) => device.set_ldac(is_enabled).await,
+        SettingChange::VoicePrompt(voice_prompt) => device.set_voice_prompt(voice_prompt).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::devices::standard::structures::AmbientSoundMode;
+
+    use super::*;
+
+    /// Records the changes it is sent. The first change to `failing_setting` fails.
+    struct RecordingTarget {
+        state: DeviceState,
+        failing_setting: Option<&'static str>,
+        sent: Vec<SettingChange>,
+    }
+
+    impl ChangeTarget for RecordingTarget {
+        async fn state(&self) -> DeviceState {
+            self.state.to_owned()
+        }
+
+        async fn send(&mut self, change: SettingChange) -> crate::Result<()> {
+            let setting = change.name();
+            self.sent.push(change);
+            if self.failing_setting == Some(setting) {
+                self.failing_setting = None;
+                return Err(crate::Error::MissingData { name: "test" });
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn it_rolls_back_past_changes_that_cannot_be_reverted() {
+        let sound_modes = SoundModes::default();
+        let new_sound_modes = SoundModes {
+            ambient_sound_mode: AmbientSoundMode::Transparency,
+            ..sound_modes
+        };
+        let mut target = RecordingTarget {
+            state: DeviceState {
+                sound_modes: Some(sound_modes),
+                touch_tone: Some(false),
+                voice_prompt: None,
+                ..Default::default()
+            },
+            failing_setting: Some("TouchTone"),
+            sent: Vec::new(),
+        };
+
+        let result = apply_with_rollback(
+            &mut target,
+            vec![
+                SettingChange::SoundModes(new_sound_modes),
+                // The current voice prompt isn't known, so this can't be reverted
+                SettingChange::VoicePrompt(VoicePrompt::Chinese),
+                SettingChange::TouchTone(true),
+            ],
+        )
+        .await;
+
+        assert!(
+            matches!(
+                result,
+                Err(crate::Error::ApplyFailed {
+                    index: 2,
+                    setting: "TouchTone",
+                    rolled_back: false,
+                    ..
+                })
+            ),
+            "{result:?}",
+        );
+        assert_eq!(
+            vec![
+                SettingChange::SoundModes(new_sound_modes),
+                SettingChange::VoicePrompt(VoicePrompt::Chinese),
+                SettingChange::TouchTone(true),
+                SettingChange::TouchTone(false),
+                SettingChange::SoundModes(sound_modes),
+            ],
+            target.sent,
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod capture_file;
+mod recording_connection;
+mod replay_connection;
+
+pub use capture_file::*;
+pub use recording_connection::*;
+pub use replay_connection::*;
@@ -0,0 +1,203 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use macaddr::MacAddr6;
+use strum::{AsRefStr, EnumString};
+
+/// A log of everything written to and received from a [`Connection`](crate::api::connection::Connection).
+///
+/// The text format starts with `name` and `mac_address` header lines, followed by one line per
+/// frame containing the milliseconds since the capture started, the direction, and the frame as
+/// hex. For example:
+///
+/// ```text
+/// name Soundcore Life Q30
+/// mac_address 00:00:00:00:00:00
+/// 0 outbound-with-response 08ee00000001010a0002
+/// 12 inbound 09ff00000101010b000214
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Capture {
+    pub name: String,
+    pub mac_address: MacAddr6,
+    pub entries: Vec<CaptureEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CaptureEntry {
+    pub timestamp: Duration,
+    pub direction: Direction,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, AsRefStr, EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Direction {
+    Inbound,
+    OutboundWithResponse,
+    OutboundWithoutResponse,
+}
+
+impl Capture {
+    pub(crate) fn header(name: &str, mac_address: MacAddr6) -> String {
+        format!("name {name}\nmac_address {mac_address}\n")
+    }
+}
+
+impl FromStr for Capture {
+    type Err = crate::Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        let mut header_value = |key: &str| {
+            lines
+                .next()
+                .and_then(|(_, line)| line.strip_prefix(key))
+                .and_then(|value| value.strip_prefix(' '))
+                .ok_or_else(|| crate::Error::ParseError {
+                    message: format!("capture is missing {key}"),
+                })
+        };
+        let name = header_value("name")?.to_owned();
+        let mac_address =
+            header_value("mac_address")?
+                .parse()
+                .map_err(|err| crate::Error::ParseError {
+                    message: format!("invalid mac address in capture: {err:?}"),
+                })?;
+        let entries = lines
+            .map(|(index, line)| {
+                line.parse().map_err(|err| crate::Error::ParseError {
+                    message: format!("line {}: {err}", index + 1),
+                })
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name,
+            mac_address,
+            entries,
+        })
+    }
+}
+
+impl Display for Capture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Self::header(&self.name, self.mac_address))?;
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for CaptureEntry {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut fields = line.split_whitespace();
+        let (Some(timestamp), Some(direction), Some(data), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err("expected timestamp, direction, and data".to_owned());
+        };
+        let timestamp = timestamp
+            .parse()
+            .map(Duration::from_millis)
+            .map_err(|err| format!("invalid timestamp: {err}"))?;
+        let direction = direction
+            .parse()
+            .map_err(|err| format!("invalid direction: {err}"))?;
+        let data = decode_hex(data).map_err(|err| format!("invalid data: {err}"))?;
+
+        Ok(Self {
+            timestamp,
+            direction,
+            data,
+        })
+    }
+}
+
+pub(crate) fn decode_hex(hex: &str) -> Result<Vec<u8>, String> {
+    hex.as_bytes()
+        .chunks(2)
+        .enumerate()
+        .map(|(i, digits)| match digits {
+            [_, _] => std::str::from_utf8(digits)
+                .ok()
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| format!("invalid hex byte at position {}", i * 2)),
+            _ => Err("odd number of hex digits".to_owned()),
+        })
+        .collect()
+}
+
+impl Display for CaptureEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} ",
+            self.timestamp.as_millis(),
+            self.direction.as_ref()
+        )?;
+        for byte in &self.data {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use macaddr::MacAddr6;
+
+    use super::{Capture, CaptureEntry, Direction};
+
+    #[test]
+    fn it_round_trips() {
+        let capture = Capture {
+            name: "Soundcore Life Q30".to_owned(),
+            mac_address: MacAddr6::new(0, 1, 2, 3, 4, 5),
+            entries: vec![
+                CaptureEntry {
+                    timestamp: Duration::from_millis(0),
+                    direction: Direction::OutboundWithResponse,
+                    data: vec![0x08, 0xee, 0x00, 0x00, 0x00, 0x01, 0x01, 0x0a, 0x00, 0x02],
+                },
+                CaptureEntry {
+                    timestamp: Duration::from_millis(12),
+                    direction: Direction::Inbound,
+                    data: vec![0x09, 0xff],
+                },
+            ],
+        };
+        let text = capture.to_string();
+        assert_eq!(
+            "name Soundcore Life Q30\n\
+            mac_address 00:01:02:03:04:05\n\
+            0 outbound-with-response 08ee00000001010a0002\n\
+            12 inbound 09ff\n",
+            text,
+        );
+        assert_eq!(capture, text.parse().unwrap());
+    }
+
+    #[test]
+    fn it_reports_the_line_of_invalid_entries() {
+        let text = "name Test\nmac_address 00:00:00:00:00:00\n0 inbound 09ff\n5 sideways 09ff\n";
+        let err = text.parse::<Capture>().unwrap_err();
+        assert!(
+            matches!(&err, crate::Error::ParseError { message } if message.starts_with("line 4")),
+            "{err:?}",
+        );
+    }
+
+    #[test]
+    fn it_requires_a_header() {
+        assert!("0 inbound 09ff\n".parse::<Capture>().is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use std::{io::Write, marker::PhantomData, panic::Location, sync::Arc};
+
+use macaddr::MacAddr6;
+use tokio::{
+    sync::{mpsc, watch},
+    time::Instant,
+};
+use uuid::Uuid;
+
+use crate::{
+    api::connection::{Connection, ConnectionStatus},
+    futures::{Futures, JoinHandle},
+};
+
+use super::{Capture, CaptureEntry, Direction};
+
+/// Wraps a connection and writes every frame sent or received through it to a [`Capture`].
+pub struct RecordingConnection<ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    inner: ConnectionType,
+    recorder: Arc<Recorder>,
+    inbound_handle: std::sync::Mutex<Option<FuturesType::JoinHandleType>>,
+    _futures: PhantomData<FuturesType>,
+}
+
+impl<ConnectionType, FuturesType> RecordingConnection<ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    pub async fn new(
+        inner: ConnectionType,
+        mut writer: impl Write + Send + 'static,
+    ) -> crate::Result<Self> {
+        let header = Capture::header(&inner.name().await?, inner.mac_address().await?);
+        writer
+            .write_all(header.as_bytes())
+            .map_err(|err| crate::Error::Other {
+                source: Box::new(err),
+                location: Location::caller(),
+            })?;
+        Ok(Self {
+            inner,
+            recorder: Arc::new(Recorder {
+                start: Instant::now(),
+                writer: std::sync::Mutex::new(Box::new(writer)),
+            }),
+            inbound_handle: Default::default(),
+            _futures: PhantomData,
+        })
+    }
+}
+
+impl<ConnectionType, FuturesType> Connection for RecordingConnection<ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    async fn name(&self) -> crate::Result<String> {
+        self.inner.name().await
+    }
+
+    async fn mac_address(&self) -> crate::Result<MacAddr6> {
+        self.inner.mac_address().await
+    }
+
+    fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
+        self.inner.connection_status()
+    }
+
+    async fn write_with_response(&self, data: &[u8]) -> crate::Result<()> {
+        self.recorder.record(Direction::OutboundWithResponse, data);
+        self.inner.write_with_response(data).await
+    }
+
+    async fn write_without_response(&self, data: &[u8]) -> crate::Result<()> {
+        self.recorder
+            .record(Direction::OutboundWithoutResponse, data);
+        self.inner.write_without_response(data).await
+    }
+
+    async fn inbound_packets_channel(&self) -> crate::Result<mpsc::Receiver<Vec<u8>>> {
+        let mut inner_receiver = self.inner.inbound_packets_channel().await?;
+        let (sender, receiver) = mpsc::channel(100);
+        let recorder = self.recorder.to_owned();
+        let handle = FuturesType::spawn(async move {
+            while let Some(data) = inner_receiver.recv().await {
+                recorder.record(Direction::Inbound, &data);
+                if sender.send(data).await.is_err() {
+                    break;
+                }
+            }
+        });
+        if let Some(previous_handle) = self.inbound_handle.lock().unwrap().replace(handle) {
+            previous_handle.abort();
+        }
+        Ok(receiver)
+    }
+
+    fn service_uuid(&self) -> Uuid {
+        self.inner.service_uuid()
+    }
+}
+
+impl<ConnectionType, FuturesType> Drop for RecordingConnection<ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.inbound_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+}
+
+struct Recorder {
+    start: Instant,
+    writer: std::sync::Mutex<Box<dyn Write + Send>>,
+}
+
+impl Recorder {
+    fn record(&self, direction: Direction, data: &[u8]) {
+        let entry = CaptureEntry {
+            timestamp: self.start.elapsed(),
+            direction,
+            data: data.to_vec(),
+        };
+        let mut writer = self.writer.lock().unwrap();
+        // A broken capture shouldn't break the connection
+        if let Err(err) = writeln!(writer, "{entry}").and_then(|_| writer.flush()) {
+            tracing::warn!("failed to record {entry}: {err:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use macaddr::MacAddr6;
+    use tokio::sync::mpsc;
+
+    use crate::{
+        api::{connection::Connection, device::Device},
+        capture::{Capture, ReplayConnection},
+        futures::TokioFutures,
+        soundcore_device::device::SoundcoreDevice,
+        stub::connection::StubConnection,
+    };
+
+    use super::RecordingConnection;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_records_both_directions() {
+        let connection = StubConnection::new();
+        connection
+            .set_name_return(Ok("Soundcore Life Q30".to_string()))
+            .await;
+        connection.set_mac_address_return(Ok(MacAddr6::nil())).await;
+        let (sender, receiver) = mpsc::channel(100);
+        connection.set_inbound_packets_channel(Ok(receiver)).await;
+
+        let buffer = SharedBuffer::default();
+        let recording = RecordingConnection::<_, TokioFutures>::new(connection, buffer.clone())
+            .await
+            .unwrap();
+        let mut inbound = recording.inbound_packets_channel().await.unwrap();
+
+        recording.write_with_response(&[0x08, 0xee]).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        sender.send(vec![0x09, 0xff]).await.unwrap();
+        assert_eq!(Some(vec![0x09, 0xff]), inbound.recv().await);
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        recording.write_without_response(&[0x01]).await.unwrap();
+
+        assert_eq!(
+            "name Soundcore Life Q30\n\
+            mac_address 00:00:00:00:00:00\n\
+            0 outbound-with-response 08ee\n\
+            5 inbound 09ff\n\
+            10 outbound-without-response 01\n",
+            buffer.text(),
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_can_be_replayed() {
+        let connection = StubConnection::new();
+        connection
+            .set_name_return(Ok("Soundcore Life Q30".to_string()))
+            .await;
+        connection.set_mac_address_return(Ok(MacAddr6::nil())).await;
+        let (sender, receiver) = mpsc::channel(100);
+        connection.set_inbound_packets_channel(Ok(receiver)).await;
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender
+                .send(vec![
+                    0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01, 0x46, 0x00, 0x05, 0x00, 0xfe, 0xfe,
+                    0x3c, 0xb4, 0x8f, 0xa0, 0x8e, 0xb4, 0x74, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x30, 0x32, 0x2e, 0x33,
+                    0x30, 0x33, 0x30, 0x32, 0x39, 0x30, 0x38, 0x36, 0x45, 0x43, 0x38, 0x32, 0x46,
+                    0x31, 0x32, 0x41, 0x43, 0x30,
+                ])
+                .await
+                .unwrap();
+        });
+
+        let buffer = SharedBuffer::default();
+        let recording = RecordingConnection::<_, TokioFutures>::new(connection, buffer.clone())
+            .await
+            .unwrap();
+        let recorded_device = SoundcoreDevice::<_, TokioFutures>::new(Arc::new(recording))
+            .await
+            .unwrap();
+
+        let capture: Capture = buffer.text().parse().unwrap();
+        let replay = Arc::new(ReplayConnection::new(capture));
+        let replayed_device = SoundcoreDevice::<_, TokioFutures>::new(replay.to_owned())
+            .await
+            .unwrap();
+
+        assert_eq!(recorded_device.state().await, replayed_device.state().await);
+        assert!(replay.is_finished().await);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+
+use macaddr::MacAddr6;
+use tokio::sync::{mpsc, watch, Mutex};
+use uuid::Uuid;
+
+use crate::api::connection::{Connection, ConnectionStatus};
+
+use super::{Capture, CaptureEntry, Direction};
+
+/// Plays back a [`Capture`]. Each write must match the next outbound frame in the capture, and
+/// the inbound frames that follow it are then delivered immediately, so replays don't depend on
+/// the timing of the original session.
+#[derive(Debug)]
+pub struct ReplayConnection {
+    name: String,
+    mac_address: MacAddr6,
+    entries: Mutex<VecDeque<CaptureEntry>>,
+    inbound_sender: Mutex<Option<mpsc::Sender<Vec<u8>>>>,
+    connection_status_sender: watch::Sender<ConnectionStatus>,
+}
+
+impl ReplayConnection {
+    pub fn new(capture: Capture) -> Self {
+        Self {
+            name: capture.name,
+            mac_address: capture.mac_address,
+            entries: Mutex::new(capture.entries.into()),
+            inbound_sender: Mutex::new(None),
+            connection_status_sender: watch::channel(ConnectionStatus::Connected).0,
+        }
+    }
+
+    /// Whether every frame in the capture has been replayed
+    pub async fn is_finished(&self) -> bool {
+        self.entries.lock().await.is_empty()
+    }
+
+    async fn write(&self, direction: Direction, data: &[u8]) -> crate::Result<()> {
+        let mut entries = self.entries.lock().await;
+        match entries.pop_front() {
+            Some(entry) if entry.direction == direction && entry.data == data => (),
+            Some(entry) => {
+                let message = format!(
+                    "capture diverged: expected {} {:02x?}, got {} {data:02x?}",
+                    entry.direction.as_ref(),
+                    entry.data,
+                    direction.as_ref(),
+                );
+                entries.push_front(entry);
+                return Err(crate::Error::WriteFailed {
+                    source: message.into(),
+                });
+            }
+            None => {
+                return Err(crate::Error::WriteFailed {
+                    source: "capture has no more frames".into(),
+                })
+            }
+        }
+        self.send_inbound_entries(&mut entries).await;
+        Ok(())
+    }
+
+    async fn send_inbound_entries(&self, entries: &mut VecDeque<CaptureEntry>) {
+        let inbound_sender = self.inbound_sender.lock().await;
+        let Some(sender) = inbound_sender.as_ref() else {
+            return;
+        };
+        while let Some(entry) = entries.pop_front() {
+            if entry.direction != Direction::Inbound {
+                entries.push_front(entry);
+                break;
+            }
+            if let Err(err) = sender.send(entry.data).await {
+                tracing::debug!("inbound packet receiver closed: {err:?}");
+            }
+        }
+    }
+}
+
+impl Connection for ReplayConnection {
+    async fn name(&self) -> crate::Result<String> {
+        Ok(self.name.to_owned())
+    }
+
+    async fn mac_address(&self) -> crate::Result<MacAddr6> {
+        Ok(self.mac_address)
+    }
+
+    fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
+        self.connection_status_sender.subscribe()
+    }
+
+    async fn write_with_response(&self, data: &[u8]) -> crate::Result<()> {
+        self.write(Direction::OutboundWithResponse, data).await
+    }
+
+    async fn write_without_response(&self, data: &[u8]) -> crate::Result<()> {
+        self.write(Direction::OutboundWithoutResponse, data).await
+    }
+
+    async fn inbound_packets_channel(&self) -> crate::Result<mpsc::Receiver<Vec<u8>>> {
+        let (sender, receiver) = mpsc::channel(100);
+        *self.inbound_sender.lock().await = Some(sender);
+        // Deliver anything the device sent before the first write
+        let mut entries = self.entries.lock().await;
+        self.send_inbound_entries(&mut entries).await;
+        Ok(receiver)
+    }
+
+    fn service_uuid(&self) -> Uuid {
+        Uuid::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use macaddr::MacAddr6;
+
+    use crate::api::connection::Connection;
+
+    use super::{Capture, ReplayConnection};
+
+    fn capture() -> Capture {
+        format!(
+            "name Test\nmac_address {}\n\
+            0 inbound 01\n\
+            1 outbound-with-response 02\n\
+            2 inbound 03\n\
+            3 inbound 04\n\
+            4 outbound-without-response 05\n",
+            MacAddr6::nil(),
+        )
+        .parse()
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_sends_inbound_frames_after_matching_writes() {
+        let connection = ReplayConnection::new(capture());
+        let mut receiver = connection.inbound_packets_channel().await.unwrap();
+        assert_eq!(Some(vec![0x01]), receiver.recv().await);
+        assert!(receiver.try_recv().is_err());
+
+        connection.write_with_response(&[0x02]).await.unwrap();
+        assert_eq!(Some(vec![0x03]), receiver.recv().await);
+        assert_eq!(Some(vec![0x04]), receiver.recv().await);
+
+        assert!(!connection.is_finished().await);
+        connection.write_without_response(&[0x05]).await.unwrap();
+        assert!(connection.is_finished().await);
+    }
+
+    #[tokio::test]
+    async fn it_errors_when_the_session_diverges() {
+        let connection = ReplayConnection::new(capture());
+        let _receiver = connection.inbound_packets_channel().await.unwrap();
+
+        assert!(connection.write_without_response(&[0x02]).await.is_err());
+        assert!(connection.write_with_response(&[0x06]).await.is_err());
+        // The expected frame is still available after a mismatch
+        connection.write_with_response(&[0x02]).await.unwrap();
+    }
+}
@@ -1,20 +1,27 @@
 use std::{marker::PhantomData, sync::Arc, time::Duration};
 
 use macaddr::MacAddr6;
-use tokio::sync::{watch, Mutex};
+use tokio::sync::{broadcast, watch, Mutex};
 use uuid::Uuid;
 
 use crate::{
     api::{connection::ConnectionStatus, device::Device},
     device_profile::{AvailableSoundModes, DeviceFeatures},
-    devices::standard::{state::DeviceState, structures::*},
+    devices::standard::{
+        device_event::{event_channel, send_state, DeviceEvent},
+        state::DeviceState,
+        structures::*,
+    },
+    emulator::EmulatedConnection,
     futures::Futures,
+    soundcore_device::device_model::DeviceModel,
 };
 
 pub struct DemoDevice<FuturesType> {
     name: String,
     mac_address: MacAddr6,
     state_sender: Arc<Mutex<watch::Sender<DeviceState>>>,
+    event_sender: broadcast::Sender<DeviceEvent>,
     connection_status_sender: watch::Sender<ConnectionStatus>,
     futures: PhantomData<FuturesType>,
 }
@@ -25,8 +32,41 @@ where
 {
     pub async fn new(name: impl Into<String>, mac_address: MacAddr6) -> Self {
         FuturesType::sleep(Duration::from_millis(500)).await; // it takes some time to connect
+        Self::with_state(name, mac_address, Self::full_featured_state())
+    }
+
+    /// Starts out with the state that an emulated `model` reports, so only the features `model`
+    /// has are available. Returns `None` if `model` can't be emulated.
+    pub async fn new_with_model(
+        name: impl Into<String>,
+        mac_address: MacAddr6,
+        model: DeviceModel,
+    ) -> Option<Self> {
+        let mut state = EmulatedConnection::new(model, mac_address)?.state().await;
+        // No state update packet includes the voice prompt, so it's only known once it has been set
+        if state.device_features.has_voice_prompt {
+            state.voice_prompt.get_or_insert(VoicePrompt::English);
+        }
+        FuturesType::sleep(Duration::from_millis(500)).await; // it takes some time to connect
+        Some(Self::with_state(name, mac_address, state))
+    }
+
+    fn with_state(name: impl Into<String>, mac_address: MacAddr6, state: DeviceState) -> Self {
+        let (state_sender, _) = watch::channel(state);
+        let (connection_status_sender, _) = watch::channel(ConnectionStatus::Connected);
+
+        Self {
+            name: name.into(),
+            mac_address,
+            state_sender: Arc::new(Mutex::new(state_sender)),
+            event_sender: event_channel(),
+            connection_status_sender,
+            futures: PhantomData,
+        }
+    }
 
-        let (state_sender, _) = watch::channel(DeviceState {
+    fn full_featured_state() -> DeviceState {
+        DeviceState {
             device_features: DeviceFeatures {
                 available_sound_modes: Some(AvailableSoundModes {
                     ambient_sound_modes: &[
@@ -56,15 +96,22 @@ where
                 has_touch_tone: true,
                 has_auto_power_off: true,
                 has_ambient_sound_mode_cycle: true,
+                has_game_mode: true,
+                has_ldac: true,
+                has_voice_prompt: true,
             },
             battery: SingleBattery {
                 is_charging: IsBatteryCharging::No,
                 level: BatteryLevel(4),
             }
             .into(),
+            charging_case_battery_level: Some(BatteryLevel(3)),
             equalizer_configuration: EqualizerConfiguration::new_from_preset_profile(
                 PresetEqualizerProfile::SoundcoreSignature,
             ),
+            right_equalizer_configuration: Some(EqualizerConfiguration::new_from_preset_profile(
+                PresetEqualizerProfile::SoundcoreSignature,
+            )),
             sound_modes: Some(SoundModes {
                 ambient_sound_mode: AmbientSoundMode::Normal,
                 noise_canceling_mode: NoiseCancelingMode::Indoor,
@@ -107,7 +154,10 @@ where
                 }
                 .into(),
             ),
-            firmware_version: Some(FirmwareVersion::new(2, 0)),
+            dual_firmware_version: Some(DualFirmwareVersion::new(
+                FirmwareVersion::new(2, 0),
+                FirmwareVersion::new(2, 1),
+            )),
             serial_number: Some(SerialNumber("0123456789ABCDEF".into())),
             ambient_sound_mode_cycle: Some(AmbientSoundModeCycle::default()),
             sound_modes_type_two: Some(SoundModesTypeTwo::default()),
@@ -115,16 +165,15 @@ where
                 is_connected: true,
                 host_device: HostDevice::Left,
             }),
-        });
-
-        let (connection_status_sender, _) = watch::channel(ConnectionStatus::Connected);
-
-        Self {
-            name: name.into(),
-            mac_address,
-            state_sender: Arc::new(Mutex::new(state_sender)),
-            connection_status_sender,
-            futures: PhantomData,
+            wear_detection: Some(true),
+            touch_tone: Some(false),
+            auto_power_off: Some(AutoPowerOff {
+                is_enabled: true,
+                duration: AutoPowerOffDuration::SixtyMinutes,
+            }),
+            game_mode: Some(false),
+            ldac: Some(false),
+            voice_prompt: Some(VoicePrompt::English),
         }
     }
 }
@@ -137,6 +186,10 @@ where
         self.state_sender.lock().await.subscribe()
     }
 
+    fn subscribe_to_events(&self) -> broadcast::Receiver<DeviceEvent> {
+        self.event_sender.subscribe()
+    }
+
     async fn mac_address(&self) -> crate::Result<MacAddr6> {
         Ok(self.mac_address.to_owned())
     }
@@ -169,10 +222,14 @@ where
             return Ok(());
         }
         tracing::info!("set sound modes to {sound_modes:?}");
-        state_sender.send_replace(DeviceState {
-            sound_modes: Some(sound_modes),
-            ..state
-        });
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                sound_modes: Some(sound_modes),
+                ..state
+            },
+        );
         Ok(())
     }
 
@@ -188,10 +245,14 @@ where
             return Ok(());
         }
         tracing::info!("set sound modes type two to {sound_modes:?}");
-        state_sender.send_replace(DeviceState {
-            sound_modes_type_two: Some(sound_modes),
-            ..state
-        });
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                sound_modes_type_two: Some(sound_modes),
+                ..state
+            },
+        );
         Ok(())
     }
 
@@ -210,10 +271,14 @@ where
             return Ok(());
         }
         tracing::info!("set ambient sound mode cycle to {cycle:?}");
-        state_sender.send_replace(DeviceState {
-            ambient_sound_mode_cycle: Some(cycle),
-            ..state
-        });
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                ambient_sound_mode_cycle: Some(cycle),
+                ..state
+            },
+        );
         Ok(())
     }
 
@@ -223,14 +288,73 @@ where
     ) -> crate::Result<()> {
         let state_sender = self.state_sender.lock().await;
         let state = state_sender.borrow().to_owned();
-        if state.equalizer_configuration == equalizer_configuration {
+        if !equalizer_configuration
+            .is_valid_for_number_of_bands(state.device_features.num_equalizer_bands)
+        {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "wrong number of equalizer bands",
+            });
+        }
+        if state.equalizer_configuration == equalizer_configuration
+            && state
+                .right_equalizer_configuration
+                .as_ref()
+                .is_none_or(|right| right == &equalizer_configuration)
+        {
             return Ok(());
         }
         tracing::info!("set equalizer configuration to {equalizer_configuration:?}");
-        state_sender.send_replace(DeviceState {
-            equalizer_configuration,
-            ..state
-        });
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                right_equalizer_configuration: state
+                    .right_equalizer_configuration
+                    .as_ref()
+                    .map(|_| equalizer_configuration.to_owned()),
+                equalizer_configuration,
+                ..state
+            },
+        );
+        Ok(())
+    }
+
+    async fn set_stereo_equalizer_configuration(
+        &self,
+        equalizer_configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<()> {
+        let state_sender = self.state_sender.lock().await;
+        let state = state_sender.borrow().to_owned();
+        if state.right_equalizer_configuration.is_none() {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "stereo equalizer",
+            });
+        }
+        let num_bands = state.device_features.num_equalizer_bands;
+        if !equalizer_configuration
+            .left
+            .is_valid_for_number_of_bands(num_bands)
+            || !equalizer_configuration
+                .right
+                .is_valid_for_number_of_bands(num_bands)
+        {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "wrong number of equalizer bands",
+            });
+        }
+        if state.stereo_equalizer_configuration().as_ref() == Some(&equalizer_configuration) {
+            return Ok(());
+        }
+        tracing::info!("set stereo equalizer configuration to {equalizer_configuration:?}");
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                equalizer_configuration: equalizer_configuration.left,
+                right_equalizer_configuration: Some(equalizer_configuration.right),
+                ..state
+            },
+        );
         Ok(())
     }
 
@@ -246,10 +370,14 @@ where
             return Ok(());
         }
         tracing::info!("set hear id to {hear_id:?}");
-        state_sender.send_replace(DeviceState {
-            hear_id: Some(hear_id),
-            ..state
-        });
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                hear_id: Some(hear_id),
+                ..state
+            },
+        );
         Ok(())
     }
 
@@ -268,10 +396,152 @@ where
             return Ok(());
         }
         tracing::info!("set custom button model to {buttons:?}");
-        state_sender.send_replace(DeviceState {
-            button_configuration: Some(buttons),
-            ..state
-        });
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                button_configuration: Some(buttons),
+                ..state
+            },
+        );
+        Ok(())
+    }
+
+    async fn set_wear_detection(&self, is_enabled: bool) -> crate::Result<()> {
+        let state_sender = self.state_sender.lock().await;
+        let state = state_sender.borrow().to_owned();
+        if state.wear_detection.is_none() {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "wear detection",
+            });
+        }
+        if state.wear_detection == Some(is_enabled) {
+            return Ok(());
+        }
+        tracing::info!("set wear detection to {is_enabled}");
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                wear_detection: Some(is_enabled),
+                ..state
+            },
+        );
+        Ok(())
+    }
+
+    async fn set_touch_tone(&self, is_enabled: bool) -> crate::Result<()> {
+        let state_sender = self.state_sender.lock().await;
+        let state = state_sender.borrow().to_owned();
+        if state.touch_tone.is_none() {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "touch tone",
+            });
+        }
+        if state.touch_tone == Some(is_enabled) {
+            return Ok(());
+        }
+        tracing::info!("set touch tone to {is_enabled}");
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                touch_tone: Some(is_enabled),
+                ..state
+            },
+        );
+        Ok(())
+    }
+
+    async fn set_auto_power_off(&self, auto_power_off: AutoPowerOff) -> crate::Result<()> {
+        let state_sender = self.state_sender.lock().await;
+        let state = state_sender.borrow().to_owned();
+        if state.auto_power_off.is_none() {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "auto power off",
+            });
+        }
+        if state.auto_power_off == Some(auto_power_off) {
+            return Ok(());
+        }
+        tracing::info!("set auto power off to {auto_power_off:?}");
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                auto_power_off: Some(auto_power_off),
+                ..state
+            },
+        );
+        Ok(())
+    }
+
+    async fn set_game_mode(&self, is_enabled: bool) -> crate::Result<()> {
+        let state_sender = self.state_sender.lock().await;
+        let state = state_sender.borrow().to_owned();
+        if state.game_mode.is_none() {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "game mode",
+            });
+        }
+        if state.game_mode == Some(is_enabled) {
+            return Ok(());
+        }
+        tracing::info!("set game mode to {is_enabled}");
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                game_mode: Some(is_enabled),
+                ..state
+            },
+        );
+        Ok(())
+    }
+
+    async fn set_ldac(&self, is_enabled: bool) -> crate::Result<()> {
+        let state_sender = self.state_sender.lock().await;
+        let state = state_sender.borrow().to_owned();
+        if state.ldac.is_none() {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "LDAC",
+            });
+        }
+        if state.ldac == Some(is_enabled) {
+            return Ok(());
+        }
+        tracing::info!("set LDAC to {is_enabled}");
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                ldac: Some(is_enabled),
+                ..state
+            },
+        );
+        Ok(())
+    }
+
+    async fn set_voice_prompt(&self, voice_prompt: VoicePrompt) -> crate::Result<()> {
+        let state_sender = self.state_sender.lock().await;
+        let state = state_sender.borrow().to_owned();
+        if state.voice_prompt.is_none() {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "voice prompt",
+            });
+        }
+        if state.voice_prompt == Some(voice_prompt) {
+            return Ok(());
+        }
+        tracing::info!("set voice prompt to {voice_prompt}");
+        send_state(
+            &state_sender,
+            &self.event_sender,
+            DeviceState {
+                voice_prompt: Some(voice_prompt),
+                ..state
+            },
+        );
         Ok(())
     }
 }
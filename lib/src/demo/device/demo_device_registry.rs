@@ -1,19 +1,22 @@
 use std::{marker::PhantomData, rc::Rc};
 
 use macaddr::MacAddr6;
+use strum::VariantArray;
 
 use crate::{
-    api::device::{DeviceRegistry, GenericDeviceDescriptor},
+    api::device::{DeviceDescriptor, DeviceRegistry, GenericDeviceDescriptor},
+    emulator::EmulatedConnection,
     futures::Futures,
+    soundcore_device::device_model::DeviceModel,
 };
 
 use super::demo_device::DemoDevice;
 
-#[derive(Default)]
 pub struct DemoDeviceRegistry<FuturesType>
 where
     FuturesType: Futures,
 {
+    devices: Vec<(GenericDeviceDescriptor, DeviceModel)>,
     futures: PhantomData<FuturesType>,
 }
 
@@ -21,16 +24,45 @@ impl<FuturesType> DemoDeviceRegistry<FuturesType>
 where
     FuturesType: Futures,
 {
-    const DEVICE_NAME: &'static str = "Demo Q30";
-    const DEVICE_MAC_ADDRESS: MacAddr6 = MacAddr6::nil();
-
+    /// One demo device for each model that can be emulated, with the model number as the last two
+    /// bytes of its mac address, so the A3028 is `00:00:00:00:30:28`. The A3029 is left out, since
+    /// without a known state update packet for it there is nothing to emulate.
     pub fn new() -> Self {
         Self {
+            devices: DeviceModel::VARIANTS
+                .iter()
+                .map(|model| (*model, mac_address(*model)))
+                .filter(|(model, mac_address)| {
+                    EmulatedConnection::new(*model, *mac_address).is_some()
+                })
+                .map(|(model, mac_address)| {
+                    (
+                        GenericDeviceDescriptor::new(format!("Demo {model}"), mac_address),
+                        model,
+                    )
+                })
+                .collect(),
             futures: PhantomData,
         }
     }
 }
 
+fn mac_address(model: DeviceModel) -> MacAddr6 {
+    let [high, low] = u16::from_str_radix(&model.as_ref()[1..], 16)
+        .expect("model names are an A followed by four digits")
+        .to_be_bytes();
+    MacAddr6::new(0, 0, 0, 0, high, low)
+}
+
+impl<FuturesType> Default for DemoDeviceRegistry<FuturesType>
+where
+    FuturesType: Futures,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<FuturesType> DeviceRegistry for DemoDeviceRegistry<FuturesType>
 where
     FuturesType: Futures,
@@ -39,19 +71,92 @@ where
     type DescriptorType = GenericDeviceDescriptor;
 
     async fn device_descriptors(&self) -> crate::Result<Vec<Self::DescriptorType>> {
-        Ok(vec![GenericDeviceDescriptor::new(
-            "Demo Q30",
-            MacAddr6::nil(),
-        )])
+        Ok(self
+            .devices
+            .iter()
+            .map(|(descriptor, _model)| descriptor.to_owned())
+            .collect())
     }
 
     async fn device(&self, mac_address: MacAddr6) -> crate::Result<Option<Rc<Self::DeviceType>>> {
-        if mac_address == Self::DEVICE_MAC_ADDRESS {
-            Ok(Some(Rc::new(
-                DemoDevice::new(Self::DEVICE_NAME, Self::DEVICE_MAC_ADDRESS).await,
-            )))
-        } else {
-            Ok(None)
+        let Some((descriptor, model)) = self
+            .devices
+            .iter()
+            .find(|(descriptor, _model)| descriptor.mac_address() == mac_address)
+        else {
+            return Ok(None);
+        };
+        Ok(
+            DemoDevice::new_with_model(descriptor.name(), mac_address, *model)
+                .await
+                .map(Rc::new),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use macaddr::MacAddr6;
+
+    use crate::{
+        api::{
+            connection::ConnectionRegistry,
+            device::{Device, DeviceDescriptor, DeviceRegistry},
+        },
+        emulator::EmulatedConnectionRegistry,
+        futures::TokioFutures,
+    };
+
+    use super::DemoDeviceRegistry;
+
+    #[tokio::test(start_paused = true)]
+    async fn it_has_a_device_for_each_emulated_model() {
+        let registry = DemoDeviceRegistry::<TokioFutures>::new();
+        let descriptors = registry.device_descriptors().await.unwrap();
+        assert_eq!(
+            EmulatedConnectionRegistry::with_all_models()
+                .connection_descriptors()
+                .await
+                .unwrap()
+                .len(),
+            descriptors.len(),
+        );
+        assert!(descriptors
+            .iter()
+            .any(|descriptor| descriptor.name() == "Demo A3028"));
+        // There is no known state update packet for the A3029
+        assert!(!descriptors
+            .iter()
+            .any(|descriptor| descriptor.name() == "Demo A3029"));
+
+        for descriptor in descriptors {
+            let device = registry
+                .device(descriptor.mac_address())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(descriptor.name(), device.name().await.unwrap());
         }
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_has_no_device_for_unknown_mac_addresses() {
+        let registry = DemoDeviceRegistry::<TokioFutures>::new();
+        assert!(registry
+            .device(MacAddr6::broadcast())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_derives_mac_addresses_from_the_model() {
+        let registry = DemoDeviceRegistry::<TokioFutures>::new();
+        let device = registry
+            .device(MacAddr6::new(0, 0, 0, 0, 0x39, 0x36))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!("Demo A3936", device.name().await.unwrap());
+    }
 }
@@ -46,6 +46,9 @@ pub struct DeviceFeatures {
     pub has_touch_tone: bool,
     pub has_auto_power_off: bool,
     pub has_ambient_sound_mode_cycle: bool,
+    pub has_game_mode: bool,
+    pub has_ldac: bool,
+    pub has_voice_prompt: bool,
     pub dynamic_range_compression_min_firmware_version: Option<FirmwareVersion>,
 }
 
@@ -9,7 +9,7 @@ use crate::devices::{
     a3027::device_profile::A3027_DEVICE_PROFILE,
     standard::{
         packets::{
-            inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
+            inbound::{state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody},
             parsing::take_bool,
         },
         structures::{
@@ -40,20 +40,44 @@ impl From<A3027StateUpdatePacket> for StateUpdatePacket {
             device_profile: &A3027_DEVICE_PROFILE,
             tws_status: None,
             battery: packet.battery.into(),
+            charging_case_battery_level: None,
             equalizer_configuration: packet.equalizer_configuration,
+            right_equalizer_configuration: None,
             sound_modes: Some(packet.sound_modes),
             age_range: Some(packet.age_range),
             gender: Some(packet.gender),
             hear_id: Some(packet.hear_id.into()),
             button_configuration: None,
-            firmware_version: Some(packet.firmware_version),
+            dual_firmware_version: Some(packet.firmware_version.into()),
             serial_number: Some(packet.serial_number),
             ambient_sound_mode_cycle: None,
             sound_modes_type_two: None,
+            wear_detection: Some(packet.wear_detection),
+            touch_tone: None,
+            auto_power_off: None,
+            game_mode: None,
+            ldac: None,
         }
     }
 }
 
+impl InboundPacketBody for A3027StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(63);
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.equalizer_configuration.bytes());
+        bytes.push(self.gender.0);
+        bytes.push(self.age_range.0);
+        bytes.extend(self.hear_id.bytes());
+        bytes.extend(self.sound_modes.bytes());
+        bytes.extend(self.firmware_version.bytes());
+        bytes.extend(self.serial_number.bytes());
+        bytes.push(self.wear_detection.into());
+        bytes.push(self.touch_func.into());
+        bytes
+    }
+}
+
 impl InboundPacket for A3027StateUpdatePacket {
     fn command() -> crate::devices::standard::structures::Command {
         StateUpdatePacket::command()
@@ -106,3 +130,58 @@ impl InboundPacket for A3027StateUpdatePacket {
         )(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nom::error::VerboseError;
+    use proptest::prelude::*;
+
+    use crate::devices::standard::{
+        packets::inbound::{take_inbound_packet_header, InboundPacket, InboundPacketBytesExt},
+        structures::strategies::{
+            age_range, basic_hear_id, equalizer_configuration, firmware_version, gender,
+            serial_number, single_battery, sound_modes,
+        },
+    };
+
+    use super::*;
+
+    prop_compose! {
+        fn a3027_state_update_packet()(
+            battery in single_battery(),
+            equalizer_configuration in equalizer_configuration(),
+            gender in gender(),
+            age_range in age_range(),
+            hear_id in basic_hear_id(),
+            sound_modes in sound_modes(),
+            firmware_version in firmware_version(),
+            serial_number in serial_number(),
+            wear_detection in any::<bool>(),
+            touch_func in any::<bool>(),
+        ) -> A3027StateUpdatePacket {
+            A3027StateUpdatePacket {
+                battery,
+                equalizer_configuration,
+                gender,
+                age_range,
+                hear_id,
+                sound_modes,
+                firmware_version,
+                serial_number,
+                wear_detection,
+                touch_func,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3027_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3027StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3027StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
+    }
+}
@@ -35,6 +35,9 @@ pub(crate) const A3028_DEVICE_PROFILE: DeviceProfile = DeviceProfile {
         has_touch_tone: false,
         has_auto_power_off: false,
         has_ambient_sound_mode_cycle: false,
+        has_game_mode: false,
+        has_ldac: false,
+        has_voice_prompt: false,
     },
     compatible_models: &[DeviceModel::A3028],
     implementation: || StandardImplementation::new::<A3028StateUpdatePacket>(),
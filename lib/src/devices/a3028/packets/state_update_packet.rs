@@ -11,7 +11,7 @@ use crate::devices::{
     a3028::device_profile::A3028_DEVICE_PROFILE,
     standard::{
         packets::{
-            inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
+            inbound::{state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody},
             parsing::take_bool,
         },
         structures::{
@@ -40,20 +40,45 @@ impl From<A3028StateUpdatePacket> for StateUpdatePacket {
             device_profile: &A3028_DEVICE_PROFILE,
             tws_status: None,
             battery: packet.battery.into(),
+            charging_case_battery_level: None,
             equalizer_configuration: packet.equalizer_configuration,
+            right_equalizer_configuration: None,
             sound_modes: Some(packet.sound_modes),
             age_range: Some(packet.age_range),
             gender: Some(packet.gender),
             hear_id: Some(packet.hear_id.into()),
             button_configuration: None,
-            firmware_version: Some(packet.firmware_version),
+            dual_firmware_version: Some(packet.firmware_version.into()),
             serial_number: Some(packet.serial_number),
             ambient_sound_mode_cycle: None,
             sound_modes_type_two: None,
+            wear_detection: None,
+            touch_tone: None,
+            auto_power_off: None,
+            game_mode: None,
+            ldac: None,
         }
     }
 }
 
+impl InboundPacketBody for A3028StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(69);
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.equalizer_configuration.bytes());
+        bytes.push(self.gender.0);
+        bytes.push(self.age_range.0);
+        bytes.extend(self.hear_id.bytes());
+        bytes.extend(self.sound_modes.bytes());
+        bytes.extend(self.firmware_version.bytes());
+        bytes.extend(self.serial_number.bytes());
+        if let Some(extra_fields) = &self.extra_fields {
+            bytes.extend(extra_fields.bytes());
+        }
+        bytes
+    }
+}
+
 impl InboundPacket for A3028StateUpdatePacket {
     fn command() -> crate::devices::standard::structures::Command {
         StateUpdatePacket::command()
@@ -104,7 +129,7 @@ impl InboundPacket for A3028StateUpdatePacket {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct ExtraFields {
     unknown1: u8,
     touch_control: bool,
@@ -117,6 +142,18 @@ pub struct ExtraFields {
 }
 
 impl ExtraFields {
+    fn bytes(&self) -> [u8; 7] {
+        [
+            self.unknown1,
+            self.touch_control.into(),
+            self.dual_connections.into(),
+            self.auto_power_off_enabled.into(),
+            self.auto_power_off_duration as u8,
+            self.ambient_sound_prompt_tone.into(),
+            self.battery_alert_prompt_tone.into(),
+        ]
+    }
+
     fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], Self, E> {
@@ -150,8 +187,9 @@ impl ExtraFields {
 }
 
 #[repr(u8)]
-#[derive(FromRepr, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(FromRepr, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 enum AutoPowerOffDuration {
+    #[default]
     ThirtyMinutes = 0,
     OneHour = 1,
     NinetyMinutes = 2,
@@ -161,17 +199,25 @@ enum AutoPowerOffDuration {
 #[cfg(test)]
 mod tests {
     use nom::error::VerboseError;
+    use proptest::prelude::*;
 
     use crate::devices::standard::{
         packets::inbound::{
             state_update_packet::StateUpdatePacket, take_inbound_packet_header, InboundPacket,
+            InboundPacketBytesExt,
         },
         structures::{
+            strategies::{
+                age_range, basic_hear_id, equalizer_configuration, firmware_version, gender,
+                serial_number, single_battery, sound_modes,
+            },
             AmbientSoundMode, CustomNoiseCanceling, EqualizerConfiguration, NoiseCancelingMode,
             PresetEqualizerProfile, SoundModes, VolumeAdjustments,
         },
     };
 
+    use super::{A3028StateUpdatePacket, AutoPowerOffDuration, ExtraFields};
+
     #[test]
     fn it_parses_packet_with_preset_eq() {
         let input: &[u8] = &[
@@ -357,4 +403,68 @@ mod tests {
         let (body, _) = take_inbound_packet_header::<VerboseError<_>>(input).unwrap();
         StateUpdatePacket::take::<VerboseError<_>>(body).expect("it parses successfully");
     }
+
+    prop_compose! {
+        fn extra_fields()(
+            unknown1 in any::<u8>(),
+            touch_control in any::<bool>(),
+            dual_connections in any::<bool>(),
+            auto_power_off_enabled in any::<bool>(),
+            auto_power_off_duration in prop::sample::select(vec![
+                AutoPowerOffDuration::ThirtyMinutes,
+                AutoPowerOffDuration::OneHour,
+                AutoPowerOffDuration::NinetyMinutes,
+                AutoPowerOffDuration::TwoHours,
+            ]),
+            ambient_sound_prompt_tone in any::<bool>(),
+            battery_alert_prompt_tone in any::<bool>(),
+        ) -> ExtraFields {
+            ExtraFields {
+                unknown1,
+                touch_control,
+                dual_connections,
+                auto_power_off_enabled,
+                auto_power_off_duration,
+                ambient_sound_prompt_tone,
+                battery_alert_prompt_tone,
+            }
+        }
+    }
+
+    prop_compose! {
+        fn a3028_state_update_packet()(
+            battery in single_battery(),
+            equalizer_configuration in equalizer_configuration(),
+            gender in gender(),
+            age_range in age_range(),
+            hear_id in basic_hear_id(),
+            sound_modes in sound_modes(),
+            firmware_version in firmware_version(),
+            serial_number in serial_number(),
+            extra_fields in proptest::option::of(extra_fields()),
+        ) -> A3028StateUpdatePacket {
+            A3028StateUpdatePacket {
+                battery,
+                equalizer_configuration,
+                gender,
+                age_range,
+                hear_id,
+                sound_modes,
+                firmware_version,
+                serial_number,
+                extra_fields,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3028_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3028StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3028StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
+    }
 }
@@ -10,9 +10,9 @@ use crate::{
         packets::inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
         state::DeviceState,
         structures::{
-            AmbientSoundMode, AmbientSoundModeCycle, Command, EqualizerConfiguration, HearId,
-            MultiButtonConfiguration, NoiseCancelingMode, SoundModes, SoundModesTypeTwo,
-            STATE_UPDATE,
+            AmbientSoundMode, AmbientSoundModeCycle, AutoPowerOff, Command, EqualizerConfiguration,
+            HearId, MultiButtonConfiguration, NoiseCancelingMode, SoundModes, SoundModesTypeTwo,
+            StereoEqualizerConfiguration, VoicePrompt, STATE_UPDATE,
         },
     },
     soundcore_device::{
@@ -49,6 +49,9 @@ pub(crate) const A3031_DEVICE_PROFILE: DeviceProfile = DeviceProfile {
         has_touch_tone: true,
         has_auto_power_off: true,
         has_ambient_sound_mode_cycle: false,
+        has_game_mode: false,
+        has_ldac: false,
+        has_voice_prompt: false,
     },
     compatible_models: &[DeviceModel::A3031],
     implementation: || Arc::new(A3031Implementation::default()),
@@ -78,7 +81,7 @@ impl DeviceImplementation for A3031Implementation {
                 };
                 buttons.set_internal_data(packet.button_configuration);
 
-                StateUpdatePacket::from(packet).into()
+                DeviceState::from(StateUpdatePacket::from(packet)).with_identity_from(state)
             }),
         );
 
@@ -102,6 +105,14 @@ impl DeviceImplementation for A3031Implementation {
         standard::implementation::set_equalizer_configuration(state, equalizer_configuration)
     }
 
+    fn set_stereo_equalizer_configuration(
+        &self,
+        state: DeviceState,
+        equalizer_configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_stereo_equalizer_configuration(state, equalizer_configuration)
+    }
+
     fn set_sound_modes(
         &self,
         state: DeviceState,
@@ -141,4 +152,48 @@ impl DeviceImplementation for A3031Implementation {
     ) -> crate::Result<CommandResponse> {
         standard::implementation::set_ambient_sound_mode_cycle(state, cycle)
     }
+
+    fn set_wear_detection(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_wear_detection(state, is_enabled)
+    }
+
+    fn set_touch_tone(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_touch_tone(state, is_enabled)
+    }
+
+    fn set_auto_power_off(
+        &self,
+        state: DeviceState,
+        auto_power_off: AutoPowerOff,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_auto_power_off(state, auto_power_off)
+    }
+
+    fn set_game_mode(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_game_mode(state, is_enabled)
+    }
+
+    fn set_ldac(&self, state: DeviceState, is_enabled: bool) -> crate::Result<CommandResponse> {
+        standard::implementation::set_ldac(state, is_enabled)
+    }
+
+    fn set_voice_prompt(
+        &self,
+        state: DeviceState,
+        voice_prompt: VoicePrompt,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_voice_prompt(state, voice_prompt)
+    }
 }
@@ -10,11 +10,11 @@ use crate::devices::{
     a3031::device_profile::A3031_DEVICE_PROFILE,
     standard::{
         packets::{
-            inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
+            inbound::{state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody},
             parsing::take_bool,
         },
         structures::{
-            DualBattery, EqualizerConfiguration, InternalMultiButtonConfiguration, SoundModes,
+            AutoPowerOff, DualBattery, InternalMultiButtonConfiguration, SoundModes,
             StereoEqualizerConfiguration, TwsStatus,
         },
     },
@@ -24,7 +24,7 @@ use crate::devices::{
 pub struct A3031StateUpdatePacket {
     pub tws_status: TwsStatus,
     pub battery: DualBattery,
-    pub equalizer_configuration: EqualizerConfiguration,
+    pub equalizer_configuration: StereoEqualizerConfiguration,
     pub button_configuration: InternalMultiButtonConfiguration,
     pub sound_modes: SoundModes,
     pub side_tone: bool,
@@ -39,20 +39,46 @@ impl From<A3031StateUpdatePacket> for StateUpdatePacket {
             device_profile: &A3031_DEVICE_PROFILE,
             tws_status: Some(packet.tws_status),
             battery: packet.battery.into(),
-            equalizer_configuration: packet.equalizer_configuration,
+            charging_case_battery_level: None,
+            equalizer_configuration: packet.equalizer_configuration.left,
+            right_equalizer_configuration: Some(packet.equalizer_configuration.right),
             sound_modes: Some(packet.sound_modes),
             age_range: None,
             gender: None,
             hear_id: None,
             button_configuration: Some(packet.button_configuration.into()),
-            firmware_version: None,
+            dual_firmware_version: None,
             serial_number: None,
             ambient_sound_mode_cycle: None,
             sound_modes_type_two: None,
+            wear_detection: None,
+            touch_tone: Some(packet.touch_tone),
+            auto_power_off: AutoPowerOff::from_index(
+                packet.auto_power_off_on,
+                packet.auto_power_off_on_index,
+            ),
+            game_mode: None,
+            ldac: None,
         }
     }
 }
 
+impl InboundPacketBody for A3031StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(44);
+        bytes.extend(self.tws_status.bytes());
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.equalizer_configuration.bytes());
+        bytes.extend(self.button_configuration.bytes());
+        bytes.extend(self.sound_modes.bytes());
+        bytes.push(self.side_tone.into());
+        bytes.push(self.touch_tone.into());
+        bytes.push(self.auto_power_off_on.into());
+        bytes.push(self.auto_power_off_on_index);
+        bytes
+    }
+}
+
 impl InboundPacket for A3031StateUpdatePacket {
     fn command() -> crate::devices::standard::structures::Command {
         StateUpdatePacket::command()
@@ -102,3 +128,56 @@ impl InboundPacket for A3031StateUpdatePacket {
         )(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nom::error::VerboseError;
+    use proptest::prelude::*;
+
+    use crate::devices::standard::{
+        packets::inbound::{take_inbound_packet_header, InboundPacket, InboundPacketBytesExt},
+        structures::strategies::{
+            dual_battery, internal_multi_button_configuration, sound_modes,
+            stereo_equalizer_configuration, tws_status,
+        },
+    };
+
+    use super::*;
+
+    prop_compose! {
+        fn a3031_state_update_packet()(
+            tws_status in tws_status(),
+            battery in dual_battery(),
+            equalizer_configuration in stereo_equalizer_configuration(),
+            button_configuration in internal_multi_button_configuration(),
+            sound_modes in sound_modes(),
+            side_tone in any::<bool>(),
+            touch_tone in any::<bool>(),
+            auto_power_off_on in any::<bool>(),
+            auto_power_off_on_index in any::<u8>(),
+        ) -> A3031StateUpdatePacket {
+            A3031StateUpdatePacket {
+                tws_status,
+                battery,
+                equalizer_configuration,
+                button_configuration,
+                sound_modes,
+                side_tone,
+                touch_tone,
+                auto_power_off_on,
+                auto_power_off_on_index,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3031_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3031StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3031StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
+    }
+}
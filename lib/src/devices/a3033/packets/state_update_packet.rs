@@ -9,7 +9,7 @@ use crate::devices::{
     a3033::device_profile::A3033_DEVICE_PROFILE,
     standard::{
         packets::{
-            inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
+            inbound::{state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody},
             parsing::take_bool,
         },
         structures::{EqualizerConfiguration, FirmwareVersion, SerialNumber, SingleBattery},
@@ -19,11 +19,11 @@ use crate::devices::{
 // A3033 and A3033EU
 #[derive(Debug, Clone, PartialEq)]
 pub struct A3033StateUpdatePacket {
-    battery: SingleBattery,
-    equalizer_configuration: EqualizerConfiguration,
-    firmware_version: FirmwareVersion,
-    serial_number: SerialNumber,
-    wear_detection: bool,
+    pub battery: SingleBattery,
+    pub equalizer_configuration: EqualizerConfiguration,
+    pub firmware_version: FirmwareVersion,
+    pub serial_number: SerialNumber,
+    pub wear_detection: bool,
 }
 
 impl From<A3033StateUpdatePacket> for StateUpdatePacket {
@@ -32,20 +32,39 @@ impl From<A3033StateUpdatePacket> for StateUpdatePacket {
             device_profile: &A3033_DEVICE_PROFILE,
             tws_status: None,
             battery: packet.battery.into(),
+            charging_case_battery_level: None,
             equalizer_configuration: packet.equalizer_configuration,
+            right_equalizer_configuration: None,
             sound_modes: None,
             age_range: None,
             gender: None,
             hear_id: None,
             button_configuration: None,
-            firmware_version: Some(packet.firmware_version),
+            dual_firmware_version: Some(packet.firmware_version.into()),
             serial_number: Some(packet.serial_number),
             ambient_sound_mode_cycle: None,
             sound_modes_type_two: None,
+            wear_detection: None,
+            touch_tone: None,
+            auto_power_off: None,
+            game_mode: None,
+            ldac: None,
         }
     }
 }
 
+impl InboundPacketBody for A3033StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(34);
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.equalizer_configuration.bytes());
+        bytes.extend(self.firmware_version.bytes());
+        bytes.extend(self.serial_number.bytes());
+        bytes.push(self.wear_detection.into());
+        bytes
+    }
+}
+
 impl InboundPacket for A3033StateUpdatePacket {
     fn command() -> crate::devices::standard::structures::Command {
         StateUpdatePacket::command()
@@ -83,3 +102,47 @@ impl InboundPacket for A3033StateUpdatePacket {
         )(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nom::error::VerboseError;
+    use proptest::prelude::*;
+
+    use crate::devices::standard::{
+        packets::inbound::{take_inbound_packet_header, InboundPacket, InboundPacketBytesExt},
+        structures::strategies::{
+            equalizer_configuration, firmware_version, serial_number, single_battery,
+        },
+    };
+
+    use super::*;
+
+    prop_compose! {
+        fn a3033_state_update_packet()(
+            battery in single_battery(),
+            equalizer_configuration in equalizer_configuration(),
+            firmware_version in firmware_version(),
+            serial_number in serial_number(),
+            wear_detection in any::<bool>(),
+        ) -> A3033StateUpdatePacket {
+            A3033StateUpdatePacket {
+                battery,
+                equalizer_configuration,
+                firmware_version,
+                serial_number,
+                wear_detection,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3033_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3033StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3033StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
+    }
+}
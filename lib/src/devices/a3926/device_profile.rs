@@ -10,9 +10,9 @@ use crate::{
         packets::inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
         state::DeviceState,
         structures::{
-            AmbientSoundMode, AmbientSoundModeCycle, Command, EqualizerConfiguration, HearId,
-            MultiButtonConfiguration, NoiseCancelingMode, SoundModes, SoundModesTypeTwo,
-            STATE_UPDATE,
+            AmbientSoundMode, AmbientSoundModeCycle, AutoPowerOff, Command, EqualizerConfiguration,
+            HearId, MultiButtonConfiguration, NoiseCancelingMode, SoundModes, SoundModesTypeTwo,
+            StereoEqualizerConfiguration, VoicePrompt, STATE_UPDATE,
         },
     },
     soundcore_device::{
@@ -50,6 +50,9 @@ pub(crate) const A3926_DEVICE_PROFILE: DeviceProfile = DeviceProfile {
         has_touch_tone: false,
         has_auto_power_off: false,
         has_ambient_sound_mode_cycle: false,
+        has_game_mode: false,
+        has_ldac: false,
+        has_voice_prompt: false,
     },
     compatible_models: &[DeviceModel::A3926],
     implementation: || Arc::new(A3926Implementation::default()),
@@ -79,7 +82,7 @@ impl DeviceImplementation for A3926Implementation {
                 };
                 buttons.set_internal_data(packet.button_configuration);
 
-                StateUpdatePacket::from(packet).into()
+                DeviceState::from(StateUpdatePacket::from(packet)).with_identity_from(state)
             }),
         );
 
@@ -103,6 +106,14 @@ impl DeviceImplementation for A3926Implementation {
         standard::implementation::set_equalizer_configuration(state, equalizer_configuration)
     }
 
+    fn set_stereo_equalizer_configuration(
+        &self,
+        state: DeviceState,
+        equalizer_configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_stereo_equalizer_configuration(state, equalizer_configuration)
+    }
+
     fn set_sound_modes(
         &self,
         state: DeviceState,
@@ -142,4 +153,50 @@ impl DeviceImplementation for A3926Implementation {
     ) -> crate::Result<CommandResponse> {
         standard::implementation::set_ambient_sound_mode_cycle(state, cycle)
     }
+
+    fn set_wear_detection(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_wear_detection(state, is_enabled)
+    }
+
+    fn set_touch_tone(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_touch_tone(state, is_enabled)
+    }
+
+    fn set_auto_power_off(
+        &self,
+        _state: DeviceState,
+        _auto_power_off: AutoPowerOff,
+    ) -> crate::Result<CommandResponse> {
+        Err(crate::Error::FeatureNotSupported {
+            feature_name: "auto power off",
+        })
+    }
+
+    fn set_game_mode(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_game_mode(state, is_enabled)
+    }
+
+    fn set_ldac(&self, state: DeviceState, is_enabled: bool) -> crate::Result<CommandResponse> {
+        standard::implementation::set_ldac(state, is_enabled)
+    }
+
+    fn set_voice_prompt(
+        &self,
+        state: DeviceState,
+        voice_prompt: VoicePrompt,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_voice_prompt(state, voice_prompt)
+    }
 }
@@ -8,10 +8,12 @@ use nom::{
 use crate::devices::{
     a3926::device_profile::A3926_DEVICE_PROFILE,
     standard::{
-        packets::inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
+        packets::inbound::{
+            state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody,
+        },
         structures::{
-            AgeRange, BasicHearId, DualBattery, EqualizerConfiguration, Gender,
-            InternalMultiButtonConfiguration, StereoEqualizerConfiguration, TwsStatus,
+            AgeRange, BasicHearId, DualBattery, Gender, InternalMultiButtonConfiguration,
+            StereoEqualizerConfiguration, TwsStatus,
         },
     },
 };
@@ -21,7 +23,7 @@ use crate::devices::{
 pub struct A3926StateUpdatePacket {
     pub tws_status: TwsStatus,
     pub battery: DualBattery,
-    pub equalizer_configuration: EqualizerConfiguration,
+    pub equalizer_configuration: StereoEqualizerConfiguration,
     pub gender: Gender,
     pub age_range: AgeRange,
     pub hear_id: BasicHearId,
@@ -34,20 +36,41 @@ impl From<A3926StateUpdatePacket> for StateUpdatePacket {
             device_profile: &A3926_DEVICE_PROFILE,
             tws_status: Some(packet.tws_status),
             battery: packet.battery.into(),
-            equalizer_configuration: packet.equalizer_configuration,
+            charging_case_battery_level: None,
+            equalizer_configuration: packet.equalizer_configuration.left,
+            right_equalizer_configuration: Some(packet.equalizer_configuration.right),
             sound_modes: None,
             age_range: Some(packet.age_range),
             gender: Some(packet.gender),
             hear_id: Some(packet.hear_id.into()),
             button_configuration: Some(packet.button_configuration.into()),
-            firmware_version: None,
+            dual_firmware_version: None,
             serial_number: None,
             ambient_sound_mode_cycle: None,
             sound_modes_type_two: None,
+            wear_detection: None,
+            touch_tone: None,
+            auto_power_off: None,
+            game_mode: None,
+            ldac: None,
         }
     }
 }
 
+impl InboundPacketBody for A3926StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(65);
+        bytes.extend(self.tws_status.bytes());
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.equalizer_configuration.bytes());
+        bytes.push(self.gender.0);
+        bytes.push(self.age_range.0);
+        bytes.extend(self.hear_id.bytes());
+        bytes.extend(self.button_configuration.bytes());
+        bytes
+    }
+}
+
 impl InboundPacket for A3926StateUpdatePacket {
     fn command() -> crate::devices::standard::structures::Command {
         StateUpdatePacket::command()
@@ -90,3 +113,52 @@ impl InboundPacket for A3926StateUpdatePacket {
         )(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nom::error::VerboseError;
+    use proptest::prelude::*;
+
+    use crate::devices::standard::{
+        packets::inbound::{take_inbound_packet_header, InboundPacket, InboundPacketBytesExt},
+        structures::strategies::{
+            age_range, basic_hear_id, dual_battery, gender, internal_multi_button_configuration,
+            stereo_equalizer_configuration, tws_status,
+        },
+    };
+
+    use super::*;
+
+    prop_compose! {
+        fn a3926_state_update_packet()(
+            tws_status in tws_status(),
+            battery in dual_battery(),
+            equalizer_configuration in stereo_equalizer_configuration(),
+            gender in gender(),
+            age_range in age_range(),
+            hear_id in basic_hear_id(),
+            button_configuration in internal_multi_button_configuration(),
+        ) -> A3926StateUpdatePacket {
+            A3926StateUpdatePacket {
+                tws_status,
+                battery,
+                equalizer_configuration,
+                gender,
+                age_range,
+                hear_id,
+                button_configuration,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3926_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3926StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3926StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
+    }
+}
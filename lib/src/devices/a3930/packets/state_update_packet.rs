@@ -10,12 +10,12 @@ use crate::devices::{
     a3930::device_profile::A3930_DEVICE_PROFILE,
     standard::{
         packets::{
-            inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
+            inbound::{state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody},
             parsing::take_bool,
         },
         structures::{
-            AgeRange, CustomHearId, DualBattery, EqualizerConfiguration, Gender,
-            InternalMultiButtonConfiguration, SoundModes, StereoEqualizerConfiguration, TwsStatus,
+            AgeRange, CustomHearId, DualBattery, Gender, InternalMultiButtonConfiguration,
+            SoundModes, StereoEqualizerConfiguration, TwsStatus,
         },
     },
 };
@@ -23,17 +23,17 @@ use crate::devices::{
 // A3930
 #[derive(Debug, Clone, PartialEq)]
 pub struct A3930StateUpdatePacket {
-    tws_status: TwsStatus,
-    battery: DualBattery,
-    equalizer_configuration: EqualizerConfiguration,
-    gender: Gender,
-    age_range: AgeRange,
-    custom_hear_id: CustomHearId,
-    button_configuration: InternalMultiButtonConfiguration,
-    sound_modes: SoundModes,
-    side_tone: bool,
+    pub tws_status: TwsStatus,
+    pub battery: DualBattery,
+    pub equalizer_configuration: StereoEqualizerConfiguration,
+    pub gender: Gender,
+    pub age_range: AgeRange,
+    pub custom_hear_id: CustomHearId,
+    pub button_configuration: InternalMultiButtonConfiguration,
+    pub sound_modes: SoundModes,
+    pub side_tone: bool,
     // length >= 94
-    hear_id_eq_index: Option<u16>,
+    pub hear_id_eq_index: Option<u16>,
 }
 
 impl From<A3930StateUpdatePacket> for StateUpdatePacket {
@@ -42,20 +42,46 @@ impl From<A3930StateUpdatePacket> for StateUpdatePacket {
             device_profile: &A3930_DEVICE_PROFILE,
             tws_status: Some(packet.tws_status),
             battery: packet.battery.into(),
-            equalizer_configuration: packet.equalizer_configuration,
+            charging_case_battery_level: None,
+            equalizer_configuration: packet.equalizer_configuration.left,
+            right_equalizer_configuration: Some(packet.equalizer_configuration.right),
             sound_modes: Some(packet.sound_modes),
             age_range: Some(packet.age_range),
             gender: Some(packet.gender),
             hear_id: Some(packet.custom_hear_id.into()),
             button_configuration: Some(packet.button_configuration.into()),
-            firmware_version: None,
+            dual_firmware_version: None,
             serial_number: None,
             ambient_sound_mode_cycle: None,
             sound_modes_type_two: None,
+            wear_detection: None,
+            touch_tone: None,
+            auto_power_off: None,
+            game_mode: None,
+            ldac: None,
         }
     }
 }
 
+impl InboundPacketBody for A3930StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(96);
+        bytes.extend(self.tws_status.bytes());
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.equalizer_configuration.bytes());
+        bytes.push(self.gender.0);
+        bytes.push(self.age_range.0);
+        bytes.extend(self.custom_hear_id.bytes_with_all_fields());
+        bytes.extend(self.button_configuration.bytes());
+        bytes.extend(self.sound_modes.bytes());
+        bytes.push(self.side_tone.into());
+        if let Some(hear_id_eq_index) = self.hear_id_eq_index {
+            bytes.extend(hear_id_eq_index.to_le_bytes());
+        }
+        bytes
+    }
+}
+
 impl InboundPacket for A3930StateUpdatePacket {
     fn command() -> crate::devices::standard::structures::Command {
         StateUpdatePacket::command()
@@ -107,3 +133,59 @@ impl InboundPacket for A3930StateUpdatePacket {
         )(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nom::error::VerboseError;
+    use proptest::prelude::*;
+
+    use crate::devices::standard::{
+        packets::inbound::{take_inbound_packet_header, InboundPacket, InboundPacketBytesExt},
+        structures::strategies::{
+            age_range, custom_hear_id_with_all_fields, dual_battery, gender,
+            internal_multi_button_configuration, sound_modes, stereo_equalizer_configuration,
+            tws_status,
+        },
+    };
+
+    use super::*;
+
+    prop_compose! {
+        fn a3930_state_update_packet()(
+            tws_status in tws_status(),
+            battery in dual_battery(),
+            equalizer_configuration in stereo_equalizer_configuration(),
+            gender in gender(),
+            age_range in age_range(),
+            custom_hear_id in custom_hear_id_with_all_fields(),
+            button_configuration in internal_multi_button_configuration(),
+            sound_modes in sound_modes(),
+            side_tone in any::<bool>(),
+            hear_id_eq_index in proptest::option::of(any::<u16>()),
+        ) -> A3930StateUpdatePacket {
+            A3930StateUpdatePacket {
+                tws_status,
+                battery,
+                equalizer_configuration,
+                gender,
+                age_range,
+                custom_hear_id,
+                button_configuration,
+                sound_modes,
+                side_tone,
+                hear_id_eq_index,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3930_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3930StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3930StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
+    }
+}
@@ -7,13 +7,13 @@ use crate::{
     devices::standard::{
         self,
         implementation::ButtonConfigurationImplementation,
-        packets::inbound::state_update_packet::StateUpdatePacket,
-        quirks::{TwoExtraEqBandSetEqualizerPacket, TwoExtraEqBands},
+        packets::inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
+        quirks,
         state::DeviceState,
         structures::{
-            AmbientSoundMode, AmbientSoundModeCycle, Command, EqualizerConfiguration, HearId,
-            MultiButtonConfiguration, NoiseCancelingMode, SoundModes, SoundModesTypeTwo,
-            TransparencyMode, STATE_UPDATE,
+            AmbientSoundMode, AmbientSoundModeCycle, AutoPowerOff, Command, EqualizerConfiguration,
+            HearId, MultiButtonConfiguration, NoiseCancelingMode, SoundModes, SoundModesTypeTwo,
+            StereoEqualizerConfiguration, TransparencyMode, VoicePrompt, STATE_UPDATE,
         },
     },
     soundcore_device::{
@@ -45,7 +45,7 @@ pub(crate) const A3933_DEVICE_PROFILE: DeviceProfile = DeviceProfile {
         }),
         has_hear_id: true,
         num_equalizer_channels: 2,
-        num_equalizer_bands: 8,
+        num_equalizer_bands: 10,
         has_dynamic_range_compression: true,
         dynamic_range_compression_min_firmware_version: None,
         has_button_configuration: true,
@@ -53,6 +53,9 @@ pub(crate) const A3933_DEVICE_PROFILE: DeviceProfile = DeviceProfile {
         has_touch_tone: false,
         has_auto_power_off: false,
         has_ambient_sound_mode_cycle: true,
+        has_game_mode: true,
+        has_ldac: false,
+        has_voice_prompt: true,
     },
     compatible_models: &[DeviceModel::A3933, DeviceModel::A3939],
     implementation: || Arc::new(A3933Implementation::default()),
@@ -60,9 +63,6 @@ pub(crate) const A3933_DEVICE_PROFILE: DeviceProfile = DeviceProfile {
 
 #[derive(Debug, Default)]
 struct A3933Implementation {
-    // The official app only displays 8 bands, so I have no idea what bands 9 and 10 do. We'll just keep track
-    // of their initial value and resend that.
-    extra_bands: Arc<TwoExtraEqBands>,
     buttons: Arc<ButtonConfigurationImplementation>,
 }
 
@@ -70,7 +70,6 @@ impl DeviceImplementation for A3933Implementation {
     fn packet_handlers(
         &self,
     ) -> HashMap<Command, Box<dyn Fn(&[u8], DeviceState) -> DeviceState + Send + Sync>> {
-        let extra_bands = self.extra_bands.to_owned();
         let buttons = self.buttons.to_owned();
         let mut handlers = standard::implementation::packet_handlers();
 
@@ -85,10 +84,9 @@ impl DeviceImplementation for A3933Implementation {
                         return state;
                     }
                 };
-                extra_bands.set_values(packet.extra_band_values);
                 buttons.set_internal_data(packet.button_configuration);
 
-                StateUpdatePacket::from(packet).into()
+                DeviceState::from(StateUpdatePacket::from(packet)).with_identity_from(state)
             }),
         );
 
@@ -109,23 +107,21 @@ impl DeviceImplementation for A3933Implementation {
         state: DeviceState,
         equalizer_configuration: EqualizerConfiguration,
     ) -> crate::Result<CommandResponse> {
-        let left_channel = &equalizer_configuration;
-        let right_channel = &equalizer_configuration;
-        let extra_band_values = self.extra_bands.values();
-
-        let packet = TwoExtraEqBandSetEqualizerPacket {
-            left_channel,
-            right_channel,
-            extra_band_values,
-        };
-
-        Ok(CommandResponse {
-            packets: vec![packet.into()],
-            new_state: DeviceState {
-                equalizer_configuration,
-                ..state
+        self.set_stereo_equalizer_configuration(
+            state,
+            StereoEqualizerConfiguration {
+                left: equalizer_configuration.to_owned(),
+                right: equalizer_configuration,
             },
-        })
+        )
+    }
+
+    fn set_stereo_equalizer_configuration(
+        &self,
+        state: DeviceState,
+        equalizer_configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<CommandResponse> {
+        quirks::set_equalizer_configuration_with_two_extra_bands(state, equalizer_configuration)
     }
 
     fn set_sound_modes(
@@ -167,6 +163,52 @@ impl DeviceImplementation for A3933Implementation {
     ) -> crate::Result<CommandResponse> {
         standard::implementation::set_ambient_sound_mode_cycle(state, cycle)
     }
+
+    fn set_wear_detection(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_wear_detection(state, is_enabled)
+    }
+
+    fn set_touch_tone(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_touch_tone(state, is_enabled)
+    }
+
+    fn set_auto_power_off(
+        &self,
+        _state: DeviceState,
+        _auto_power_off: AutoPowerOff,
+    ) -> crate::Result<CommandResponse> {
+        Err(crate::Error::FeatureNotSupported {
+            feature_name: "auto power off",
+        })
+    }
+
+    fn set_game_mode(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_game_mode(state, is_enabled)
+    }
+
+    fn set_ldac(&self, state: DeviceState, is_enabled: bool) -> crate::Result<CommandResponse> {
+        standard::implementation::set_ldac(state, is_enabled)
+    }
+
+    fn set_voice_prompt(
+        &self,
+        state: DeviceState,
+        voice_prompt: VoicePrompt,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_voice_prompt(state, voice_prompt)
+    }
 }
 
 #[cfg(test)]
@@ -180,7 +222,10 @@ mod tests {
             },
             standard::{
                 packets::{
-                    inbound::{state_update_packet::StateUpdatePacket, take_inbound_packet_header},
+                    inbound::{
+                        state_update_packet::StateUpdatePacket, take_inbound_packet_header,
+                        InboundPacket,
+                    },
                     outbound::{OutboundPacket, OutboundPacketBytesExt},
                 },
                 quirks::{TwoExtraEqBandSetEqualizerPacket, TwoExtraEqBandsValues},
@@ -258,6 +303,17 @@ mod tests {
         let state: DeviceState = StateUpdatePacket::from(state_update).into();
         let implementation = (A3933_DEVICE_PROFILE.implementation)();
         let state = implementation.packet_handlers()[&STATE_UPDATE](body, state);
+        assert_eq!(
+            Some(TwoExtraEqBandsValues {
+                left_extra_1: 121,
+                left_extra_2: 122,
+                right_extra_1: 123,
+                right_extra_2: 124,
+            }),
+            state
+                .stereo_equalizer_configuration()
+                .and_then(|configuration| configuration.two_extra_eq_bands_values()),
+        );
 
         let equalizer_configuration =
             EqualizerConfiguration::new_from_preset_profile(PresetEqualizerProfile::TrebleReducer);
@@ -268,14 +324,12 @@ mod tests {
         assert_eq!(1, command_response.packets.len());
         assert_eq!(
             &Packet::from(TwoExtraEqBandSetEqualizerPacket {
-                left_channel: &equalizer_configuration,
-                right_channel: &equalizer_configuration,
-                extra_band_values: TwoExtraEqBandsValues {
-                    left_extra_1: 121,
-                    left_extra_2: 122,
-                    right_extra_1: 123,
-                    right_extra_2: 124,
-                },
+                left_channel: &equalizer_configuration
+                    .with_extra_bands([0.1, 0.2])
+                    .unwrap(),
+                right_channel: &equalizer_configuration
+                    .with_extra_bands([0.3, 0.4])
+                    .unwrap(),
             }),
             command_response.packets.first().unwrap(),
         );
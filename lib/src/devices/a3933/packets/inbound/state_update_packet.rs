@@ -10,18 +10,20 @@ use nom::{
 use crate::devices::{
     a3933::device_profile::A3933_DEVICE_PROFILE,
     standard::{
-        packets::{inbound::state_update_packet::StateUpdatePacket, parsing::take_bool},
-        quirks::TwoExtraEqBandsValues,
+        packets::{
+            inbound::{state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody},
+            parsing::take_bool,
+        },
         structures::{
-            AgeRange, AmbientSoundModeCycle, BatteryLevel, CustomHearId, DualBattery,
-            EqualizerConfiguration, FirmwareVersion, HearId, InternalMultiButtonConfiguration,
-            SerialNumber, SoundModes, StereoEqualizerConfiguration, TwsStatus,
+            AgeRange, AmbientSoundModeCycle, BatteryLevel, Command, CustomHearId, DualBattery,
+            DualFirmwareVersion, EqualizerConfiguration, FirmwareVersion, HearId,
+            InternalMultiButtonConfiguration, SerialNumber, SoundModes,
+            StereoEqualizerConfiguration, TwsStatus,
         },
     },
 };
 
 // A3933 and A3939
-// Despite EQ being 10 bands, only the first 8 seem to be used?
 #[derive(Debug, Clone, PartialEq)]
 pub struct A3933StateUpdatePacket {
     pub tws_status: TwsStatus,
@@ -31,42 +33,103 @@ pub struct A3933StateUpdatePacket {
     pub serial_number: SerialNumber,
     pub left_equalizer_configuration: EqualizerConfiguration,
     pub right_equalizer_configuration: EqualizerConfiguration,
-    pub extra_band_values: TwoExtraEqBandsValues,
     pub age_range: AgeRange,
     pub hear_id: Option<CustomHearId>, // 10 bands
     pub button_configuration: InternalMultiButtonConfiguration,
     pub ambient_sound_mode_cycle: AmbientSoundModeCycle,
     pub sound_modes: SoundModes,
-    pub touch_tone_switch: bool,
-    pub wear_detection_switch: bool,
-    pub game_mode_switch: bool,
-    pub charging_case_battery_level: BatteryLevel,
+    pub touch_tone_switch: Option<bool>,
+    pub wear_detection_switch: Option<bool>,
+    pub game_mode_switch: Option<bool>,
+    pub charging_case_battery_level: Option<BatteryLevel>,
     pub device_color: u8,
     pub wind_noise_detection: bool,
 }
 
 impl From<A3933StateUpdatePacket> for StateUpdatePacket {
     fn from(packet: A3933StateUpdatePacket) -> Self {
+        let dual_firmware_version =
+            DualFirmwareVersion::new(packet.left_firmware, packet.right_firmware);
         Self {
             device_profile: &A3933_DEVICE_PROFILE,
             tws_status: Some(packet.tws_status),
             battery: packet.battery.into(),
+            charging_case_battery_level: packet.charging_case_battery_level,
             equalizer_configuration: packet.left_equalizer_configuration,
+            right_equalizer_configuration: Some(packet.right_equalizer_configuration),
             sound_modes: Some(packet.sound_modes),
             age_range: None,
             gender: None,
             hear_id: packet.hear_id.map(HearId::Custom),
             button_configuration: Some(packet.button_configuration.into()),
-            firmware_version: Some(packet.left_firmware.min(packet.right_firmware)),
+            dual_firmware_version: Some(dual_firmware_version),
             serial_number: Some(packet.serial_number),
             ambient_sound_mode_cycle: Some(packet.ambient_sound_mode_cycle),
             sound_modes_type_two: None,
+            wear_detection: packet.wear_detection_switch,
+            touch_tone: packet.touch_tone_switch,
+            auto_power_off: None,
+            game_mode: packet.game_mode_switch,
+            ldac: None,
         }
     }
 }
 
-impl A3933StateUpdatePacket {
-    pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+impl InboundPacketBody for A3933StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(130);
+        bytes.extend(self.tws_status.bytes());
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.left_firmware.bytes());
+        bytes.extend(self.right_firmware.bytes());
+        bytes.extend(self.serial_number.bytes());
+        bytes.extend(StereoEqualizerConfiguration::bytes_with_two_extra_bands(
+            &self.left_equalizer_configuration,
+            &self.right_equalizer_configuration,
+        ));
+        bytes.push(self.age_range.0);
+        match &self.hear_id {
+            Some(hear_id) if self.age_range.supports_hear_id() => {
+                bytes.extend(hear_id.bytes_without_music_type())
+            }
+            _ => bytes.extend([0; 48]),
+        }
+        bytes.extend(self.button_configuration.bytes());
+        bytes.push(self.ambient_sound_mode_cycle.into());
+        bytes.extend(self.sound_modes.bytes());
+        bytes.extend([0, 0]);
+        if let (
+            Some(touch_tone_switch),
+            Some(wear_detection_switch),
+            Some(game_mode_switch),
+            Some(charging_case_battery_level),
+        ) = (
+            self.touch_tone_switch,
+            self.wear_detection_switch,
+            self.game_mode_switch,
+            self.charging_case_battery_level,
+        ) {
+            bytes.extend([
+                touch_tone_switch.into(),
+                wear_detection_switch.into(),
+                game_mode_switch.into(),
+                charging_case_battery_level.0,
+                0,
+                self.device_color,
+                self.wind_noise_detection.into(),
+            ]);
+            bytes.extend([0; 3]);
+        }
+        bytes
+    }
+}
+
+impl InboundPacket for A3933StateUpdatePacket {
+    fn command() -> Command {
+        StateUpdatePacket::command()
+    }
+
+    fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], A3933StateUpdatePacket, E> {
         context(
@@ -80,7 +143,7 @@ impl A3933StateUpdatePacket {
                         left_firmware,
                         right_firmware,
                         serial_number,
-                        (equalizer_configuration, extra_band_values),
+                        equalizer_configuration,
                         age_range,
                     ),
                 ) = tuple((
@@ -110,7 +173,10 @@ impl A3933StateUpdatePacket {
                     SoundModes::take,
                     // Unsure if these two unknown bytes should be inside or outside the optional
                     take(2usize), // unknown bytes
-                    opt(pair(Self::take_optional_extra_data, take(3usize))),
+                    opt(pair(
+                        A3933StateUpdatePacket::take_optional_extra_data,
+                        take(3usize),
+                    )),
                 ))(input)?;
 
                 Ok((
@@ -123,16 +189,15 @@ impl A3933StateUpdatePacket {
                         serial_number,
                         left_equalizer_configuration: equalizer_configuration.left,
                         right_equalizer_configuration: equalizer_configuration.right,
-                        extra_band_values,
                         age_range,
                         hear_id,
                         button_configuration,
                         ambient_sound_mode_cycle,
                         sound_modes,
-                        touch_tone_switch: extra.map(|(e, _)| e.0).unwrap_or_default(),
-                        wear_detection_switch: extra.map(|(e, _)| e.1).unwrap_or_default(),
-                        game_mode_switch: extra.map(|(e, _)| e.2).unwrap_or_default(),
-                        charging_case_battery_level: extra.map(|(e, _)| e.3).unwrap_or_default(),
+                        touch_tone_switch: extra.map(|(e, _)| e.0),
+                        wear_detection_switch: extra.map(|(e, _)| e.1),
+                        game_mode_switch: extra.map(|(e, _)| e.2),
+                        charging_case_battery_level: extra.map(|(e, _)| e.3),
                         device_color: extra.map(|(e, _)| e.5).unwrap_or_default(),
                         wind_noise_detection: extra.map(|(e, _)| e.6).unwrap_or_default(),
                     },
@@ -140,7 +205,9 @@ impl A3933StateUpdatePacket {
             }),
         )(input)
     }
+}
 
+impl A3933StateUpdatePacket {
     fn take_optional_extra_data<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], (bool, bool, bool, BatteryLevel, u8, u8, bool), E> {
@@ -162,15 +229,22 @@ impl A3933StateUpdatePacket {
 #[cfg(test)]
 mod tests {
     use nom::error::VerboseError;
+    use proptest::prelude::*;
 
     use crate::devices::{
         a3933::packets::inbound::A3933StateUpdatePacket,
         standard::{
-            packets::inbound::take_inbound_packet_header,
+            packets::inbound::{take_inbound_packet_header, InboundPacket, InboundPacketBytesExt},
             structures::{
-                AmbientSoundMode, BatteryLevel, CustomNoiseCanceling, EqualizerConfiguration,
-                FirmwareVersion, HostDevice, IsBatteryCharging, PresetEqualizerProfile,
-                SingleBattery, TwsStatus,
+                strategies::{
+                    age_range, ambient_sound_mode_cycle, battery_level,
+                    custom_hear_id_without_music_type, dual_battery, firmware_version,
+                    internal_multi_button_configuration, serial_number, sound_modes,
+                    stereo_equalizer_configuration_with_two_extra_bands, tws_status,
+                },
+                AgeRange, AmbientSoundMode, BatteryLevel, CustomNoiseCanceling,
+                EqualizerConfiguration, FirmwareVersion, HostDevice, IsBatteryCharging,
+                PresetEqualizerProfile, SingleBattery, TwsStatus,
             },
         },
     };
@@ -219,7 +293,9 @@ mod tests {
         assert_eq!(
             EqualizerConfiguration::new_from_preset_profile(
                 PresetEqualizerProfile::SoundcoreSignature
-            ),
+            )
+            .with_extra_bands([0.0, 0.0])
+            .unwrap(),
             packet.left_equalizer_configuration
         );
         assert_eq!(
@@ -231,4 +307,86 @@ mod tests {
             packet.sound_modes.custom_noise_canceling
         );
     }
+
+    #[test]
+    fn it_parses_packet_without_extra_data() {
+        let input: &[u8] = &[
+            9, 255, 0, 0, 1, 1, 1, 142, 0, 1, 1, 4, 4, 0, 0, 48, 50, 46, 54, 49, 48, 50, 46, 54,
+            49, 51, 57, 51, 57, 50, 65, 55, 70, 67, 67, 50, 70, 49, 50, 65, 67, 0, 0, 120, 120,
+            120, 120, 120, 120, 120, 120, 120, 120, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 99,
+            1, 82, 1, 102, 1, 84, 1, 1, 1, 0, 7, 0, 0, 0, 10, 255, 255, 0, 255, 0, 0, 0, 51, 255,
+            255, 255, 255, 102,
+        ];
+        let (body, _) = take_inbound_packet_header::<VerboseError<_>>(input).unwrap();
+        // Without the 7 bytes of extra data and the 3 bytes following them
+        let body = &body[..body.len() - 10];
+        let (_, packet) =
+            A3933StateUpdatePacket::take::<VerboseError<_>>(body).expect("should parse packet");
+
+        assert_eq!(None, packet.touch_tone_switch);
+        assert_eq!(None, packet.wear_detection_switch);
+        assert_eq!(None, packet.game_mode_switch);
+        assert_eq!(None, packet.charging_case_battery_level);
+    }
+
+    prop_compose! {
+        fn a3933_state_update_packet()(
+            tws_status in tws_status(),
+            battery in dual_battery(),
+            left_firmware in firmware_version(),
+            right_firmware in firmware_version(),
+            serial_number in serial_number(),
+            equalizer_configuration in stereo_equalizer_configuration_with_two_extra_bands(),
+            // Devices without hear id support send 255
+            age_range in prop_oneof![age_range(), Just(AgeRange(u8::MAX))],
+            hear_id in custom_hear_id_without_music_type(10),
+            button_configuration in internal_multi_button_configuration(),
+            ambient_sound_mode_cycle in ambient_sound_mode_cycle(),
+            sound_modes in sound_modes(),
+            // Older firmware doesn't send the extra data at the end
+            extra in proptest::option::of((
+                any::<bool>(),
+                any::<bool>(),
+                any::<bool>(),
+                battery_level(),
+                any::<u8>(),
+                any::<bool>(),
+            )),
+        ) -> A3933StateUpdatePacket {
+            A3933StateUpdatePacket {
+                tws_status,
+                battery,
+                left_firmware,
+                right_firmware,
+                serial_number,
+                left_equalizer_configuration: equalizer_configuration.left,
+                right_equalizer_configuration: equalizer_configuration.right,
+                age_range,
+                hear_id: age_range.supports_hear_id().then_some(hear_id),
+                button_configuration,
+                ambient_sound_mode_cycle,
+                sound_modes,
+                touch_tone_switch: extra.map(|e| e.0),
+                wear_detection_switch: extra.map(|e| e.1),
+                game_mode_switch: extra.map(|e| e.2),
+                charging_case_battery_level: extra.map(|e| e.3),
+                device_color: extra.map(|e| e.4).unwrap_or_default(),
+                wind_noise_detection: extra.map(|e| e.5).unwrap_or_default(),
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3933_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3933StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3933StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
+    }
 }
@@ -12,7 +12,7 @@ use crate::{
         packets::inbound::{
             state_update_packet::StateUpdatePacket, InboundPacket, SoundModeTypeTwoUpdatePacket,
         },
-        quirks::{TwoExtraEqBandSetEqualizerPacket, TwoExtraEqBands},
+        quirks,
         state::DeviceState,
         structures::*,
     },
@@ -32,14 +32,17 @@ pub(crate) const A3936_DEVICE_PROFILE: DeviceProfile = DeviceProfile {
         available_sound_modes: None,
         has_hear_id: true,
         num_equalizer_channels: 2,
-        num_equalizer_bands: 8,
+        num_equalizer_bands: 10,
         has_dynamic_range_compression: true,
         dynamic_range_compression_min_firmware_version: None,
         has_button_configuration: true,
         has_wear_detection: false,
         has_touch_tone: false,
-        has_auto_power_off: false,
+        has_auto_power_off: true,
         has_ambient_sound_mode_cycle: true,
+        has_game_mode: true,
+        has_ldac: false,
+        has_voice_prompt: true,
     },
     compatible_models: &[DeviceModel::A3936],
     implementation: || Arc::new(A3936Implementation::default()),
@@ -47,9 +50,6 @@ pub(crate) const A3936_DEVICE_PROFILE: DeviceProfile = DeviceProfile {
 
 #[derive(Debug, Default)]
 struct A3936Implementation {
-    // The official app only displays 8 bands, so I have no idea what bands 9 and 10 do. We'll just keep track
-    // of their initial value and resend that.
-    extra_bands: Arc<TwoExtraEqBands>,
     buttons: Arc<A3936ButtonConfigurationImplementation>,
 }
 
@@ -57,7 +57,6 @@ impl DeviceImplementation for A3936Implementation {
     fn packet_handlers(
         &self,
     ) -> HashMap<Command, Box<dyn Fn(&[u8], DeviceState) -> DeviceState + Send + Sync>> {
-        let extra_bands = self.extra_bands.to_owned();
         let buttons = self.buttons.to_owned();
         let mut handlers = standard::implementation::packet_handlers();
 
@@ -72,10 +71,9 @@ impl DeviceImplementation for A3936Implementation {
                         return state;
                     }
                 };
-                extra_bands.set_values(packet.extra_bands);
                 buttons.set_internal_data(packet.button_configuration);
 
-                StateUpdatePacket::from(packet).into()
+                DeviceState::from(StateUpdatePacket::from(packet)).with_identity_from(state)
             }),
         );
         handlers.insert(
@@ -113,23 +111,21 @@ impl DeviceImplementation for A3936Implementation {
         state: DeviceState,
         equalizer_configuration: EqualizerConfiguration,
     ) -> crate::Result<CommandResponse> {
-        let left_channel = &equalizer_configuration;
-        let right_channel = &equalizer_configuration;
-        let extra_band_values = self.extra_bands.values();
-
-        let packet = TwoExtraEqBandSetEqualizerPacket {
-            left_channel,
-            right_channel,
-            extra_band_values,
-        };
-
-        Ok(CommandResponse {
-            packets: vec![packet.into()],
-            new_state: DeviceState {
-                equalizer_configuration,
-                ..state
+        self.set_stereo_equalizer_configuration(
+            state,
+            StereoEqualizerConfiguration {
+                left: equalizer_configuration.to_owned(),
+                right: equalizer_configuration,
             },
-        })
+        )
+    }
+
+    fn set_stereo_equalizer_configuration(
+        &self,
+        state: DeviceState,
+        equalizer_configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<CommandResponse> {
+        quirks::set_equalizer_configuration_with_two_extra_bands(state, equalizer_configuration)
     }
 
     fn set_sound_modes(
@@ -199,6 +195,50 @@ impl DeviceImplementation for A3936Implementation {
     ) -> crate::Result<CommandResponse> {
         standard::implementation::set_ambient_sound_mode_cycle(state, cycle)
     }
+
+    fn set_wear_detection(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_wear_detection(state, is_enabled)
+    }
+
+    fn set_touch_tone(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_touch_tone(state, is_enabled)
+    }
+
+    fn set_auto_power_off(
+        &self,
+        state: DeviceState,
+        auto_power_off: AutoPowerOff,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_auto_power_off(state, auto_power_off)
+    }
+
+    fn set_game_mode(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_game_mode(state, is_enabled)
+    }
+
+    fn set_ldac(&self, state: DeviceState, is_enabled: bool) -> crate::Result<CommandResponse> {
+        standard::implementation::set_ldac(state, is_enabled)
+    }
+
+    fn set_voice_prompt(
+        &self,
+        state: DeviceState,
+        voice_prompt: VoicePrompt,
+    ) -> crate::Result<CommandResponse> {
+        standard::implementation::set_voice_prompt(state, voice_prompt)
+    }
 }
 
 #[derive(Debug, Default)]
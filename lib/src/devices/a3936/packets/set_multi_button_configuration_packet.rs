@@ -1,6 +1,9 @@
 use crate::devices::{
     a3936::structures::A3936InternalMultiButtonConfiguration,
-    standard::{packets::outbound::OutboundPacket, structures::Command},
+    standard::{
+        packets::outbound::{OutboundPacket, SetMultiButtonConfigurationPacket},
+        structures::Command,
+    },
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -18,7 +21,7 @@ impl A3936SetMultiButtonConfigurationPacket {
 
 impl OutboundPacket for A3936SetMultiButtonConfigurationPacket {
     fn command(&self) -> Command {
-        Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x04, 0x84])
+        SetMultiButtonConfigurationPacket::COMMAND
     }
 
     fn body(&self) -> Vec<u8> {
@@ -11,12 +11,14 @@ use crate::devices::{
         device_profile::A3936_DEVICE_PROFILE, structures::A3936InternalMultiButtonConfiguration,
     },
     standard::{
-        packets::{inbound::state_update_packet::StateUpdatePacket, parsing::take_bool},
-        quirks::TwoExtraEqBandsValues,
+        packets::{
+            inbound::{state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody},
+            parsing::take_bool,
+        },
         structures::{
-            AgeRange, AmbientSoundModeCycle, BatteryLevel, CustomHearId, DualBattery,
-            FirmwareVersion, SerialNumber, SoundModesTypeTwo, StereoEqualizerConfiguration,
-            TwsStatus,
+            AgeRange, AmbientSoundModeCycle, AutoPowerOff, BatteryLevel, Command, CustomHearId,
+            DualBattery, DualFirmwareVersion, FirmwareVersion, SerialNumber, SoundModesTypeTwo,
+            StereoEqualizerConfiguration, TwsStatus,
         },
     },
 };
@@ -30,7 +32,6 @@ pub struct A3936StateUpdatePacket {
     pub right_firmware: FirmwareVersion,
     pub serial_number: SerialNumber,
     pub equalizer_configuration: StereoEqualizerConfiguration,
-    pub extra_bands: TwoExtraEqBandsValues,
     pub age_range: AgeRange,
     pub custom_hear_id: CustomHearId,
     pub sound_modes: SoundModesTypeTwo,
@@ -48,11 +49,15 @@ pub struct A3936StateUpdatePacket {
 
 impl From<A3936StateUpdatePacket> for StateUpdatePacket {
     fn from(packet: A3936StateUpdatePacket) -> Self {
+        let dual_firmware_version =
+            DualFirmwareVersion::new(packet.left_firmware, packet.right_firmware);
         Self {
             device_profile: &A3936_DEVICE_PROFILE,
             tws_status: Some(packet.tws_status),
             battery: packet.battery.into(),
+            charging_case_battery_level: Some(packet.charging_case_battery),
             equalizer_configuration: packet.equalizer_configuration.left,
+            right_equalizer_configuration: Some(packet.equalizer_configuration.right),
             sound_modes: None,
             sound_modes_type_two: Some(packet.sound_modes),
             age_range: Some(packet.age_range),
@@ -63,15 +68,62 @@ impl From<A3936StateUpdatePacket> for StateUpdatePacket {
                     .button_configuration
                     .as_multi_button_configuration(packet.tws_status.is_connected),
             ),
-            firmware_version: None,
+            dual_firmware_version: Some(dual_firmware_version),
             serial_number: None,
             ambient_sound_mode_cycle: None,
+            wear_detection: None,
+            touch_tone: Some(packet.touch_tone),
+            auto_power_off: AutoPowerOff::from_index(
+                packet.auto_power_off_switch,
+                packet.auto_power_off_index,
+            ),
+            game_mode: Some(packet.game_mode_switch),
+            ldac: Some(packet.ldac),
         }
     }
 }
 
-impl A3936StateUpdatePacket {
-    pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+impl InboundPacketBody for A3936StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let button_configuration = self.button_configuration.bytes();
+        let mut bytes = Vec::with_capacity(141);
+        bytes.extend(self.tws_status.bytes());
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.left_firmware.bytes());
+        bytes.extend(self.right_firmware.bytes());
+        bytes.extend(self.serial_number.bytes());
+        bytes.extend(StereoEqualizerConfiguration::bytes_with_two_extra_bands(
+            &self.equalizer_configuration.left,
+            &self.equalizer_configuration.right,
+        ));
+        bytes.push(self.age_range.0);
+        bytes.extend(self.custom_hear_id.bytes_without_music_type());
+        // No padding between the button configuration and the data after it
+        bytes.push((button_configuration.len() + 2) as u8);
+        bytes.extend(button_configuration);
+        bytes.push(self.ambient_sound_mode_cycle.into());
+        bytes.extend(self.sound_modes.bytes());
+        bytes.extend([
+            self.touch_tone.into(),
+            self.charging_case_battery.0,
+            self.color,
+            self.ldac.into(),
+            self.supports_two_cnn_switch.into(),
+            self.auto_power_off_switch.into(),
+            self.auto_power_off_index,
+            self.game_mode_switch.into(),
+        ]);
+        bytes.extend([0xFF; 12]);
+        bytes
+    }
+}
+
+impl InboundPacket for A3936StateUpdatePacket {
+    fn command() -> Command {
+        StateUpdatePacket::command()
+    }
+
+    fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], A3936StateUpdatePacket, E> {
         context(
@@ -82,7 +134,7 @@ impl A3936StateUpdatePacket {
                 let (input, left_firmware) = FirmwareVersion::take(input)?;
                 let (input, right_firmware) = FirmwareVersion::take(input)?;
                 let (input, serial_number) = SerialNumber::take(input)?;
-                let (input, (equalizer_configuration, extra_bands)) =
+                let (input, equalizer_configuration) =
                     StereoEqualizerConfiguration::take_with_two_extra_bands(8)(input)?;
                 let (input, age_range) = AgeRange::take(input)?;
                 let (input, custom_hear_id) = CustomHearId::take_without_music_type(10)(input)?;
@@ -123,7 +175,6 @@ impl A3936StateUpdatePacket {
                         right_firmware,
                         serial_number,
                         equalizer_configuration,
-                        extra_bands,
                         age_range,
                         custom_hear_id,
                         ambient_sound_mode_cycle,
@@ -147,30 +198,148 @@ impl A3936StateUpdatePacket {
 #[cfg(test)]
 mod tests {
     use nom::error::VerboseError;
+    use proptest::prelude::*;
 
-    use crate::devices::standard::packets::inbound::{take_inbound_packet_header, InboundPacket};
+    use crate::devices::{
+        a3936::structures::A3936TwsButtonAction,
+        standard::{
+            packets::inbound::{take_inbound_packet_header, InboundPacket, InboundPacketBytesExt},
+            structures::strategies::{
+                age_range, ambient_sound_mode_cycle, battery_level, button_action,
+                custom_hear_id_without_music_type, dual_battery, firmware_version, serial_number,
+                sound_modes_type_two, stereo_equalizer_configuration_with_two_extra_bands,
+                tws_status,
+            },
+        },
+    };
 
     use super::*;
 
+    const KNOWN_GOOD_PACKET: &[u8] = &[
+        0x9, 0xff, 0x0, 0x0, 0x1, 0x1, 0x1, 0x99, 0x0, 0x1, 0x1, 0x5, 0x5, 0x1, 0x1, 0x30, 0x34,
+        0x2e, 0x31, 0x39, 0x30, 0x34, 0x2e, 0x31, 0x39, 0x33, 0x39, 0x33, 0x36, 0x61, 0x34, 0x37,
+        0x37, 0x35, 0x38, 0x34, 0x37, 0x30, 0x33, 0x36, 0x36, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78,
+        0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78,
+        0x3c, 0x1, 0x0, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x3c, 0x78, 0x78,
+        0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x3c, 0x65, 0x26, 0x8d, 0xaf, 0x2, 0x78, 0x78,
+        0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x3c, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78,
+        0x78, 0x3c, 0x3c, 0x0, 0x0, 0xe, 0x1, 0x11, 0x1, 0x0, 0x11, 0x63, 0x11, 0x66, 0x11, 0x49,
+        0x11, 0x44, 0x7, 0x2, 0x32, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4, 0x31, 0x0, 0x1, 0x1, 0x0, 0x0,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xdd,
+    ];
+
     #[test]
     pub fn it_parses_a_known_good_packet() {
-        let input = &[
-            0x9, 0xff, 0x0, 0x0, 0x1, 0x1, 0x1, 0x99, 0x0, 0x1, 0x1, 0x5, 0x5, 0x1, 0x1, 0x30,
-            0x34, 0x2e, 0x31, 0x39, 0x30, 0x34, 0x2e, 0x31, 0x39, 0x33, 0x39, 0x33, 0x36, 0x61,
-            0x34, 0x37, 0x37, 0x35, 0x38, 0x34, 0x37, 0x30, 0x33, 0x36, 0x36, 0x0, 0x0, 0x78, 0x78,
-            0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78,
-            0x78, 0x78, 0x78, 0x3c, 0x1, 0x0, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c,
-            0x3c, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x3c, 0x65, 0x26, 0x8d,
-            0xaf, 0x2, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x3c, 0x78, 0x78,
-            0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x3c, 0x0, 0x0, 0xe, 0x1, 0x11, 0x1, 0x0,
-            0x11, 0x63, 0x11, 0x66, 0x11, 0x49, 0x11, 0x44, 0x7, 0x2, 0x32, 0x0, 0x1, 0x0, 0x0,
-            0x0, 0x4, 0x31, 0x0, 0x1, 0x1, 0x0, 0x0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-            0xff, 0xff, 0xff, 0xff, 0xff, 0xdd,
-        ];
-        let (body, _) = take_inbound_packet_header::<VerboseError<_>>(input).unwrap();
+        let (body, _) = take_inbound_packet_header::<VerboseError<_>>(KNOWN_GOOD_PACKET).unwrap();
         A3936StateUpdatePacket::take::<VerboseError<_>>(body)
             .expect("it should parse successfully as a A3936 state update packet");
-        StateUpdatePacket::take::<VerboseError<_>>(body)
+        let (_, packet) = StateUpdatePacket::take::<VerboseError<_>>(body)
             .expect("it should parse successfully as a state update packet");
+        assert_eq!(Some(BatteryLevel(4)), packet.charging_case_battery_level);
+    }
+
+    #[test]
+    pub fn it_encodes_button_configuration_the_same_as_the_device() {
+        let (body, _) = take_inbound_packet_header::<VerboseError<_>>(KNOWN_GOOD_PACKET).unwrap();
+        let (_, packet) = A3936StateUpdatePacket::take::<VerboseError<_>>(body).unwrap();
+        // As sent by the device, the first byte of each button is whether it is enabled rather
+        // than its action
+        assert_eq!(
+            vec![0x1, 0x11, 0x1, 0x0, 0x11, 0x63, 0x11, 0x66, 0x11, 0x49, 0x11, 0x44],
+            packet.button_configuration.bytes(),
+        );
+    }
+
+    prop_compose! {
+        fn a3936_tws_button_action()(
+            tws_connected_action in button_action(),
+            tws_disconnected_action in button_action(),
+            tws_connected_is_enabled in any::<bool>(),
+            tws_disconnected_is_enabled in any::<bool>(),
+        ) -> A3936TwsButtonAction {
+            A3936TwsButtonAction {
+                tws_connected_action,
+                tws_disconnected_action,
+                tws_connected_is_enabled,
+                tws_disconnected_is_enabled,
+            }
+        }
+    }
+
+    prop_compose! {
+        fn a3936_internal_multi_button_configuration()(
+            left_single_click in a3936_tws_button_action(),
+            right_single_click in a3936_tws_button_action(),
+            left_double_click in a3936_tws_button_action(),
+            right_double_click in a3936_tws_button_action(),
+            left_long_press in a3936_tws_button_action(),
+            right_long_press in a3936_tws_button_action(),
+        ) -> A3936InternalMultiButtonConfiguration {
+            A3936InternalMultiButtonConfiguration {
+                left_single_click,
+                right_single_click,
+                left_double_click,
+                right_double_click,
+                left_long_press,
+                right_long_press,
+            }
+        }
+    }
+
+    prop_compose! {
+        fn a3936_state_update_packet()(
+            tws_status in tws_status(),
+            battery in dual_battery(),
+            left_firmware in firmware_version(),
+            right_firmware in firmware_version(),
+            serial_number in serial_number(),
+            equalizer_configuration in stereo_equalizer_configuration_with_two_extra_bands(),
+            age_range in age_range(),
+            custom_hear_id in custom_hear_id_without_music_type(10),
+            sound_modes in sound_modes_type_two(),
+            ambient_sound_mode_cycle in ambient_sound_mode_cycle(),
+            button_configuration in a3936_internal_multi_button_configuration(),
+            touch_tone in any::<bool>(),
+            charging_case_battery in battery_level(),
+            color in any::<u8>(),
+            ldac in any::<bool>(),
+            supports_two_cnn_switch in any::<bool>(),
+            auto_power_off_switch in any::<bool>(),
+            auto_power_off_index in any::<u8>(),
+            game_mode_switch in any::<bool>(),
+        ) -> A3936StateUpdatePacket {
+            A3936StateUpdatePacket {
+                tws_status,
+                battery,
+                left_firmware,
+                right_firmware,
+                serial_number,
+                equalizer_configuration,
+                age_range,
+                custom_hear_id,
+                sound_modes,
+                ambient_sound_mode_cycle,
+                button_configuration,
+                touch_tone,
+                charging_case_battery,
+                color,
+                ldac,
+                supports_two_cnn_switch,
+                auto_power_off_switch,
+                auto_power_off_index,
+                game_mode_switch,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3936_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3936StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3936StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
     }
 }
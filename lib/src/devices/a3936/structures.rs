@@ -104,7 +104,8 @@ pub struct A3936TwsButtonAction {
 impl A3936TwsButtonAction {
     pub fn bytes(&self) -> [u8; 2] {
         [
-            (u8::from(self.tws_disconnected_is_enabled) << 4) | u8::from(self.tws_connected_action),
+            (u8::from(self.tws_disconnected_is_enabled) << 4)
+                | u8::from(self.tws_connected_is_enabled),
             (u8::from(self.tws_disconnected_action) << 4) | (u8::from(self.tws_connected_action)),
         ]
     }
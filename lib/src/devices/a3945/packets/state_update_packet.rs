@@ -9,18 +9,19 @@ use nom::{
 use crate::devices::{
     a3945::device_profile::A3945_DEVICE_PROFILE,
     standard::{
-        packets::{inbound::state_update_packet::StateUpdatePacket, parsing::take_bool},
-        quirks::TwoExtraEqBandsValues,
+        packets::{
+            inbound::{state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody},
+            parsing::take_bool,
+        },
         structures::{
-            BatteryLevel, DualBattery, EqualizerConfiguration, FirmwareVersion,
-            InternalMultiButtonConfiguration, SerialNumber, StereoEqualizerConfiguration,
-            TwsStatus,
+            BatteryLevel, Command, DualBattery, DualFirmwareVersion, EqualizerConfiguration,
+            FirmwareVersion, InternalMultiButtonConfiguration, SerialNumber,
+            StereoEqualizerConfiguration, TwsStatus,
         },
     },
 };
 
 // A3945 only
-// Despite EQ being 10 bands, only the first 8 seem to be used?
 #[derive(Debug, Clone, PartialEq)]
 pub struct A3945StateUpdatePacket {
     pub tws_status: TwsStatus,
@@ -30,7 +31,6 @@ pub struct A3945StateUpdatePacket {
     pub serial_number: SerialNumber,
     pub left_equalizer_configuration: EqualizerConfiguration,
     pub right_equalizer_configuration: EqualizerConfiguration,
-    pub extra_band_values: TwoExtraEqBandsValues,
     pub button_configuration: InternalMultiButtonConfiguration,
     pub touch_tone_switch: bool,
     pub wear_detection_switch: bool,
@@ -42,26 +42,62 @@ pub struct A3945StateUpdatePacket {
 
 impl From<A3945StateUpdatePacket> for StateUpdatePacket {
     fn from(packet: A3945StateUpdatePacket) -> Self {
+        let dual_firmware_version =
+            DualFirmwareVersion::new(packet.left_firmware, packet.right_firmware);
         Self {
             device_profile: &A3945_DEVICE_PROFILE,
             tws_status: Some(packet.tws_status),
             battery: packet.battery.into(),
+            charging_case_battery_level: Some(packet.charging_case_battery_level),
             equalizer_configuration: packet.left_equalizer_configuration,
+            right_equalizer_configuration: Some(packet.right_equalizer_configuration),
             sound_modes: None,
             age_range: None,
             gender: None,
             hear_id: None,
             button_configuration: Some(packet.button_configuration.into()),
-            firmware_version: Some(packet.left_firmware.min(packet.right_firmware)),
+            dual_firmware_version: Some(dual_firmware_version),
             serial_number: Some(packet.serial_number),
             ambient_sound_mode_cycle: None,
             sound_modes_type_two: None,
+            wear_detection: None,
+            touch_tone: None,
+            auto_power_off: None,
+            game_mode: None,
+            ldac: None,
         }
     }
 }
 
-impl A3945StateUpdatePacket {
-    pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+impl InboundPacketBody for A3945StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(78);
+        bytes.extend(self.tws_status.bytes());
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.left_firmware.bytes());
+        bytes.extend(self.right_firmware.bytes());
+        bytes.extend(self.serial_number.bytes());
+        bytes.extend(StereoEqualizerConfiguration::bytes_with_two_extra_bands(
+            &self.left_equalizer_configuration,
+            &self.right_equalizer_configuration,
+        ));
+        bytes.extend(self.button_configuration.bytes());
+        bytes.push(self.touch_tone_switch.into());
+        bytes.push(self.wear_detection_switch.into());
+        bytes.push(self.game_mode_switch.into());
+        bytes.push(self.charging_case_battery_level.0);
+        bytes.push(self.bass_up_switch.into());
+        bytes.push(self.device_color);
+        bytes
+    }
+}
+
+impl InboundPacket for A3945StateUpdatePacket {
+    fn command() -> Command {
+        StateUpdatePacket::command()
+    }
+
+    fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], A3945StateUpdatePacket, E> {
         context(
@@ -88,7 +124,7 @@ impl A3945StateUpdatePacket {
                     left_firmware,
                     right_firmware,
                     serial_number,
-                    (equalizer_configuration, extra_band_values),
+                    equalizer_configuration,
                     button_configuration,
                     touch_tone_switch,
                     wear_detection_switch,
@@ -105,7 +141,6 @@ impl A3945StateUpdatePacket {
                         serial_number,
                         left_equalizer_configuration: equalizer_configuration.left,
                         right_equalizer_configuration: equalizer_configuration.right,
-                        extra_band_values,
                         button_configuration,
                         touch_tone_switch,
                         wear_detection_switch,
@@ -119,3 +154,65 @@ impl A3945StateUpdatePacket {
         )(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nom::error::VerboseError;
+    use proptest::prelude::*;
+
+    use crate::devices::standard::{
+        packets::inbound::{take_inbound_packet_header, InboundPacket, InboundPacketBytesExt},
+        structures::strategies::{
+            battery_level, dual_battery, firmware_version, internal_multi_button_configuration,
+            serial_number, stereo_equalizer_configuration_with_two_extra_bands, tws_status,
+        },
+    };
+
+    use super::*;
+
+    prop_compose! {
+        fn a3945_state_update_packet()(
+            tws_status in tws_status(),
+            battery in dual_battery(),
+            left_firmware in firmware_version(),
+            right_firmware in firmware_version(),
+            serial_number in serial_number(),
+            equalizer_configuration in stereo_equalizer_configuration_with_two_extra_bands(),
+            button_configuration in internal_multi_button_configuration(),
+            touch_tone_switch in any::<bool>(),
+            wear_detection_switch in any::<bool>(),
+            game_mode_switch in any::<bool>(),
+            charging_case_battery_level in battery_level(),
+            bass_up_switch in any::<bool>(),
+            device_color in any::<u8>(),
+        ) -> A3945StateUpdatePacket {
+            A3945StateUpdatePacket {
+                tws_status,
+                battery,
+                left_firmware,
+                right_firmware,
+                serial_number,
+                left_equalizer_configuration: equalizer_configuration.left,
+                right_equalizer_configuration: equalizer_configuration.right,
+                button_configuration,
+                touch_tone_switch,
+                wear_detection_switch,
+                game_mode_switch,
+                charging_case_battery_level,
+                bass_up_switch,
+                device_color,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3945_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3945StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3945StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
+    }
+}
@@ -10,12 +10,12 @@ use crate::devices::{
     a3951::device_profile::A3951_DEVICE_PROFILE,
     standard::{
         packets::{
-            inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
+            inbound::{state_update_packet::StateUpdatePacket, InboundPacket, InboundPacketBody},
             parsing::take_bool,
         },
         structures::{
-            AgeRange, CustomHearId, DualBattery, EqualizerConfiguration, Gender,
-            InternalMultiButtonConfiguration, SoundModes, StereoEqualizerConfiguration, TwsStatus,
+            AgeRange, CustomHearId, DualBattery, Gender, InternalMultiButtonConfiguration,
+            SoundModes, StereoEqualizerConfiguration, TwsStatus,
         },
     },
 };
@@ -25,7 +25,7 @@ use crate::devices::{
 pub struct A3951StateUpdatePacket {
     pub tws_status: TwsStatus,
     pub battery: DualBattery,
-    pub equalizer_configuration: EqualizerConfiguration,
+    pub equalizer_configuration: StereoEqualizerConfiguration,
     pub gender: Gender,
     pub age_range: AgeRange,
     pub custom_hear_id: CustomHearId,
@@ -46,20 +46,52 @@ impl From<A3951StateUpdatePacket> for StateUpdatePacket {
             device_profile: &A3951_DEVICE_PROFILE,
             tws_status: Some(packet.tws_status),
             battery: packet.battery.into(),
-            equalizer_configuration: packet.equalizer_configuration,
+            charging_case_battery_level: None,
+            equalizer_configuration: packet.equalizer_configuration.left,
+            right_equalizer_configuration: Some(packet.equalizer_configuration.right),
             sound_modes: Some(packet.sound_modes),
             age_range: Some(packet.age_range),
             gender: Some(packet.gender),
             hear_id: Some(packet.custom_hear_id.into()),
             button_configuration: Some(packet.button_configuration.into()),
-            firmware_version: None,
+            dual_firmware_version: None,
             serial_number: None,
             ambient_sound_mode_cycle: None,
             sound_modes_type_two: None,
+            wear_detection: Some(packet.wear_detection),
+            touch_tone: Some(packet.touch_tone),
+            auto_power_off: None,
+            game_mode: None,
+            ldac: None,
         }
     }
 }
 
+impl InboundPacketBody for A3951StateUpdatePacket {
+    fn body(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(98);
+        bytes.extend(self.tws_status.bytes());
+        bytes.extend(self.battery.bytes());
+        bytes.extend(self.equalizer_configuration.bytes());
+        bytes.push(self.gender.0);
+        bytes.push(self.age_range.0);
+        bytes.extend(self.custom_hear_id.bytes_with_all_fields());
+        bytes.extend(self.button_configuration.bytes());
+        bytes.extend(self.sound_modes.bytes());
+        bytes.push(self.side_tone.into());
+        bytes.push(self.wear_detection.into());
+        bytes.push(self.touch_tone.into());
+        // The new battery fields can only be present if the eq preset is
+        if let Some(hear_id_eq_preset) = self.hear_id_eq_preset {
+            bytes.extend(hear_id_eq_preset.to_le_bytes());
+            if self.supports_new_battery {
+                bytes.extend([self.left_new_battery, self.right_new_battery]);
+            }
+        }
+        bytes
+    }
+}
+
 impl InboundPacket for A3951StateUpdatePacket {
     fn command() -> crate::devices::standard::structures::Command {
         StateUpdatePacket::command()
@@ -130,3 +162,75 @@ impl InboundPacket for A3951StateUpdatePacket {
         )(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nom::error::VerboseError;
+    use proptest::prelude::*;
+
+    use crate::devices::standard::{
+        packets::inbound::{take_inbound_packet_header, InboundPacket, InboundPacketBytesExt},
+        structures::strategies::{
+            age_range, custom_hear_id_with_all_fields, dual_battery, gender,
+            internal_multi_button_configuration, sound_modes, stereo_equalizer_configuration,
+            tws_status,
+        },
+    };
+
+    use super::*;
+
+    prop_compose! {
+        fn a3951_state_update_packet()(
+            tws_status in tws_status(),
+            battery in dual_battery(),
+            equalizer_configuration in stereo_equalizer_configuration(),
+            gender in gender(),
+            age_range in age_range(),
+            custom_hear_id in custom_hear_id_with_all_fields(),
+            button_configuration in internal_multi_button_configuration(),
+            sound_modes in sound_modes(),
+            side_tone in any::<bool>(),
+            wear_detection in any::<bool>(),
+            touch_tone in any::<bool>(),
+            optional_fields in proptest::option::of((
+                any::<u16>(),
+                proptest::option::of(any::<[u8; 2]>()),
+            )),
+        ) -> A3951StateUpdatePacket {
+            // The new battery levels can only be sent along with the hear id eq preset
+            let (hear_id_eq_preset, new_battery) = match optional_fields {
+                Some((hear_id_eq_preset, new_battery)) => (Some(hear_id_eq_preset), new_battery),
+                None => (None, None),
+            };
+            let [left_new_battery, right_new_battery] = new_battery.unwrap_or_default();
+            A3951StateUpdatePacket {
+                tws_status,
+                battery,
+                equalizer_configuration,
+                gender,
+                age_range,
+                custom_hear_id,
+                button_configuration,
+                sound_modes,
+                side_tone,
+                wear_detection,
+                touch_tone,
+                hear_id_eq_preset,
+                supports_new_battery: new_battery.is_some(),
+                left_new_battery,
+                right_new_battery,
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn it_parses_its_own_bytes(packet in a3951_state_update_packet()) {
+            let bytes = packet.bytes();
+            let (body, command) = take_inbound_packet_header::<VerboseError<_>>(&bytes).unwrap();
+            let parsed = A3951StateUpdatePacket::take::<VerboseError<_>>(body).unwrap().1;
+            prop_assert_eq!(A3951StateUpdatePacket::command(), command);
+            prop_assert_eq!(packet, parsed);
+        }
+    }
+}
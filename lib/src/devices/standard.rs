@@ -1,3 +1,4 @@
+pub mod device_event;
 pub mod implementation;
 pub mod packets;
 pub mod quirks;
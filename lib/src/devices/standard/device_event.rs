@@ -0,0 +1,161 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tokio::sync::{broadcast, watch};
+
+use super::{
+    state::DeviceState,
+    structures::{
+        Battery, BatteryLevel, EqualizerConfiguration, MultiButtonConfiguration, SoundModes,
+        SoundModesTypeTwo, TwsStatus,
+    },
+};
+
+/// How many events a subscriber can fall behind by before it starts missing them.
+const EVENT_CHANNEL_CAPACITY: usize = 32;
+
+/// A single change to a [`DeviceState`], as produced by [`DeviceState::diff`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", tag = "type"))]
+pub enum DeviceEvent {
+    BatteryChanged {
+        old: Battery,
+        new: Battery,
+    },
+    ChargingCaseBatteryLevelChanged {
+        old: Option<BatteryLevel>,
+        new: Option<BatteryLevel>,
+    },
+    SoundModesChanged {
+        old: Option<SoundModes>,
+        new: Option<SoundModes>,
+    },
+    SoundModesTypeTwoChanged {
+        old: Option<SoundModesTypeTwo>,
+        new: Option<SoundModesTypeTwo>,
+    },
+    /// The left channel, or the only channel for devices without a stereo equalizer.
+    EqualizerConfigurationChanged {
+        old: EqualizerConfiguration,
+        new: EqualizerConfiguration,
+    },
+    RightEqualizerConfigurationChanged {
+        old: Option<EqualizerConfiguration>,
+        new: Option<EqualizerConfiguration>,
+    },
+    TwsStatusChanged {
+        old: Option<TwsStatus>,
+        new: Option<TwsStatus>,
+    },
+    ButtonConfigurationChanged {
+        old: Option<MultiButtonConfiguration>,
+        new: Option<MultiButtonConfiguration>,
+    },
+}
+
+pub(crate) fn event_channel() -> broadcast::Sender<DeviceEvent> {
+    broadcast::channel(EVENT_CHANNEL_CAPACITY).0
+}
+
+/// Replaces the state and broadcasts an event for everything that changed. Having no event
+/// subscribers is not an error.
+pub(crate) fn send_state(
+    state_sender: &watch::Sender<DeviceState>,
+    event_sender: &broadcast::Sender<DeviceEvent>,
+    new_state: DeviceState,
+) {
+    let events = state_sender.borrow().diff(&new_state);
+    state_sender.send_replace(new_state);
+    for event in events {
+        let _ = event_sender.send(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::devices::standard::structures::{
+        AmbientSoundMode, HostDevice, IsBatteryCharging, PresetEqualizerProfile, SingleBattery,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_diff_of_equal_states_is_empty() {
+        let state = DeviceState::default();
+        assert_eq!(Vec::<DeviceEvent>::new(), state.diff(&state.to_owned()));
+    }
+
+    #[test]
+    fn test_diff_lists_changes_in_field_order() {
+        let old = DeviceState {
+            sound_modes: Some(SoundModes::default()),
+            ..Default::default()
+        };
+        let new = DeviceState {
+            tws_status: Some(TwsStatus {
+                is_connected: true,
+                host_device: HostDevice::Left,
+            }),
+            battery: SingleBattery {
+                is_charging: IsBatteryCharging::Yes,
+                level: BatteryLevel(3),
+            }
+            .into(),
+            equalizer_configuration: EqualizerConfiguration::new_from_preset_profile(
+                PresetEqualizerProfile::Acoustic,
+            ),
+            sound_modes: Some(SoundModes {
+                ambient_sound_mode: AmbientSoundMode::Transparency,
+                ..Default::default()
+            }),
+            // Not covered by any event
+            touch_tone: Some(true),
+            ..old.to_owned()
+        };
+
+        assert_eq!(
+            vec![
+                DeviceEvent::TwsStatusChanged {
+                    old: None,
+                    new: new.tws_status,
+                },
+                DeviceEvent::BatteryChanged {
+                    old: old.battery.to_owned(),
+                    new: new.battery.to_owned(),
+                },
+                DeviceEvent::EqualizerConfigurationChanged {
+                    old: old.equalizer_configuration.to_owned(),
+                    new: new.equalizer_configuration.to_owned(),
+                },
+                DeviceEvent::SoundModesChanged {
+                    old: old.sound_modes,
+                    new: new.sound_modes,
+                },
+            ],
+            old.diff(&new),
+        );
+    }
+
+    #[test]
+    fn test_send_state_broadcasts_changes() {
+        let (state_sender, state_receiver) = watch::channel(DeviceState::default());
+        let event_sender = event_channel();
+        let mut event_receiver = event_sender.subscribe();
+        let new_state = DeviceState {
+            charging_case_battery_level: Some(BatteryLevel(2)),
+            ..Default::default()
+        };
+
+        send_state(&state_sender, &event_sender, new_state.to_owned());
+
+        assert_eq!(new_state, *state_receiver.borrow());
+        assert_eq!(
+            DeviceEvent::ChargingCaseBatteryLevelChanged {
+                old: None,
+                new: Some(BatteryLevel(2)),
+            },
+            event_receiver.try_recv().unwrap(),
+        );
+        assert!(event_receiver.try_recv().is_err());
+    }
+}
@@ -1,21 +1,33 @@
 mod ambient_sound_mode_cycle;
+mod auto_power_off;
 mod button_configuration;
 mod equalizer_configuration;
+mod game_mode;
 mod hear_id;
+mod ldac;
 mod packet_handlers;
 mod sound_modes;
 mod sound_modes_type_two;
+mod touch_tone;
+mod voice_prompt;
+mod wear_detection;
 
 use std::{collections::HashMap, sync::Arc};
 
 pub use ambient_sound_mode_cycle::*;
+pub use auto_power_off::*;
 pub use button_configuration::*;
 pub use equalizer_configuration::*;
+pub use game_mode::*;
 pub use hear_id::*;
+pub use ldac::*;
 use nom::error::VerboseError;
 pub use packet_handlers::*;
 pub use sound_modes::*;
 pub use sound_modes_type_two::*;
+pub use touch_tone::*;
+pub use voice_prompt::*;
+pub use wear_detection::*;
 
 use super::{
     packets::inbound::{state_update_packet::StateUpdatePacket, InboundPacket},
@@ -89,6 +101,14 @@ impl DeviceImplementation for StandardImplementation {
         set_equalizer_configuration(state, equalizer_configuration)
     }
 
+    fn set_stereo_equalizer_configuration(
+        &self,
+        state: DeviceState,
+        equalizer_configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<crate::soundcore_device::device::soundcore_command::CommandResponse> {
+        set_stereo_equalizer_configuration(state, equalizer_configuration)
+    }
+
     fn set_hear_id(
         &self,
         state: DeviceState,
@@ -106,4 +126,54 @@ impl DeviceImplementation for StandardImplementation {
             feature_name: "custom button actions",
         })
     }
+
+    fn set_wear_detection(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<crate::soundcore_device::device::soundcore_command::CommandResponse> {
+        set_wear_detection(state, is_enabled)
+    }
+
+    fn set_touch_tone(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<crate::soundcore_device::device::soundcore_command::CommandResponse> {
+        set_touch_tone(state, is_enabled)
+    }
+
+    fn set_auto_power_off(
+        &self,
+        _state: DeviceState,
+        _auto_power_off: AutoPowerOff,
+    ) -> crate::Result<crate::soundcore_device::device::soundcore_command::CommandResponse> {
+        Err(crate::Error::FeatureNotSupported {
+            feature_name: "auto power off",
+        })
+    }
+
+    fn set_game_mode(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<crate::soundcore_device::device::soundcore_command::CommandResponse> {
+        set_game_mode(state, is_enabled)
+    }
+
+    fn set_ldac(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<crate::soundcore_device::device::soundcore_command::CommandResponse> {
+        set_ldac(state, is_enabled)
+    }
+
+    fn set_voice_prompt(
+        &self,
+        state: DeviceState,
+        voice_prompt: VoicePrompt,
+    ) -> crate::Result<crate::soundcore_device::device::soundcore_command::CommandResponse> {
+        set_voice_prompt(state, voice_prompt)
+    }
 }
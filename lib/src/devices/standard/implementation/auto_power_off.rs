@@ -0,0 +1,29 @@
+use crate::{
+    devices::standard::{
+        packets::outbound::SetAutoPowerOffPacket, state::DeviceState, structures::AutoPowerOff,
+    },
+    soundcore_device::device::soundcore_command::CommandResponse,
+};
+
+pub fn set_auto_power_off(
+    state: DeviceState,
+    auto_power_off: AutoPowerOff,
+) -> crate::Result<CommandResponse> {
+    if !state.device_features.has_auto_power_off {
+        return Err(crate::Error::FeatureNotSupported {
+            feature_name: "auto power off",
+        });
+    }
+
+    let packet = SetAutoPowerOffPacket {
+        is_enabled: auto_power_off.is_enabled,
+        duration_index: auto_power_off.index(),
+    };
+    Ok(CommandResponse {
+        packets: vec![packet.into()],
+        new_state: DeviceState {
+            auto_power_off: Some(auto_power_off),
+            ..state
+        },
+    })
+}
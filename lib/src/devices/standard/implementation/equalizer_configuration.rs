@@ -4,7 +4,7 @@ use crate::{
             SetEqualizerAndCustomHearIdPacket, SetEqualizerPacket, SetEqualizerWithDrcPacket,
         },
         state::DeviceState,
-        structures::{EqualizerConfiguration, HearId},
+        structures::{EqualizerConfiguration, HearId, StereoEqualizerConfiguration},
     },
     soundcore_device::device::{soundcore_command::CommandResponse, Packet},
 };
@@ -13,16 +13,46 @@ pub fn set_equalizer_configuration(
     state: DeviceState,
     equalizer_configuration: EqualizerConfiguration,
 ) -> crate::Result<CommandResponse> {
-    let left_channel = &equalizer_configuration;
     let right_channel = if state.device_features.num_equalizer_channels == 2 {
-        Some(&equalizer_configuration)
+        Some(equalizer_configuration.to_owned())
     } else {
         None
     };
+    // Single channel devices that still report a right channel apply the left channel to both
+    let has_right_channel = state.right_equalizer_configuration.is_some();
+    let mut response =
+        set_equalizer_channels(state, equalizer_configuration.to_owned(), right_channel)?;
+    if has_right_channel {
+        response.new_state.right_equalizer_configuration = Some(equalizer_configuration);
+    }
+    Ok(response)
+}
+
+pub fn set_stereo_equalizer_configuration(
+    state: DeviceState,
+    equalizer_configuration: StereoEqualizerConfiguration,
+) -> crate::Result<CommandResponse> {
+    if state.device_features.num_equalizer_channels != 2 {
+        return Err(crate::Error::FeatureNotSupported {
+            feature_name: "stereo equalizer",
+        });
+    }
+    set_equalizer_channels(
+        state,
+        equalizer_configuration.left,
+        Some(equalizer_configuration.right),
+    )
+}
 
+fn set_equalizer_channels(
+    state: DeviceState,
+    left_channel: EqualizerConfiguration,
+    right_channel: Option<EqualizerConfiguration>,
+) -> crate::Result<CommandResponse> {
     let packet: Packet = if let Some(HearId::Custom(custom_hear_id)) = &state.hear_id {
         SetEqualizerAndCustomHearIdPacket {
-            equalizer_configuration: &equalizer_configuration,
+            equalizer_configuration: &left_channel,
+            right_equalizer_configuration: right_channel.as_ref(),
             age_range: state.age_range.ok_or(crate::Error::IncompleteStateError {
                 message: "age range not set",
             })?,
@@ -33,15 +63,113 @@ pub fn set_equalizer_configuration(
         }
         .into()
     } else if state.supports_dynamic_range_compression() {
-        SetEqualizerWithDrcPacket::new(left_channel, right_channel).into()
+        SetEqualizerWithDrcPacket::new(&left_channel, right_channel.as_ref()).into()
     } else {
-        SetEqualizerPacket::new(left_channel, right_channel).into()
+        SetEqualizerPacket::new(&left_channel, right_channel.as_ref()).into()
     };
     Ok(CommandResponse {
         packets: vec![packet],
         new_state: DeviceState {
-            equalizer_configuration,
+            equalizer_configuration: left_channel,
+            right_equalizer_configuration: right_channel,
             ..state
         },
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        device_profile::DeviceFeatures,
+        devices::standard::{
+            packets::outbound::SetEqualizerPacket,
+            state::DeviceState,
+            structures::{
+                EqualizerConfiguration, PresetEqualizerProfile, StereoEqualizerConfiguration,
+                VolumeAdjustments,
+            },
+        },
+        soundcore_device::device::Packet,
+    };
+
+    use super::*;
+
+    fn state_with_channels(num_equalizer_channels: usize) -> DeviceState {
+        DeviceState {
+            device_features: DeviceFeatures {
+                num_equalizer_channels,
+                num_equalizer_bands: 8,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn it_sends_separate_channels() {
+        let left = EqualizerConfiguration::new_custom_profile(
+            VolumeAdjustments::new([1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]).unwrap(),
+        );
+        let right = EqualizerConfiguration::new_custom_profile(
+            VolumeAdjustments::new([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0]).unwrap(),
+        );
+        let response = set_stereo_equalizer_configuration(
+            state_with_channels(2),
+            StereoEqualizerConfiguration {
+                left: left.to_owned(),
+                right: right.to_owned(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![Packet::from(SetEqualizerPacket::new(&left, Some(&right)))],
+            response.packets,
+        );
+        assert_eq!(left, response.new_state.equalizer_configuration);
+        assert_eq!(
+            Some(right),
+            response.new_state.right_equalizer_configuration
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_reported_right_channel_in_sync_on_single_channel_devices() {
+        let configuration =
+            EqualizerConfiguration::new_from_preset_profile(PresetEqualizerProfile::BassBooster);
+        let response = set_equalizer_configuration(
+            DeviceState {
+                right_equalizer_configuration: Some(EqualizerConfiguration::default()),
+                ..state_with_channels(1)
+            },
+            configuration.to_owned(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![Packet::from(SetEqualizerPacket::new(&configuration, None))],
+            response.packets,
+        );
+        assert_eq!(
+            Some(configuration),
+            response.new_state.right_equalizer_configuration
+        );
+    }
+
+    #[test]
+    fn it_refuses_stereo_configuration_for_single_channel_devices() {
+        let configuration =
+            EqualizerConfiguration::new_from_preset_profile(PresetEqualizerProfile::BassBooster);
+        let result = set_stereo_equalizer_configuration(
+            state_with_channels(1),
+            StereoEqualizerConfiguration {
+                left: configuration.to_owned(),
+                right: configuration,
+            },
+        );
+        assert!(matches!(
+            result,
+            Err(crate::Error::FeatureNotSupported { .. })
+        ));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    devices::standard::{packets::outbound::SetGameModePacket, state::DeviceState},
+    soundcore_device::device::soundcore_command::CommandResponse,
+};
+
+pub fn set_game_mode(state: DeviceState, is_enabled: bool) -> crate::Result<CommandResponse> {
+    if !state.device_features.has_game_mode {
+        return Err(crate::Error::FeatureNotSupported {
+            feature_name: "game mode",
+        });
+    }
+
+    let packet = SetGameModePacket { is_enabled };
+    Ok(CommandResponse {
+        packets: vec![packet.into()],
+        new_state: DeviceState {
+            game_mode: Some(is_enabled),
+            ..state
+        },
+    })
+}
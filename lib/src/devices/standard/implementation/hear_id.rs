@@ -36,6 +36,7 @@ fn set_basic_hear_id(
 
     let packet = SetEqualizerAndCustomHearIdPacket {
         equalizer_configuration: &state.equalizer_configuration,
+        right_equalizer_configuration: state.right_equalizer_configuration.as_ref(),
         gender,
         age_range,
         custom_hear_id: &custom_hear_id,
@@ -59,6 +60,7 @@ fn set_custom_hear_id(state: DeviceState, hear_id: CustomHearId) -> crate::Resul
         .ok_or(crate::Error::MissingData { name: "age range" })?;
     let packet = SetEqualizerAndCustomHearIdPacket {
         equalizer_configuration: &state.equalizer_configuration,
+        right_equalizer_configuration: state.right_equalizer_configuration.as_ref(),
         gender,
         age_range,
         custom_hear_id: &hear_id,
@@ -0,0 +1,21 @@
+use crate::{
+    devices::standard::{packets::outbound::SetLdacPacket, state::DeviceState},
+    soundcore_device::device::soundcore_command::CommandResponse,
+};
+
+pub fn set_ldac(state: DeviceState, is_enabled: bool) -> crate::Result<CommandResponse> {
+    if !state.device_features.has_ldac {
+        return Err(crate::Error::FeatureNotSupported {
+            feature_name: "LDAC",
+        });
+    }
+
+    let packet = SetLdacPacket { is_enabled };
+    Ok(CommandResponse {
+        packets: vec![packet.into()],
+        new_state: DeviceState {
+            ldac: Some(is_enabled),
+            ..state
+        },
+    })
+}
@@ -5,15 +5,23 @@ use crate::devices::standard::{packets::inbound::*, state::DeviceState};
 mod battery_charging_update;
 mod battery_level_update;
 mod firmware_version_update;
+mod game_mode_update;
+mod ldac_state_update;
 mod sound_mode_update;
 mod state_update;
+mod tws_status_update;
+mod voice_prompt_update;
 
 pub use battery_charging_update::*;
 pub use battery_level_update::*;
 pub use firmware_version_update::*;
+pub use game_mode_update::*;
+pub use ldac_state_update::*;
 pub use sound_mode_update::*;
 pub use state_update::*;
 use state_update_packet::StateUpdatePacket;
+pub use tws_status_update::*;
+pub use voice_prompt_update::*;
 
 use super::Command;
 
@@ -42,19 +50,19 @@ pub fn packet_handlers(
         (StateUpdatePacket::command(), Box::new(state_update_handler)),
         (
             TwsStatusUpdatePacket::command(),
-            Box::new(do_nothing_handler),
+            Box::new(tws_status_update_handler),
         ),
         (
             LdacStateUpdatePacket::command(),
-            Box::new(do_nothing_handler),
+            Box::new(ldac_state_update_handler),
         ),
         (
             ChineseVoicePromptStateUpdatePacket::command(),
-            Box::new(do_nothing_handler),
+            Box::new(voice_prompt_update_handler),
         ),
         (
             GameModeUpdatePacket::command(),
-            Box::new(do_nothing_handler),
+            Box::new(game_mode_update_handler),
         ),
     ];
     let num_handlers = handlers.len();
@@ -66,9 +74,3 @@ pub fn packet_handlers(
     );
     handlers_map
 }
-
-// For packets that we know how to parse, but we don't store any of the
-// provided information in DeviceState
-fn do_nothing_handler(_: &[u8], state: DeviceState) -> DeviceState {
-    state
-}
@@ -3,6 +3,7 @@ use nom::{combinator::all_consuming, error::VerboseError};
 use crate::devices::standard::{
     packets::inbound::{FirmwareVersionUpdatePacket, InboundPacket},
     state::DeviceState,
+    structures::DualFirmwareVersion,
 };
 
 pub fn firmware_version_update_handler(input: &[u8], state: DeviceState) -> DeviceState {
@@ -16,12 +17,52 @@ pub fn firmware_version_update_handler(input: &[u8], state: DeviceState) -> Devi
         }
     };
     DeviceState {
-        firmware_version: Some(
-            packet
-                .left_firmware_version
-                .max(packet.right_firmware_version),
-        ),
+        dual_firmware_version: Some(DualFirmwareVersion::new(
+            packet.left_firmware_version,
+            packet.right_firmware_version,
+        )),
         serial_number: Some(packet.serial_number),
         ..state.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::devices::{
+        a3931::device_profile::A3931_DEVICE_PROFILE, standard::structures::FirmwareVersion,
+    };
+
+    use super::*;
+
+    fn a3931_state_after_firmware_versions(left: &str, right: &str) -> DeviceState {
+        let state = DeviceState {
+            device_features: A3931_DEVICE_PROFILE.features,
+            ..Default::default()
+        };
+        let body = format!("{left}{right}0000000000003931");
+        firmware_version_update_handler(body.as_bytes(), state)
+    }
+
+    #[test]
+    fn it_keeps_both_earbuds_firmware_versions() {
+        let state = a3931_state_after_firmware_versions("02.00", "01.99");
+        assert_eq!(
+            Some(DualFirmwareVersion::new(
+                FirmwareVersion::new(2, 0),
+                FirmwareVersion::new(1, 99)
+            )),
+            state.dual_firmware_version,
+        );
+        assert_eq!(Some(FirmwareVersion::new(2, 0)), state.firmware_version());
+    }
+
+    #[test]
+    fn it_gates_dynamic_range_compression_on_the_newer_earbud() {
+        // The A3931 requires firmware 02.00 for dynamic range compression
+        let state = a3931_state_after_firmware_versions("01.99", "02.00");
+        assert!(state.supports_dynamic_range_compression());
+
+        let state = a3931_state_after_firmware_versions("01.98", "01.99");
+        assert!(!state.supports_dynamic_range_compression());
+    }
+}
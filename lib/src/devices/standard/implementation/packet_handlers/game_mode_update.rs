@@ -0,0 +1,22 @@
+use nom::{combinator::all_consuming, error::VerboseError};
+
+use crate::devices::standard::{
+    packets::inbound::{GameModeUpdatePacket, InboundPacket},
+    state::DeviceState,
+};
+
+pub fn game_mode_update_handler(input: &[u8], state: DeviceState) -> DeviceState {
+    let result: Result<_, nom::Err<VerboseError<&[u8]>>> =
+        all_consuming(GameModeUpdatePacket::take)(input);
+    let packet = match result {
+        Ok((_, packet)) => packet,
+        Err(err) => {
+            tracing::error!("failed to parse packet: {err:?}");
+            return state;
+        }
+    };
+    DeviceState {
+        game_mode: Some(packet.is_enabled),
+        ..state.clone()
+    }
+}
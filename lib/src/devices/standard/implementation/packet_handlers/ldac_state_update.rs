@@ -0,0 +1,22 @@
+use nom::{combinator::all_consuming, error::VerboseError};
+
+use crate::devices::standard::{
+    packets::inbound::{InboundPacket, LdacStateUpdatePacket},
+    state::DeviceState,
+};
+
+pub fn ldac_state_update_handler(input: &[u8], state: DeviceState) -> DeviceState {
+    let result: Result<_, nom::Err<VerboseError<&[u8]>>> =
+        all_consuming(LdacStateUpdatePacket::take)(input);
+    let packet = match result {
+        Ok((_, packet)) => packet,
+        Err(err) => {
+            tracing::error!("failed to parse packet: {err:?}");
+            return state;
+        }
+    };
+    DeviceState {
+        ldac: Some(packet.is_enabled),
+        ..state.clone()
+    }
+}
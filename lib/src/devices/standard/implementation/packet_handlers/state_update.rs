@@ -20,16 +20,16 @@ pub fn state_update_handler(input: &[u8], state: DeviceState) -> DeviceState {
         device_features: state.device_features,
         tws_status: packet.tws_status,
         battery: state.battery,
+        charging_case_battery_level: packet
+            .charging_case_battery_level
+            .or(state.charging_case_battery_level),
         equalizer_configuration: state.equalizer_configuration.to_owned(),
+        right_equalizer_configuration: state.right_equalizer_configuration.to_owned(),
         age_range: packet.age_range.or(state.age_range),
         gender: packet.gender.or(state.gender),
         button_configuration: packet.button_configuration.or(state.button_configuration),
         hear_id: packet.hear_id.to_owned().or(state.hear_id.to_owned()),
-        firmware_version: packet
-            .firmware_version
-            .as_ref()
-            .or(state.firmware_version.as_ref())
-            .cloned(),
+        dual_firmware_version: packet.dual_firmware_version.or(state.dual_firmware_version),
         serial_number: packet
             .serial_number
             .as_ref()
@@ -40,5 +40,11 @@ pub fn state_update_handler(input: &[u8], state: DeviceState) -> DeviceState {
         ambient_sound_mode_cycle: packet
             .ambient_sound_mode_cycle
             .or(state.ambient_sound_mode_cycle),
+        wear_detection: packet.wear_detection.or(state.wear_detection),
+        touch_tone: packet.touch_tone.or(state.touch_tone),
+        auto_power_off: packet.auto_power_off.or(state.auto_power_off),
+        game_mode: packet.game_mode.or(state.game_mode),
+        ldac: packet.ldac.or(state.ldac),
+        voice_prompt: state.voice_prompt,
     }
 }
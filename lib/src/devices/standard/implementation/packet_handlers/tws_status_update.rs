@@ -0,0 +1,26 @@
+use nom::{combinator::all_consuming, error::VerboseError};
+
+use crate::devices::standard::{
+    packets::inbound::{InboundPacket, TwsStatusUpdatePacket},
+    state::DeviceState,
+    structures::TwsStatus,
+};
+
+pub fn tws_status_update_handler(input: &[u8], state: DeviceState) -> DeviceState {
+    let result: Result<_, nom::Err<VerboseError<&[u8]>>> =
+        all_consuming(TwsStatusUpdatePacket::take)(input);
+    let packet = match result {
+        Ok((_, packet)) => packet,
+        Err(err) => {
+            tracing::error!("failed to parse packet: {err:?}");
+            return state;
+        }
+    };
+    DeviceState {
+        tws_status: Some(TwsStatus {
+            is_connected: packet.tws_status,
+            host_device: packet.host_device,
+        }),
+        ..state.clone()
+    }
+}
@@ -0,0 +1,23 @@
+use nom::{combinator::all_consuming, error::VerboseError};
+
+use crate::devices::standard::{
+    packets::inbound::{ChineseVoicePromptStateUpdatePacket, InboundPacket},
+    state::DeviceState,
+    structures::VoicePrompt,
+};
+
+pub fn voice_prompt_update_handler(input: &[u8], state: DeviceState) -> DeviceState {
+    let result: Result<_, nom::Err<VerboseError<&[u8]>>> =
+        all_consuming(ChineseVoicePromptStateUpdatePacket::take)(input);
+    let packet = match result {
+        Ok((_, packet)) => packet,
+        Err(err) => {
+            tracing::error!("failed to parse packet: {err:?}");
+            return state;
+        }
+    };
+    DeviceState {
+        voice_prompt: Some(VoicePrompt::from_is_chinese(packet.is_enabled)),
+        ..state.clone()
+    }
+}
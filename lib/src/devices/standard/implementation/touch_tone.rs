@@ -0,0 +1,21 @@
+use crate::{
+    devices::standard::{packets::outbound::SetTouchTonePacket, state::DeviceState},
+    soundcore_device::device::soundcore_command::CommandResponse,
+};
+
+pub fn set_touch_tone(state: DeviceState, is_enabled: bool) -> crate::Result<CommandResponse> {
+    if !state.device_features.has_touch_tone {
+        return Err(crate::Error::FeatureNotSupported {
+            feature_name: "touch tone",
+        });
+    }
+
+    let packet = SetTouchTonePacket { is_enabled };
+    Ok(CommandResponse {
+        packets: vec![packet.into()],
+        new_state: DeviceState {
+            touch_tone: Some(is_enabled),
+            ..state
+        },
+    })
+}
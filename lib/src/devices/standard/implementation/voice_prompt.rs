@@ -0,0 +1,26 @@
+use crate::{
+    devices::standard::{
+        packets::outbound::SetVoicePromptPacket, state::DeviceState, structures::VoicePrompt,
+    },
+    soundcore_device::device::soundcore_command::CommandResponse,
+};
+
+pub fn set_voice_prompt(
+    state: DeviceState,
+    voice_prompt: VoicePrompt,
+) -> crate::Result<CommandResponse> {
+    if !state.device_features.has_voice_prompt {
+        return Err(crate::Error::FeatureNotSupported {
+            feature_name: "voice prompt",
+        });
+    }
+
+    let packet = SetVoicePromptPacket { voice_prompt };
+    Ok(CommandResponse {
+        packets: vec![packet.into()],
+        new_state: DeviceState {
+            voice_prompt: Some(voice_prompt),
+            ..state
+        },
+    })
+}
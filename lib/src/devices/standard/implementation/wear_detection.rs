@@ -0,0 +1,21 @@
+use crate::{
+    devices::standard::{packets::outbound::SetWearDetectionPacket, state::DeviceState},
+    soundcore_device::device::soundcore_command::CommandResponse,
+};
+
+pub fn set_wear_detection(state: DeviceState, is_enabled: bool) -> crate::Result<CommandResponse> {
+    if !state.device_features.has_wear_detection {
+        return Err(crate::Error::FeatureNotSupported {
+            feature_name: "wear detection",
+        });
+    }
+
+    let packet = SetWearDetectionPacket { is_enabled };
+    Ok(CommandResponse {
+        packets: vec![packet.into()],
+        new_state: DeviceState {
+            wear_detection: Some(is_enabled),
+            ..state
+        },
+    })
+}
@@ -1,3 +1,4 @@
+mod any_inbound_packet;
 mod battery_charging_update_packet;
 mod battery_level_update_packet;
 mod chinese_voice_propt_state_update_packet;
@@ -10,6 +11,7 @@ mod sound_mode_update_packet;
 pub mod state_update_packet;
 mod tws_status_update_packet;
 
+pub use any_inbound_packet::*;
 pub use battery_charging_update_packet::*;
 pub use battery_level_update_packet::*;
 pub use chinese_voice_propt_state_update_packet::*;
@@ -0,0 +1,395 @@
+use std::panic::Location;
+
+use nom::{
+    combinator::{all_consuming, map},
+    error::VerboseError,
+    IResult,
+};
+use strum::IntoStaticStr;
+
+use crate::{
+    devices::{
+        a3027::packets::A3027StateUpdatePacket, a3028::packets::A3028StateUpdatePacket,
+        a3031::packets::A3031StateUpdatePacket, a3033::packets::A3033StateUpdatePacket,
+        a3926::packets::A3926StateUpdatePacket, a3930::packets::A3930StateUpdatePacket,
+        a3931::packets::A3931StateUpdatePacket, a3933::packets::inbound::A3933StateUpdatePacket,
+        a3936::packets::A3936StateUpdatePacket, a3945::packets::A3945StateUpdatePacket,
+        a3951::packets::A3951StateUpdatePacket, standard::structures::Command,
+    },
+    soundcore_device::device::Packet,
+};
+
+use super::{
+    state_update_packet::StateUpdatePacket, BatteryChargingUpdatePacket, BatteryLevelUpdatePacket,
+    ChineseVoicePromptStateUpdatePacket, FirmwareVersionUpdatePacket, GameModeUpdatePacket,
+    InboundPacket, LdacStateUpdatePacket, SoundModeTypeTwoUpdatePacket, SoundModeUpdatePacket,
+    TwsStatusUpdatePacket,
+};
+
+/// Any packet we know how to decode. State update packets are split up by model, since each model
+/// lays them out differently.
+#[derive(Debug, Clone, PartialEq, IntoStaticStr)]
+pub enum AnyInboundPacket {
+    A3027StateUpdate(A3027StateUpdatePacket),
+    A3028StateUpdate(A3028StateUpdatePacket),
+    A3031StateUpdate(A3031StateUpdatePacket),
+    A3033StateUpdate(A3033StateUpdatePacket),
+    A3926StateUpdate(A3926StateUpdatePacket),
+    A3930StateUpdate(A3930StateUpdatePacket),
+    A3931StateUpdate(A3931StateUpdatePacket),
+    A3933StateUpdate(A3933StateUpdatePacket),
+    A3936StateUpdate(A3936StateUpdatePacket),
+    A3945StateUpdate(A3945StateUpdatePacket),
+    A3951StateUpdate(A3951StateUpdatePacket),
+    TwsStatusUpdate(TwsStatusUpdatePacket),
+    BatteryLevelUpdate(BatteryLevelUpdatePacket),
+    BatteryChargingUpdate(BatteryChargingUpdatePacket),
+    FirmwareVersionUpdate(FirmwareVersionUpdatePacket),
+    SoundModeUpdate(SoundModeUpdatePacket),
+    SoundModeTypeTwoUpdate(SoundModeTypeTwoUpdatePacket),
+    ChineseVoicePromptStateUpdate(ChineseVoicePromptStateUpdatePacket),
+    GameModeUpdate(GameModeUpdatePacket),
+    LdacStateUpdate(LdacStateUpdatePacket),
+}
+
+/// One entry in the catalogue of known packets. Several entries can share a command, in which case
+/// they are tried in catalogue order.
+pub struct InboundPacketDecoder {
+    pub name: &'static str,
+    pub command: Command,
+    pub take: for<'a> fn(&'a [u8]) -> IResult<&'a [u8], AnyInboundPacket, VerboseError<&'a [u8]>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnyInboundPacketError {
+    #[error("no known packet has command {0:?}")]
+    UnknownCommand(Command),
+    #[error("packet with command {command:?} did not match any known layout: {message}")]
+    Malformed { command: Command, message: String },
+}
+
+impl From<AnyInboundPacketError> for crate::Error {
+    #[track_caller]
+    fn from(error: AnyInboundPacketError) -> Self {
+        Self::Other {
+            source: Box::new(error),
+            location: Location::caller(),
+        }
+    }
+}
+
+impl AnyInboundPacket {
+    /// Every known packet. State update packets are in the same order that [`StateUpdatePacket`]
+    /// tries them in, so the first successful decode matches what a device would see.
+    pub fn decoders() -> Vec<InboundPacketDecoder> {
+        let state_update = StateUpdatePacket::command();
+        vec![
+            InboundPacketDecoder {
+                name: "A3027StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3027StateUpdatePacket::take),
+                        Self::A3027StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3028StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3028StateUpdatePacket::take),
+                        Self::A3028StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3031StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3031StateUpdatePacket::take),
+                        Self::A3031StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3033StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3033StateUpdatePacket::take),
+                        Self::A3033StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3926StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3926StateUpdatePacket::take),
+                        Self::A3926StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3930StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3930StateUpdatePacket::take),
+                        Self::A3930StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3931StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3931StateUpdatePacket::take),
+                        Self::A3931StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3951StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3951StateUpdatePacket::take),
+                        Self::A3951StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3933StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3933StateUpdatePacket::take),
+                        Self::A3933StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3936StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3936StateUpdatePacket::take),
+                        Self::A3936StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "A3945StateUpdate",
+                command: state_update,
+                take: |input| {
+                    map(
+                        all_consuming(A3945StateUpdatePacket::take),
+                        Self::A3945StateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "TwsStatusUpdate",
+                command: TwsStatusUpdatePacket::command(),
+                take: |input| {
+                    map(
+                        all_consuming(TwsStatusUpdatePacket::take),
+                        Self::TwsStatusUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "BatteryLevelUpdate",
+                command: BatteryLevelUpdatePacket::command(),
+                take: |input| {
+                    map(
+                        all_consuming(BatteryLevelUpdatePacket::take),
+                        Self::BatteryLevelUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "BatteryChargingUpdate",
+                command: BatteryChargingUpdatePacket::command(),
+                take: |input| {
+                    map(
+                        all_consuming(BatteryChargingUpdatePacket::take),
+                        Self::BatteryChargingUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "FirmwareVersionUpdate",
+                command: FirmwareVersionUpdatePacket::command(),
+                take: |input| {
+                    map(
+                        all_consuming(FirmwareVersionUpdatePacket::take),
+                        Self::FirmwareVersionUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "SoundModeUpdate",
+                command: SoundModeUpdatePacket::command(),
+                take: |input| {
+                    map(
+                        all_consuming(SoundModeUpdatePacket::take),
+                        Self::SoundModeUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "SoundModeTypeTwoUpdate",
+                command: SoundModeTypeTwoUpdatePacket::command(),
+                take: |input| {
+                    map(
+                        all_consuming(SoundModeTypeTwoUpdatePacket::take),
+                        Self::SoundModeTypeTwoUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "ChineseVoicePromptStateUpdate",
+                command: ChineseVoicePromptStateUpdatePacket::command(),
+                take: |input| {
+                    map(
+                        all_consuming(ChineseVoicePromptStateUpdatePacket::take),
+                        Self::ChineseVoicePromptStateUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "GameModeUpdate",
+                command: GameModeUpdatePacket::command(),
+                take: |input| {
+                    map(
+                        all_consuming(GameModeUpdatePacket::take),
+                        Self::GameModeUpdate,
+                    )(input)
+                },
+            },
+            InboundPacketDecoder {
+                name: "LdacStateUpdate",
+                command: LdacStateUpdatePacket::command(),
+                take: |input| {
+                    map(
+                        all_consuming(LdacStateUpdatePacket::take),
+                        Self::LdacStateUpdate,
+                    )(input)
+                },
+            },
+        ]
+    }
+
+    pub fn decode(command: Command, body: &[u8]) -> Result<Self, AnyInboundPacketError> {
+        let mut errors = Vec::new();
+        for decoder in Self::decoders()
+            .into_iter()
+            .filter(|decoder| decoder.command == command)
+        {
+            match (decoder.take)(body) {
+                Ok((_, packet)) => return Ok(packet),
+                Err(err) => errors.push(format!("{}: {err:?}", decoder.name)),
+            }
+        }
+        if errors.is_empty() {
+            Err(AnyInboundPacketError::UnknownCommand(command))
+        } else {
+            Err(AnyInboundPacketError::Malformed {
+                command,
+                message: errors.join(", "),
+            })
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    pub fn command(&self) -> Command {
+        match self {
+            Self::A3027StateUpdate(_)
+            | Self::A3028StateUpdate(_)
+            | Self::A3031StateUpdate(_)
+            | Self::A3033StateUpdate(_)
+            | Self::A3926StateUpdate(_)
+            | Self::A3930StateUpdate(_)
+            | Self::A3931StateUpdate(_)
+            | Self::A3933StateUpdate(_)
+            | Self::A3936StateUpdate(_)
+            | Self::A3945StateUpdate(_)
+            | Self::A3951StateUpdate(_) => StateUpdatePacket::command(),
+            Self::TwsStatusUpdate(_) => TwsStatusUpdatePacket::command(),
+            Self::BatteryLevelUpdate(_) => BatteryLevelUpdatePacket::command(),
+            Self::BatteryChargingUpdate(_) => BatteryChargingUpdatePacket::command(),
+            Self::FirmwareVersionUpdate(_) => FirmwareVersionUpdatePacket::command(),
+            Self::SoundModeUpdate(_) => SoundModeUpdatePacket::command(),
+            Self::SoundModeTypeTwoUpdate(_) => SoundModeTypeTwoUpdatePacket::command(),
+            Self::ChineseVoicePromptStateUpdate(_) => {
+                ChineseVoicePromptStateUpdatePacket::command()
+            }
+            Self::GameModeUpdate(_) => GameModeUpdatePacket::command(),
+            Self::LdacStateUpdate(_) => LdacStateUpdatePacket::command(),
+        }
+    }
+}
+
+impl TryFrom<&Packet> for AnyInboundPacket {
+    type Error = AnyInboundPacketError;
+
+    fn try_from(packet: &Packet) -> Result<Self, Self::Error> {
+        Self::decode(packet.command, &packet.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::devices::standard::{
+        packets::inbound::{take_inbound_packet_header, TwsStatusUpdatePacket},
+        structures::{Command, HostDevice},
+    };
+
+    use super::*;
+
+    #[test]
+    fn it_decodes_by_command() {
+        let input: &[u8] = &[
+            0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x02, 0x0c, 0x00, 0x00, 0x01, 0x19,
+        ];
+        let (body, command) = take_inbound_packet_header::<VerboseError<_>>(input).unwrap();
+        let packet = AnyInboundPacket::decode(command, body).unwrap();
+
+        assert_eq!(
+            AnyInboundPacket::TwsStatusUpdate(TwsStatusUpdatePacket {
+                host_device: HostDevice::Left,
+                tws_status: true,
+            }),
+            packet,
+        );
+        assert_eq!("TwsStatusUpdate", packet.name());
+        assert_eq!(command, packet.command());
+    }
+
+    #[test]
+    fn it_errors_on_unknown_commands() {
+        let command = Command::new([0x09, 0xff, 0x00, 0x00, 0x01, 0x7f, 0x7f]);
+        assert!(matches!(
+            AnyInboundPacket::decode(command, &[]),
+            Err(AnyInboundPacketError::UnknownCommand(_)),
+        ));
+    }
+
+    #[test]
+    fn it_errors_on_malformed_packets() {
+        assert!(matches!(
+            AnyInboundPacket::decode(TwsStatusUpdatePacket::command(), &[0x00]),
+            Err(AnyInboundPacketError::Malformed { .. }),
+        ));
+    }
+}
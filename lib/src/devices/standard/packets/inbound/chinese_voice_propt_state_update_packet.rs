@@ -18,7 +18,6 @@ impl InboundPacket for ChineseVoicePromptStateUpdatePacket {
         Command::new([0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x0F])
     }
 
-    #[allow(dead_code)]
     fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], ChineseVoicePromptStateUpdatePacket, E> {
@@ -23,6 +23,29 @@ where
     ) -> IResult<&'a [u8], Self, E>;
 }
 
+/// The inverse of [`InboundPacket::take`], for packets that need to be built the way a device
+/// would send them.
+pub trait InboundPacketBody: InboundPacket {
+    fn body(&self) -> Vec<u8>;
+}
+
+pub trait InboundPacketBytesExt {
+    fn bytes(&self) -> Vec<u8>;
+}
+
+impl<T> InboundPacketBytesExt for T
+where
+    T: InboundPacketBody,
+{
+    fn bytes(&self) -> Vec<u8> {
+        Packet {
+            command: T::command(),
+            body: self.body(),
+        }
+        .bytes()
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error("{message}")]
 pub struct TryIntoInboundPacketError {
@@ -20,9 +20,9 @@ use crate::{
         a3945::packets::A3945StateUpdatePacket,
         a3951::packets::A3951StateUpdatePacket,
         standard::structures::{
-            AgeRange, AmbientSoundModeCycle, Battery, Command, EqualizerConfiguration,
-            FirmwareVersion, Gender, HearId, MultiButtonConfiguration, SerialNumber, SoundModes,
-            SoundModesTypeTwo, TwsStatus,
+            AgeRange, AmbientSoundModeCycle, AutoPowerOff, Battery, BatteryLevel, Command,
+            DualFirmwareVersion, EqualizerConfiguration, Gender, HearId, MultiButtonConfiguration,
+            SerialNumber, SoundModes, SoundModesTypeTwo, TwsStatus,
         },
     },
 };
@@ -34,16 +34,23 @@ pub(crate) struct StateUpdatePacket {
     pub device_profile: &'static DeviceProfile,
     pub tws_status: Option<TwsStatus>,
     pub battery: Battery,
+    pub charging_case_battery_level: Option<BatteryLevel>,
     pub equalizer_configuration: EqualizerConfiguration,
+    pub right_equalizer_configuration: Option<EqualizerConfiguration>,
     pub sound_modes: Option<SoundModes>,
     pub sound_modes_type_two: Option<SoundModesTypeTwo>,
     pub age_range: Option<AgeRange>,
     pub gender: Option<Gender>,
     pub hear_id: Option<HearId>,
     pub button_configuration: Option<MultiButtonConfiguration>,
-    pub firmware_version: Option<FirmwareVersion>,
+    pub dual_firmware_version: Option<DualFirmwareVersion>,
     pub serial_number: Option<SerialNumber>,
     pub ambient_sound_mode_cycle: Option<AmbientSoundModeCycle>,
+    pub wear_detection: Option<bool>,
+    pub touch_tone: Option<bool>,
+    pub auto_power_off: Option<AutoPowerOff>,
+    pub game_mode: Option<bool>,
+    pub ldac: Option<bool>,
 }
 
 impl InboundPacket for StateUpdatePacket {
@@ -4,12 +4,18 @@ mod request_battery_level_packet;
 mod request_firmware_version_packet;
 mod request_state_packet;
 mod set_ambient_sound_mode_cycle_packet;
+mod set_auto_power_off_packet;
 mod set_equalizer;
 mod set_equalizer_and_custom_hear_id_packet;
 mod set_equalizer_with_drc;
+mod set_game_mode_packet;
+mod set_ldac_packet;
 mod set_multi_button_configuration_packet;
 mod set_sound_mode;
 mod set_sound_mode_type_two;
+mod set_touch_tone_packet;
+mod set_voice_prompt_packet;
+mod set_wear_detection_packet;
 
 pub use outbound_packet::*;
 pub use request_battery_charging_packet::*;
@@ -17,9 +23,15 @@ pub use request_battery_level_packet::*;
 pub use request_firmware_version_packet::*;
 pub use request_state_packet::*;
 pub use set_ambient_sound_mode_cycle_packet::*;
+pub use set_auto_power_off_packet::*;
 pub use set_equalizer::*;
 pub use set_equalizer_and_custom_hear_id_packet::*;
 pub use set_equalizer_with_drc::*;
+pub use set_game_mode_packet::*;
+pub use set_ldac_packet::*;
 pub use set_multi_button_configuration_packet::*;
 pub use set_sound_mode::*;
 pub use set_sound_mode_type_two::*;
+pub use set_touch_tone_packet::*;
+pub use set_voice_prompt_packet::*;
+pub use set_wear_detection_packet::*;
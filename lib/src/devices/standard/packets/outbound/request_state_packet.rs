@@ -6,6 +6,7 @@ use super::outbound_packet::OutboundPacket;
 pub struct RequestStatePacket {}
 
 impl RequestStatePacket {
+    pub const COMMAND: Command = Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x01, 0x01]);
     pub fn new() -> Self {
         RequestStatePacket {}
     }
@@ -13,7 +14,7 @@ impl RequestStatePacket {
 
 impl OutboundPacket for RequestStatePacket {
     fn command(&self) -> Command {
-        Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x01, 0x01])
+        Self::COMMAND
     }
 
     fn body(&self) -> Vec<u8> {
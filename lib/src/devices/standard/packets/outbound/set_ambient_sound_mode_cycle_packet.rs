@@ -8,9 +8,13 @@ pub struct SetAmbientSoundModeCyclePacket {
     pub cycle: AmbientSoundModeCycle,
 }
 
+impl SetAmbientSoundModeCyclePacket {
+    pub const COMMAND: Command = Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x06, 0x82]);
+}
+
 impl OutboundPacket for SetAmbientSoundModeCyclePacket {
     fn command(&self) -> Command {
-        Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x06, 0x82])
+        Self::COMMAND
     }
 
     fn body(&self) -> Vec<u8> {
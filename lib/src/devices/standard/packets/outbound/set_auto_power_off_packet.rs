@@ -0,0 +1,42 @@
+use crate::devices::standard::structures::Command;
+
+use super::outbound_packet::OutboundPacket;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SetAutoPowerOffPacket {
+    pub is_enabled: bool,
+    pub duration_index: u8,
+}
+
+impl SetAutoPowerOffPacket {
+    pub const COMMAND: Command = Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x01, 0x86]);
+}
+
+impl OutboundPacket for SetAutoPowerOffPacket {
+    fn command(&self) -> Command {
+        Self::COMMAND
+    }
+
+    fn body(&self) -> Vec<u8> {
+        vec![self.is_enabled.into(), self.duration_index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::devices::standard::packets::outbound::{
+        OutboundPacketBytesExt, SetAutoPowerOffPacket,
+    };
+
+    #[test]
+    fn it_matches_a_manually_crafted_packet() {
+        const EXPECTED: &[u8] = &[
+            0x08, 0xee, 0x00, 0x00, 0x00, 0x01, 0x86, 0x0c, 0x00, 0x01, 0x02, 0x8c,
+        ];
+        let packet = SetAutoPowerOffPacket {
+            is_enabled: true,
+            duration_index: 2,
+        };
+        assert_eq!(EXPECTED, packet.bytes());
+    }
+}
@@ -7,17 +7,27 @@ use super::outbound_packet::OutboundPacket;
 #[derive(Debug, Clone, PartialEq)]
 pub struct SetEqualizerAndCustomHearIdPacket<'a> {
     pub equalizer_configuration: &'a EqualizerConfiguration,
+    /// Falls back to `equalizer_configuration` if not set
+    pub right_equalizer_configuration: Option<&'a EqualizerConfiguration>,
     pub gender: Gender,
     pub age_range: AgeRange,
     pub custom_hear_id: &'a CustomHearId,
 }
 
+impl SetEqualizerAndCustomHearIdPacket<'_> {
+    pub const COMMAND: Command = Command::new([0x08, 0xee, 0x00, 0x00, 0x00, 0x03, 0x87]);
+    /// Used when the age range doesn't support hear id, in which case the hear id fields are
+    /// filled with placeholder values.
+    pub const COMMAND_WITHOUT_HEAR_ID: Command =
+        Command::new([0x08, 0xee, 0x00, 0x00, 0x00, 0x03, 0x86]);
+}
+
 impl OutboundPacket for SetEqualizerAndCustomHearIdPacket<'_> {
     fn command(&self) -> Command {
         if self.age_range.supports_hear_id() {
-            Command::new([0x08, 0xee, 0x00, 0x00, 0x00, 0x03, 0x87])
+            Self::COMMAND
         } else {
-            Command::new([0x08, 0xee, 0x00, 0x00, 0x00, 0x03, 0x86])
+            Self::COMMAND_WITHOUT_HEAR_ID
         }
     }
 
@@ -37,9 +47,11 @@ impl OutboundPacket for SetEqualizerAndCustomHearIdPacket<'_> {
         if supports_hear_id {
             bytes.extend(EQ_HEAR_INDEX_ID.to_le_bytes());
         }
-        let eq = self.equalizer_configuration.volume_adjustments();
-        bytes.extend(eq.bytes()); // left
-        bytes.extend(eq.bytes()); // right
+        let right_equalizer_configuration = self
+            .right_equalizer_configuration
+            .unwrap_or(self.equalizer_configuration);
+        bytes.extend(self.equalizer_configuration.volume_adjustments().bytes()); // left
+        bytes.extend(right_equalizer_configuration.volume_adjustments().bytes()); // right
         bytes.push(if supports_hear_id {
             self.gender.0
         } else {
@@ -108,6 +120,7 @@ mod tests {
             equalizer_configuration: &EqualizerConfiguration::new_custom_profile(
                 VolumeAdjustments::new([-5.2, -6.6, -6.4, -6.7, -10.8, -2.2, -4.9, -10.1]).unwrap(),
             ),
+            right_equalizer_configuration: None,
             gender: Gender(1),
             age_range: AgeRange(2),
             custom_hear_id: &CustomHearId {
@@ -156,6 +169,7 @@ mod tests {
             equalizer_configuration: &EqualizerConfiguration::new_from_preset_profile(
                 PresetEqualizerProfile::SoundcoreSignature,
             ),
+            right_equalizer_configuration: None,
             gender: Gender(1),
             age_range: AgeRange(255),
             custom_hear_id: &CustomHearId {
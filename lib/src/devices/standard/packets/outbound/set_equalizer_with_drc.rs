@@ -9,6 +9,7 @@ pub struct SetEqualizerWithDrcPacket<'a> {
 }
 
 impl<'a> SetEqualizerWithDrcPacket<'a> {
+    pub const COMMAND: Command = Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x02, 0x83]);
     pub fn new(
         left_configuration: &'a EqualizerConfiguration,
         right_configuration: Option<&'a EqualizerConfiguration>,
@@ -22,7 +23,7 @@ impl<'a> SetEqualizerWithDrcPacket<'a> {
 
 impl OutboundPacket for SetEqualizerWithDrcPacket<'_> {
     fn command(&self) -> Command {
-        Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x02, 0x83])
+        Self::COMMAND
     }
 
     fn body(&self) -> Vec<u8> {
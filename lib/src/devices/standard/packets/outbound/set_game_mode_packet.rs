@@ -0,0 +1,36 @@
+use crate::devices::standard::structures::Command;
+
+use super::outbound_packet::OutboundPacket;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SetGameModePacket {
+    pub is_enabled: bool,
+}
+
+impl SetGameModePacket {
+    pub const COMMAND: Command = Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x01, 0x91]);
+}
+
+impl OutboundPacket for SetGameModePacket {
+    fn command(&self) -> Command {
+        Self::COMMAND
+    }
+
+    fn body(&self) -> Vec<u8> {
+        vec![self.is_enabled.into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::devices::standard::packets::outbound::{OutboundPacketBytesExt, SetGameModePacket};
+
+    #[test]
+    fn it_matches_a_manually_crafted_packet() {
+        const EXPECTED: &[u8] = &[
+            0x08, 0xee, 0x00, 0x00, 0x00, 0x01, 0x91, 0x0b, 0x00, 0x01, 0x94,
+        ];
+        let packet = SetGameModePacket { is_enabled: true };
+        assert_eq!(EXPECTED, packet.bytes());
+    }
+}
@@ -8,6 +8,7 @@ pub struct SetMultiButtonConfigurationPacket {
 }
 
 impl SetMultiButtonConfigurationPacket {
+    pub const COMMAND: Command = Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x04, 0x84]);
     pub(crate) fn new(button_configuration: InternalMultiButtonConfiguration) -> Self {
         Self {
             button_configuration,
@@ -17,7 +18,7 @@ impl SetMultiButtonConfigurationPacket {
 
 impl OutboundPacket for SetMultiButtonConfigurationPacket {
     fn command(&self) -> Command {
-        Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x04, 0x84])
+        Self::COMMAND
     }
 
     fn body(&self) -> Vec<u8> {
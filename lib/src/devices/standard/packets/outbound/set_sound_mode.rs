@@ -12,9 +12,13 @@ pub struct SetSoundModePacket {
     pub custom_noise_canceling: CustomNoiseCanceling,
 }
 
+impl SetSoundModePacket {
+    pub const COMMAND: Command = Command::new([0x08, 0xee, 0x00, 0x00, 0x00, 0x06, 0x81]);
+}
+
 impl OutboundPacket for SetSoundModePacket {
     fn command(&self) -> Command {
-        Command::new([0x08, 0xee, 0x00, 0x00, 0x00, 0x06, 0x81])
+        Self::COMMAND
     }
 
     fn body(&self) -> Vec<u8> {
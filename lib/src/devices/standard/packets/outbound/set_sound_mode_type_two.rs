@@ -1,6 +1,6 @@
 use crate::devices::standard::structures::{Command, SoundModesTypeTwo};
 
-use super::outbound_packet::OutboundPacket;
+use super::{outbound_packet::OutboundPacket, SetSoundModePacket};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SetSoundModeTypeTwoPacket {
@@ -9,19 +9,11 @@ pub struct SetSoundModeTypeTwoPacket {
 
 impl OutboundPacket for SetSoundModeTypeTwoPacket {
     fn command(&self) -> Command {
-        Command::new([0x08, 0xee, 0x00, 0x00, 0x00, 0x06, 0x81])
+        SetSoundModePacket::COMMAND
     }
 
     fn body(&self) -> Vec<u8> {
-        vec![
-            self.sound_modes.ambient_sound_mode.id(),
-            (self.sound_modes.manual_noise_canceling.id() << 4)
-                | self.sound_modes.adaptive_noise_canceling.id(),
-            self.sound_modes.transparency_mode.id(),
-            self.sound_modes.noise_canceling_mode.id(), // ANC automation mode?
-            self.sound_modes.wind_noise_suppression.into(),
-            self.sound_modes.noise_canceling_adaptive_sensitivity_level,
-        ]
+        self.sound_modes.bytes().to_vec()
     }
 }
 
@@ -0,0 +1,36 @@
+use crate::devices::standard::structures::Command;
+
+use super::outbound_packet::OutboundPacket;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SetTouchTonePacket {
+    pub is_enabled: bool,
+}
+
+impl SetTouchTonePacket {
+    pub const COMMAND: Command = Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x01, 0x83]);
+}
+
+impl OutboundPacket for SetTouchTonePacket {
+    fn command(&self) -> Command {
+        Self::COMMAND
+    }
+
+    fn body(&self) -> Vec<u8> {
+        vec![self.is_enabled.into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::devices::standard::packets::outbound::{OutboundPacketBytesExt, SetTouchTonePacket};
+
+    #[test]
+    fn it_matches_a_manually_crafted_packet() {
+        const EXPECTED: &[u8] = &[
+            0x08, 0xee, 0x00, 0x00, 0x00, 0x01, 0x83, 0x0b, 0x00, 0x00, 0x85,
+        ];
+        let packet = SetTouchTonePacket { is_enabled: false };
+        assert_eq!(EXPECTED, packet.bytes());
+    }
+}
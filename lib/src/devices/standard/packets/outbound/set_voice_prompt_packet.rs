@@ -0,0 +1,41 @@
+use crate::devices::standard::structures::{Command, VoicePrompt};
+
+use super::outbound_packet::OutboundPacket;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SetVoicePromptPacket {
+    pub voice_prompt: VoicePrompt,
+}
+
+impl SetVoicePromptPacket {
+    pub const COMMAND: Command = Command::new([0x08, 0xEE, 0x00, 0x00, 0x00, 0x01, 0x8F]);
+}
+
+impl OutboundPacket for SetVoicePromptPacket {
+    fn command(&self) -> Command {
+        Self::COMMAND
+    }
+
+    fn body(&self) -> Vec<u8> {
+        vec![self.voice_prompt.is_chinese().into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::devices::standard::{
+        packets::outbound::{OutboundPacketBytesExt, SetVoicePromptPacket},
+        structures::VoicePrompt,
+    };
+
+    #[test]
+    fn it_matches_a_manually_crafted_packet() {
+        const EXPECTED: &[u8] = &[
+            0x08, 0xee, 0x00, 0x00, 0x00, 0x01, 0x8f, 0x0b, 0x00, 0x00, 0x91,
+        ];
+        let packet = SetVoicePromptPacket {
+            voice_prompt: VoicePrompt::English,
+        };
+        assert_eq!(EXPECTED, packet.bytes());
+    }
+}
@@ -5,19 +5,22 @@ use nom::{
     sequence::tuple,
     IResult,
 };
-use std::sync::atomic::{self, AtomicI32};
 
-use crate::devices::standard::{
-    packets::outbound::{OutboundPacket, SetEqualizerPacket},
-    structures::{
-        Command, EqualizerConfiguration, StereoEqualizerConfiguration, VolumeAdjustments,
+use crate::{
+    devices::standard::{
+        packets::outbound::{OutboundPacket, SetEqualizerPacket},
+        state::DeviceState,
+        structures::{
+            Command, EqualizerConfiguration, StereoEqualizerConfiguration, VolumeAdjustments,
+        },
     },
+    soundcore_device::device::soundcore_command::CommandResponse,
 };
 
+/// Both channels are expected to include bands 9 and 10.
 pub struct TwoExtraEqBandSetEqualizerPacket<'a> {
     pub left_channel: &'a EqualizerConfiguration,
     pub right_channel: &'a EqualizerConfiguration,
-    pub extra_band_values: TwoExtraEqBandsValues,
 }
 
 impl OutboundPacket for TwoExtraEqBandSetEqualizerPacket<'_> {
@@ -31,35 +34,61 @@ impl OutboundPacket for TwoExtraEqBandSetEqualizerPacket<'_> {
             .to_le_bytes()
             .into_iter()
             .chain(self.left_channel.volume_adjustments().bytes())
-            .chain(self.extra_band_values.left())
             .chain(self.right_channel.volume_adjustments().bytes())
-            .chain(self.extra_band_values.right())
             .collect::<Vec<_>>()
     }
 }
 
-#[derive(Debug, Default)]
-pub struct TwoExtraEqBands {
-    // The official app only displays 8 bands, so I have no idea what bands 9 and 10 do. We'll just keep track
-    // of their initial value and resend that.
-    extra_bands: AtomicI32,
-}
-
-impl TwoExtraEqBands {
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    pub fn set_values(&self, extra_bands: TwoExtraEqBandsValues) {
-        self.extra_bands
-            .store(extra_bands.into(), atomic::Ordering::Relaxed);
-    }
+/// Sets both channels, keeping the current values of bands 9 and 10 for any channel that only has the 8 bands
+/// covered by preset profiles. If the current values aren't known, only configurations that include all 10 bands
+/// can be set.
+pub(crate) fn set_equalizer_configuration_with_two_extra_bands(
+    state: DeviceState,
+    equalizer_configuration: StereoEqualizerConfiguration,
+) -> crate::Result<CommandResponse> {
+    let current_values = state
+        .stereo_equalizer_configuration()
+        .and_then(|configuration| configuration.two_extra_eq_bands_values());
+    let with_extra_bands = |channel: EqualizerConfiguration,
+                            extra_bands: Option<[u8; 2]>|
+     -> crate::Result<EqualizerConfiguration> {
+        if channel.volume_adjustments().adjustments().len() == NUM_BANDS {
+            return Ok(channel);
+        }
+        let Some(extra_bands) = extra_bands else {
+            return Err(crate::Error::MissingData {
+                name: "equalizer bands 9 and 10",
+            });
+        };
+        Ok(channel
+            .with_extra_bands(extra_bands.map(VolumeAdjustments::packet_byte_to_signed_adjustment))
+            .expect("8 bands plus 2 extra bands is a valid number of bands"))
+    };
+    let left = with_extra_bands(
+        equalizer_configuration.left,
+        current_values.map(|values| values.left()),
+    )?;
+    let right = with_extra_bands(
+        equalizer_configuration.right,
+        current_values.map(|values| values.right()),
+    )?;
 
-    pub fn values(&self) -> TwoExtraEqBandsValues {
-        self.extra_bands.load(atomic::Ordering::Relaxed).into()
-    }
+    let packet = TwoExtraEqBandSetEqualizerPacket {
+        left_channel: &left,
+        right_channel: &right,
+    };
+    Ok(CommandResponse {
+        packets: vec![packet.into()],
+        new_state: DeviceState {
+            equalizer_configuration: left,
+            right_equalizer_configuration: Some(right),
+            ..state
+        },
+    })
 }
 
+const NUM_BANDS: usize = 10;
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct TwoExtraEqBandsValues {
     pub left_extra_1: u8,
@@ -78,37 +107,57 @@ impl TwoExtraEqBandsValues {
     }
 }
 
-impl From<i32> for TwoExtraEqBandsValues {
-    fn from(value: i32) -> Self {
-        let bytes = value.to_ne_bytes();
+impl StereoEqualizerConfiguration {
+    /// Bands 9 and 10 of both channels, if both channels have them.
+    pub fn two_extra_eq_bands_values(&self) -> Option<TwoExtraEqBandsValues> {
+        let left = self.left.volume_adjustments();
+        let right = self.right.volume_adjustments();
+        if left.adjustments().len() != NUM_BANDS || right.adjustments().len() != NUM_BANDS {
+            return None;
+        }
+        let mut left_bytes = left.bytes().skip(8);
+        let mut right_bytes = right.bytes().skip(8);
+        Some(TwoExtraEqBandsValues {
+            left_extra_1: left_bytes.next()?,
+            left_extra_2: left_bytes.next()?,
+            right_extra_1: right_bytes.next()?,
+            right_extra_2: right_bytes.next()?,
+        })
+    }
+
+    pub fn with_two_extra_eq_bands_values(&self, values: TwoExtraEqBandsValues) -> Self {
+        let with_extra_bands = |channel: &EqualizerConfiguration, extra_bands: [u8; 2]| {
+            channel
+                .with_extra_bands(
+                    extra_bands.map(VolumeAdjustments::packet_byte_to_signed_adjustment),
+                )
+                .expect("8 bands plus 2 extra bands is a valid number of bands")
+        };
         Self {
-            left_extra_1: bytes[0],
-            left_extra_2: bytes[1],
-            right_extra_1: bytes[2],
-            right_extra_2: bytes[3],
+            left: with_extra_bands(&self.left, values.left()),
+            right: with_extra_bands(&self.right, values.right()),
         }
     }
-}
 
-impl From<TwoExtraEqBandsValues> for i32 {
-    fn from(value: TwoExtraEqBandsValues) -> Self {
-        i32::from_ne_bytes([
-            value.left_extra_1,
-            value.left_extra_2,
-            value.right_extra_1,
-            value.right_extra_2,
-        ])
+    /// Inverse of [`StereoEqualizerConfiguration::take_with_two_extra_bands`]. Both channels are
+    /// expected to include bands 9 and 10.
+    pub(crate) fn bytes_with_two_extra_bands(
+        left: &EqualizerConfiguration,
+        right: &EqualizerConfiguration,
+    ) -> Vec<u8> {
+        TwoExtraEqBandSetEqualizerPacket {
+            left_channel: left,
+            right_channel: right,
+        }
+        .body()
     }
-}
 
-impl StereoEqualizerConfiguration {
     pub(crate) fn take_with_two_extra_bands<
         'a,
         E: ParseError<&'a [u8]> + ContextError<&'a [u8]>,
     >(
         num_bands: usize,
-    ) -> impl Fn(&'a [u8]) -> IResult<&'a [u8], (StereoEqualizerConfiguration, TwoExtraEqBandsValues), E>
-    {
+    ) -> impl Fn(&'a [u8]) -> IResult<&'a [u8], StereoEqualizerConfiguration, E> {
         move |input| {
             context(
                 "stereo volume adjustments",
@@ -129,11 +178,11 @@ impl StereoEqualizerConfiguration {
                         right_extra_1,
                         right_extra_2,
                     )| {
-                        (
-                            StereoEqualizerConfiguration::new(
-                                left_equalizer_configuration,
-                                right_volume_adjustments,
-                            ),
+                        StereoEqualizerConfiguration::new(
+                            left_equalizer_configuration,
+                            right_volume_adjustments,
+                        )
+                        .with_two_extra_eq_bands_values(
                             TwoExtraEqBandsValues {
                                 left_extra_1,
                                 left_extra_2,
@@ -147,3 +196,106 @@ impl StereoEqualizerConfiguration {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        devices::standard::{
+            state::DeviceState,
+            structures::{
+                EqualizerConfiguration, PresetEqualizerProfile, StereoEqualizerConfiguration,
+                VolumeAdjustments,
+            },
+        },
+        soundcore_device::device::Packet,
+    };
+
+    use super::{
+        set_equalizer_configuration_with_two_extra_bands, TwoExtraEqBandSetEqualizerPacket,
+    };
+
+    fn ten_band_configuration(extra_bands: [f64; 2]) -> EqualizerConfiguration {
+        EqualizerConfiguration::new_custom_profile(
+            VolumeAdjustments::new([0.0; 8].into_iter().chain(extra_bands)).unwrap(),
+        )
+    }
+
+    #[test]
+    fn it_sends_extra_bands_that_were_set() {
+        let configuration = StereoEqualizerConfiguration {
+            left: ten_band_configuration([1.0, 2.0]),
+            right: ten_band_configuration([3.0, 4.0]),
+        };
+        let response = set_equalizer_configuration_with_two_extra_bands(
+            DeviceState::default(),
+            configuration.to_owned(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![Packet::from(TwoExtraEqBandSetEqualizerPacket {
+                left_channel: &configuration.left,
+                right_channel: &configuration.right,
+            })],
+            response.packets,
+        );
+        assert_eq!(
+            Some(configuration),
+            response.new_state.stereo_equalizer_configuration(),
+        );
+    }
+
+    #[test]
+    fn it_errors_when_setting_a_preset_without_current_extra_bands() {
+        let state = DeviceState {
+            equalizer_configuration: EqualizerConfiguration::new_from_preset_profile(
+                PresetEqualizerProfile::SoundcoreSignature,
+            ),
+            ..Default::default()
+        };
+        let preset =
+            EqualizerConfiguration::new_from_preset_profile(PresetEqualizerProfile::Acoustic);
+        let result = set_equalizer_configuration_with_two_extra_bands(
+            state,
+            StereoEqualizerConfiguration {
+                left: preset.to_owned(),
+                right: preset,
+            },
+        );
+
+        assert!(matches!(
+            result,
+            Err(crate::Error::MissingData {
+                name: "equalizer bands 9 and 10"
+            })
+        ));
+    }
+
+    #[test]
+    fn it_keeps_current_extra_bands_when_setting_a_preset() {
+        let state = DeviceState {
+            equalizer_configuration: ten_band_configuration([1.0, 2.0]),
+            right_equalizer_configuration: Some(ten_band_configuration([3.0, 4.0])),
+            ..Default::default()
+        };
+        let preset =
+            EqualizerConfiguration::new_from_preset_profile(PresetEqualizerProfile::Acoustic);
+        let response = set_equalizer_configuration_with_two_extra_bands(
+            state,
+            StereoEqualizerConfiguration {
+                left: preset.to_owned(),
+                right: preset.to_owned(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            preset.with_extra_bands([1.0, 2.0]).unwrap(),
+            response.new_state.equalizer_configuration,
+        );
+        assert_eq!(
+            Some(preset.with_extra_bands([3.0, 4.0]).unwrap()),
+            response.new_state.right_equalizer_configuration,
+        );
+    }
+}
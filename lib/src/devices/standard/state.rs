@@ -6,14 +6,19 @@ use crate::{
     devices::standard::{
         packets::inbound::state_update_packet::StateUpdatePacket,
         structures::{
-            AgeRange, Battery, EqualizerConfiguration, FirmwareVersion, Gender, HearId,
-            SerialNumber, SoundModes,
+            AgeRange, Battery, BatteryLevel, DualFirmwareVersion, EqualizerConfiguration,
+            FirmwareVersion, Gender, HearId, SerialNumber, SoundModes,
+            StereoEqualizerConfiguration,
         },
     },
 };
 
-use super::structures::{
-    AmbientSoundModeCycle, MultiButtonConfiguration, SoundModesTypeTwo, TwsStatus,
+use super::{
+    device_event::DeviceEvent,
+    structures::{
+        AmbientSoundModeCycle, AutoPowerOff, MultiButtonConfiguration, SoundModesTypeTwo,
+        TwsStatus, VoicePrompt,
+    },
 };
 
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -23,16 +28,24 @@ pub struct DeviceState {
     pub device_features: DeviceFeatures,
     pub tws_status: Option<TwsStatus>,
     pub battery: Battery,
+    pub charging_case_battery_level: Option<BatteryLevel>,
     pub equalizer_configuration: EqualizerConfiguration,
+    pub right_equalizer_configuration: Option<EqualizerConfiguration>,
     pub sound_modes: Option<SoundModes>,
     pub sound_modes_type_two: Option<SoundModesTypeTwo>,
     pub age_range: Option<AgeRange>,
     pub gender: Option<Gender>,
     pub hear_id: Option<HearId>,
     pub button_configuration: Option<MultiButtonConfiguration>,
-    pub firmware_version: Option<FirmwareVersion>,
+    pub dual_firmware_version: Option<DualFirmwareVersion>,
     pub serial_number: Option<SerialNumber>,
     pub ambient_sound_mode_cycle: Option<AmbientSoundModeCycle>,
+    pub wear_detection: Option<bool>,
+    pub touch_tone: Option<bool>,
+    pub auto_power_off: Option<AutoPowerOff>,
+    pub game_mode: Option<bool>,
+    pub ldac: Option<bool>,
+    pub voice_prompt: Option<VoicePrompt>,
 }
 
 impl From<StateUpdatePacket> for DeviceState {
@@ -41,26 +54,120 @@ impl From<StateUpdatePacket> for DeviceState {
             device_features: packet.device_profile.features,
             tws_status: packet.tws_status,
             battery: packet.battery,
+            charging_case_battery_level: packet.charging_case_battery_level,
             equalizer_configuration: packet.equalizer_configuration,
+            right_equalizer_configuration: packet.right_equalizer_configuration,
             sound_modes: packet.sound_modes,
             sound_modes_type_two: packet.sound_modes_type_two,
             age_range: packet.age_range,
             gender: packet.gender,
             hear_id: packet.hear_id,
             button_configuration: packet.button_configuration,
-            firmware_version: packet.firmware_version,
+            dual_firmware_version: packet.dual_firmware_version,
             serial_number: packet.serial_number.clone(),
             ambient_sound_mode_cycle: packet.ambient_sound_mode_cycle,
+            wear_detection: packet.wear_detection,
+            touch_tone: packet.touch_tone,
+            auto_power_off: packet.auto_power_off,
+            game_mode: packet.game_mode,
+            ldac: packet.ldac,
+            // Only reported by a separate update packet
+            voice_prompt: None,
         }
     }
 }
 
 impl DeviceState {
+    pub fn stereo_equalizer_configuration(&self) -> Option<StereoEqualizerConfiguration> {
+        self.right_equalizer_configuration
+            .as_ref()
+            .map(|right| StereoEqualizerConfiguration {
+                left: self.equalizer_configuration.to_owned(),
+                right: right.to_owned(),
+            })
+    }
+
+    /// Some models' state update packets don't include the serial number or firmware version, since
+    /// those are requested separately, and none include the voice prompt, which has its own update
+    /// packet. Any that are missing are kept from `previous_state` rather than being cleared by
+    /// every state update.
+    pub(crate) fn with_identity_from(self, previous_state: DeviceState) -> Self {
+        Self {
+            serial_number: self.serial_number.or(previous_state.serial_number),
+            dual_firmware_version: self
+                .dual_firmware_version
+                .or(previous_state.dual_firmware_version),
+            voice_prompt: self.voice_prompt.or(previous_state.voice_prompt),
+            ..self
+        }
+    }
+
+    /// The newer of the two firmware versions, which is what feature support has always been checked
+    /// against. Models with a single firmware version report it for both sides.
+    pub fn firmware_version(&self) -> Option<FirmwareVersion> {
+        self.dual_firmware_version.map(|version| version.max())
+    }
+
+    /// Lists what changed going from `self` to `new_state`, in the order the fields are declared.
+    /// Fields that don't have a corresponding [`DeviceEvent`] are not compared.
+    pub fn diff(&self, new_state: &DeviceState) -> Vec<DeviceEvent> {
+        let mut events = Vec::new();
+        push_if_changed(
+            &mut events,
+            &self.tws_status,
+            &new_state.tws_status,
+            |old, new| DeviceEvent::TwsStatusChanged { old, new },
+        );
+        push_if_changed(
+            &mut events,
+            &self.battery,
+            &new_state.battery,
+            |old, new| DeviceEvent::BatteryChanged { old, new },
+        );
+        push_if_changed(
+            &mut events,
+            &self.charging_case_battery_level,
+            &new_state.charging_case_battery_level,
+            |old, new| DeviceEvent::ChargingCaseBatteryLevelChanged { old, new },
+        );
+        push_if_changed(
+            &mut events,
+            &self.equalizer_configuration,
+            &new_state.equalizer_configuration,
+            |old, new| DeviceEvent::EqualizerConfigurationChanged { old, new },
+        );
+        push_if_changed(
+            &mut events,
+            &self.right_equalizer_configuration,
+            &new_state.right_equalizer_configuration,
+            |old, new| DeviceEvent::RightEqualizerConfigurationChanged { old, new },
+        );
+        push_if_changed(
+            &mut events,
+            &self.sound_modes,
+            &new_state.sound_modes,
+            |old, new| DeviceEvent::SoundModesChanged { old, new },
+        );
+        push_if_changed(
+            &mut events,
+            &self.sound_modes_type_two,
+            &new_state.sound_modes_type_two,
+            |old, new| DeviceEvent::SoundModesTypeTwoChanged { old, new },
+        );
+        push_if_changed(
+            &mut events,
+            &self.button_configuration,
+            &new_state.button_configuration,
+            |old, new| DeviceEvent::ButtonConfigurationChanged { old, new },
+        );
+        events
+    }
+
     // need drc:                     A3951, A3930, A3931, A3931XR, A3935, A3935W,
     // separate left/right firmware: A3951, A3930, A3931, A3931XR, A3935, A3935W,
     pub fn supports_dynamic_range_compression(&self) -> bool {
         if self.device_features.has_dynamic_range_compression {
-            self.does_firmware_version_support_drc(self.firmware_version.unwrap_or_default())
+            self.does_firmware_version_support_drc(self.firmware_version().unwrap_or_default())
         } else {
             false
         }
@@ -74,3 +181,14 @@ impl DeviceState {
                 .unwrap_or_default()
     }
 }
+
+fn push_if_changed<T: PartialEq + Clone>(
+    events: &mut Vec<DeviceEvent>,
+    old: &T,
+    new: &T,
+    event: impl FnOnce(T, T) -> DeviceEvent,
+) {
+    if old != new {
+        events.push(event(old.to_owned(), new.to_owned()));
+    }
+}
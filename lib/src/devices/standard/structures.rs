@@ -1,12 +1,14 @@
 mod age_range;
 mod ambient_sound_mode;
 mod ambient_sound_mode_cycle;
+mod auto_power_off;
 mod basic_hear_id;
 mod battery;
 mod button_configuration;
 mod command;
 mod custom_hear_id;
 mod custom_noise_canceling;
+mod dual_firmware_version;
 mod equalizer_configuration;
 mod firmware_version;
 mod gender;
@@ -20,19 +22,24 @@ mod sound_modes;
 mod sound_modes_type_two;
 mod stereo_equalizer_configuration;
 mod stereo_volume_adjustments;
+#[cfg(test)]
+pub(crate) mod strategies;
 mod transparency_mode;
 mod tws_status;
+mod voice_prompt;
 mod volume_adjustments;
 
 pub use age_range::*;
 pub use ambient_sound_mode::*;
 pub use ambient_sound_mode_cycle::*;
+pub use auto_power_off::*;
 pub use basic_hear_id::*;
 pub use battery::*;
 pub use button_configuration::*;
 pub use command::*;
 pub use custom_hear_id::*;
 pub use custom_noise_canceling::*;
+pub use dual_firmware_version::*;
 pub use equalizer_configuration::*;
 pub use firmware_version::*;
 pub use gender::*;
@@ -48,4 +55,5 @@ pub use stereo_equalizer_configuration::*;
 pub use stereo_volume_adjustments::*;
 pub use transparency_mode::*;
 pub use tws_status::*;
+pub use voice_prompt::*;
 pub use volume_adjustments::*;
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, IntoStaticStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct AutoPowerOff {
+    pub is_enabled: bool,
+    pub duration: AutoPowerOffDuration,
+}
+
+impl AutoPowerOff {
+    /// Devices send the selected duration as an index into [`DURATIONS`].
+    pub(crate) fn from_index(is_enabled: bool, index: u8) -> Option<Self> {
+        DURATIONS.get(usize::from(index)).map(|&duration| Self {
+            is_enabled,
+            duration,
+        })
+    }
+
+    pub(crate) fn index(&self) -> u8 {
+        DURATIONS
+            .iter()
+            .position(|duration| *duration == self.duration)
+            .expect("every duration is in the list")
+            .try_into()
+            .expect("the list is shorter than u8::MAX")
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Default,
+    Display,
+    AsRefStr,
+    IntoStaticStr,
+    EnumIter,
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum AutoPowerOffDuration {
+    #[default]
+    ThirtyMinutes,
+    SixtyMinutes,
+    NinetyMinutes,
+    OneHundredTwentyMinutes,
+}
+
+impl AutoPowerOffDuration {
+    pub fn duration(&self) -> Duration {
+        let minutes = match self {
+            AutoPowerOffDuration::ThirtyMinutes => 30,
+            AutoPowerOffDuration::SixtyMinutes => 60,
+            AutoPowerOffDuration::NinetyMinutes => 90,
+            AutoPowerOffDuration::OneHundredTwentyMinutes => 120,
+        };
+        Duration::from_secs(minutes * 60)
+    }
+}
+
+/// Every model that supports auto power off sends the selected duration as an index into this list.
+const DURATIONS: &[AutoPowerOffDuration] = &[
+    AutoPowerOffDuration::ThirtyMinutes,
+    AutoPowerOffDuration::SixtyMinutes,
+    AutoPowerOffDuration::NinetyMinutes,
+    AutoPowerOffDuration::OneHundredTwentyMinutes,
+];
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn it_maps_index_to_duration() {
+        assert_eq!(
+            Some(AutoPowerOff {
+                is_enabled: true,
+                duration: AutoPowerOffDuration::SixtyMinutes,
+            }),
+            AutoPowerOff::from_index(true, 1),
+        );
+        assert_eq!(None, AutoPowerOff::from_index(true, 4));
+    }
+
+    #[test]
+    fn it_maps_every_duration_to_its_index() {
+        for duration in AutoPowerOffDuration::iter() {
+            let auto_power_off = AutoPowerOff {
+                is_enabled: false,
+                duration,
+            };
+            assert_eq!(
+                Some(auto_power_off),
+                AutoPowerOff::from_index(false, auto_power_off.index()),
+            );
+        }
+    }
+}
@@ -22,6 +22,14 @@ pub struct BasicHearId {
 }
 
 impl BasicHearId {
+    pub fn bytes(&self) -> Vec<u8> {
+        [u8::from(self.is_enabled)]
+            .into_iter()
+            .chain(self.volume_adjustments.bytes())
+            .chain(self.time.to_le_bytes())
+            .collect()
+    }
+
     pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], BasicHearId, E> {
@@ -42,6 +42,15 @@ pub struct DualBattery {
 }
 
 impl DualBattery {
+    pub fn bytes(&self) -> [u8; 4] {
+        [
+            self.left.level.0,
+            self.right.level.0,
+            bool::from(self.left.is_charging).into(),
+            bool::from(self.right.is_charging).into(),
+        ]
+    }
+
     pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], DualBattery, E> {
@@ -78,6 +87,10 @@ pub struct SingleBattery {
 }
 
 impl SingleBattery {
+    pub fn bytes(&self) -> [u8; 2] {
+        [self.level.0, bool::from(self.is_charging).into()]
+    }
+
     pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], SingleBattery, E> {
@@ -34,7 +34,7 @@ pub struct ButtonConfiguration {
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-pub(crate) struct InternalMultiButtonConfiguration {
+pub struct InternalMultiButtonConfiguration {
     pub left_single_click: NoTwsButtonConfiguration,
     pub left_double_click: TwsButtonConfiguration,
     pub left_long_press: TwsButtonConfiguration,
@@ -106,7 +106,7 @@ impl InternalMultiButtonConfiguration {
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-pub(crate) struct TwsButtonConfiguration {
+pub struct TwsButtonConfiguration {
     pub tws_connected_action: ButtonAction,
     pub tws_disconnected_action: ButtonAction,
     pub disconnected_switch: bool,
@@ -163,7 +163,7 @@ impl TwsButtonConfiguration {
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-pub(crate) struct NoTwsButtonConfiguration {
+pub struct NoTwsButtonConfiguration {
     pub action: ButtonAction,
     pub is_enabled: bool,
 }
@@ -26,6 +26,39 @@ pub struct CustomHearId {
 }
 
 impl CustomHearId {
+    /// Inverse of [`CustomHearId::take_with_all_fields`]. Missing custom volume adjustments are
+    /// written as all 255, which is how the device represents them.
+    pub fn bytes_with_all_fields(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(39);
+        bytes.push(self.is_enabled.into());
+        bytes.extend(self.volume_adjustments.bytes());
+        bytes.extend(self.time.to_le_bytes());
+        bytes.push(self.hear_id_type.0);
+        bytes.push(self.hear_id_music_type.0);
+        match &self.custom_volume_adjustments {
+            Some(custom_volume_adjustments) => bytes.extend(custom_volume_adjustments.bytes()),
+            None => bytes.extend([255; 16]),
+        }
+        bytes
+    }
+
+    /// Inverse of [`CustomHearId::take_without_music_type`], using the number of bands in
+    /// `volume_adjustments`.
+    pub fn bytes_without_music_type(&self) -> Vec<u8> {
+        let num_bands = self.volume_adjustments.left.adjustments().len();
+        let mut bytes = Vec::with_capacity(num_bands * 4 + 8);
+        bytes.push(self.is_enabled.into());
+        bytes.extend(self.volume_adjustments.bytes());
+        bytes.extend(self.time.to_le_bytes());
+        bytes.push(self.hear_id_type.0);
+        match &self.custom_volume_adjustments {
+            Some(custom_volume_adjustments) => bytes.extend(custom_volume_adjustments.bytes()),
+            None => bytes.extend(std::iter::repeat_n(255, num_bands * 2)),
+        }
+        bytes.extend([0, 0]);
+        bytes
+    }
+
     pub(crate) fn take_with_all_fields<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], CustomHearId, E> {
@@ -0,0 +1,54 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::FirmwareVersion;
+
+/// Firmware versions of the left and right earbuds, which are not guaranteed to match.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DualFirmwareVersion {
+    pub left: FirmwareVersion,
+    pub right: FirmwareVersion,
+}
+
+impl DualFirmwareVersion {
+    pub const fn new(left: FirmwareVersion, right: FirmwareVersion) -> Self {
+        Self { left, right }
+    }
+
+    /// The older of the two versions, for checking whether both earbuds support a feature.
+    pub fn min(&self) -> FirmwareVersion {
+        self.left.min(self.right)
+    }
+
+    /// The newer of the two versions.
+    pub fn max(&self) -> FirmwareVersion {
+        self.left.max(self.right)
+    }
+}
+
+/// Devices with a single firmware version, such as headphones, have the same version on both sides.
+impl From<FirmwareVersion> for DualFirmwareVersion {
+    fn from(version: FirmwareVersion) -> Self {
+        Self::new(version, version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_is_the_older_version() {
+        let version =
+            DualFirmwareVersion::new(FirmwareVersion::new(2, 61), FirmwareVersion::new(2, 5));
+        assert_eq!(FirmwareVersion::new(2, 5), version.min());
+    }
+
+    #[test]
+    fn max_is_the_newer_version() {
+        let version =
+            DualFirmwareVersion::new(FirmwareVersion::new(2, 61), FirmwareVersion::new(2, 5));
+        assert_eq!(FirmwareVersion::new(2, 61), version.max());
+    }
+}
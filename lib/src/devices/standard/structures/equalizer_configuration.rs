@@ -8,7 +8,9 @@ use nom::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::{preset_equalizer_profile::PresetEqualizerProfile, VolumeAdjustments};
+use super::{
+    preset_equalizer_profile::PresetEqualizerProfile, VolumeAdjustments, VolumeAdjustmentsError,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -26,6 +28,7 @@ impl Default for EqualizerConfiguration {
 
 impl EqualizerConfiguration {
     pub const CUSTOM_PROFILE_ID: u16 = 0xfefe;
+    const PRESET_PROFILE_BANDS: usize = 8;
 
     pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         num_bands: usize,
@@ -47,6 +50,13 @@ impl EqualizerConfiguration {
         }
     }
 
+    pub fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.profile_id()
+            .to_le_bytes()
+            .into_iter()
+            .chain(self.volume_adjustments.bytes())
+    }
+
     pub fn new_from_preset_profile(preset_profile: PresetEqualizerProfile) -> Self {
         Self {
             preset_profile: Some(preset_profile),
@@ -74,4 +84,47 @@ impl EqualizerConfiguration {
     pub fn volume_adjustments(&self) -> &VolumeAdjustments {
         &self.volume_adjustments
     }
+
+    /// Devices with more than 8 bands also accept only the first 8, since that's all preset profiles cover. The
+    /// rest are left as they are.
+    pub(crate) fn is_valid_for_number_of_bands(&self, num_bands: usize) -> bool {
+        let len = self.volume_adjustments.adjustments().len();
+        len == num_bands || len == Self::PRESET_PROFILE_BANDS
+    }
+
+    /// If `self` only has the 8 bands covered by preset profiles, fills in the rest from `current`, which is what
+    /// devices with more bands end up with when given `self`. Otherwise, `self` is returned as is.
+    pub(crate) fn with_extra_bands_from(&self, current: &EqualizerConfiguration) -> Self {
+        if self.volume_adjustments.adjustments().len() != Self::PRESET_PROFILE_BANDS {
+            return self.to_owned();
+        }
+        self.with_extra_bands(
+            current
+                .volume_adjustments
+                .adjustments()
+                .iter()
+                .skip(Self::PRESET_PROFILE_BANDS)
+                .cloned(),
+        )
+        .expect("current configuration has a valid number of bands")
+    }
+
+    /// Preset profiles only cover the first 8 bands, so devices with more bands than that keep their own values
+    /// for the rest. This replaces everything past the 8th band with `extra_bands`.
+    pub fn with_extra_bands(
+        &self,
+        extra_bands: impl IntoIterator<Item = f64>,
+    ) -> Result<Self, VolumeAdjustmentsError> {
+        Ok(Self {
+            preset_profile: self.preset_profile,
+            volume_adjustments: VolumeAdjustments::new(
+                self.volume_adjustments
+                    .adjustments()
+                    .iter()
+                    .take(Self::PRESET_PROFILE_BANDS)
+                    .cloned()
+                    .chain(extra_bands),
+            )?,
+        })
+    }
 }
@@ -34,6 +34,12 @@ impl FirmwareVersion {
         (self.major as u16) * 100 + (self.minor as u16)
     }
 
+    /// Packets only have room for two digits on each side of the dot, so versions past 99.99
+    /// can't be represented.
+    pub fn bytes(&self) -> Vec<u8> {
+        self.to_string().into_bytes()
+    }
+
     pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], FirmwareVersion, E> {
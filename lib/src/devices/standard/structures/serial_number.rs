@@ -19,6 +19,10 @@ impl SerialNumber {
         &self.0
     }
 
+    pub fn bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
     pub fn model_number(&self) -> &str {
         &self.0[0..4]
     }
@@ -20,6 +20,15 @@ pub struct SoundModes {
 }
 
 impl SoundModes {
+    pub fn bytes(&self) -> [u8; 4] {
+        [
+            self.ambient_sound_mode.id(),
+            self.noise_canceling_mode.id(),
+            self.transparency_mode.id(),
+            self.custom_noise_canceling.value(),
+        ]
+    }
+
     pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], SoundModes, E> {
@@ -25,6 +25,17 @@ pub struct SoundModesTypeTwo {
 }
 
 impl SoundModesTypeTwo {
+    pub fn bytes(&self) -> [u8; 6] {
+        [
+            self.ambient_sound_mode.id(),
+            (self.manual_noise_canceling.id() << 4) | self.adaptive_noise_canceling.id(),
+            self.transparency_mode.id(),
+            self.noise_canceling_mode.id(),
+            self.wind_noise_suppression.into(),
+            self.noise_canceling_adaptive_sensitivity_level,
+        ]
+    }
+
     pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], SoundModesTypeTwo, E> {
@@ -1,4 +1,7 @@
 use super::{EqualizerConfiguration, VolumeAdjustments};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use nom::{
     combinator::map,
     error::{context, ContextError, ParseError},
@@ -6,7 +9,8 @@ use nom::{
     IResult,
 };
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StereoEqualizerConfiguration {
     pub left: EqualizerConfiguration,
     pub right: EqualizerConfiguration,
@@ -27,9 +31,15 @@ impl StereoEqualizerConfiguration {
         }
     }
 
+    pub fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.left
+            .bytes()
+            .chain(self.right.volume_adjustments().bytes())
+    }
+
     pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         num_bands: usize,
-    ) -> impl Fn(&'a [u8]) -> IResult<&'a [u8], EqualizerConfiguration, E> {
+    ) -> impl Fn(&'a [u8]) -> IResult<&'a [u8], StereoEqualizerConfiguration, E> {
         move |input| {
             context(
                 "stereo equalizer configuration",
@@ -38,8 +48,7 @@ impl StereoEqualizerConfiguration {
                         EqualizerConfiguration::take(num_bands),
                         VolumeAdjustments::take(num_bands),
                     )),
-                    // discard the right channel since we don't support it yet
-                    |results| results.0,
+                    |(left, right)| StereoEqualizerConfiguration::new(left, right),
                 ),
             )(input)
         }
@@ -0,0 +1,319 @@
+//! [proptest] strategies for the structures that devices send. Values are only generated in the
+//! form that parsing would produce them, so they can be expected to be unchanged after being
+//! encoded and parsed again.
+
+use proptest::{collection::vec, option, prelude::*, sample::select};
+use strum::IntoEnumIterator;
+
+use crate::devices::standard::quirks::TwoExtraEqBandsValues;
+
+use super::{
+    AdaptiveNoiseCanceling, AgeRange, AmbientSoundMode, AmbientSoundModeCycle, BasicHearId,
+    BatteryLevel, ButtonAction, CustomHearId, CustomNoiseCanceling, DualBattery,
+    EqualizerConfiguration, FirmwareVersion, Gender, HearIdMusicType, HearIdType, HostDevice,
+    InternalMultiButtonConfiguration, ManualNoiseCanceling, NoTwsButtonConfiguration,
+    NoiseCancelingMode, NoiseCancelingModeTypeTwo, PresetEqualizerProfile, SerialNumber,
+    SingleBattery, SoundModes, SoundModesTypeTwo, StereoEqualizerConfiguration,
+    StereoVolumeAdjustments, TransparencyMode, TwsButtonConfiguration, TwsStatus,
+    VolumeAdjustments,
+};
+
+pub(crate) fn tws_status() -> impl Strategy<Value = TwsStatus> {
+    (
+        select(vec![HostDevice::Left, HostDevice::Right]),
+        any::<bool>(),
+    )
+        .prop_map(|(host_device, is_connected)| TwsStatus {
+            is_connected,
+            host_device,
+        })
+}
+
+pub(crate) fn battery_level() -> impl Strategy<Value = BatteryLevel> {
+    any::<u8>().prop_map(BatteryLevel)
+}
+
+pub(crate) fn single_battery() -> impl Strategy<Value = SingleBattery> {
+    (any::<bool>(), battery_level()).prop_map(|(is_charging, level)| SingleBattery {
+        is_charging: is_charging.into(),
+        level,
+    })
+}
+
+pub(crate) fn dual_battery() -> impl Strategy<Value = DualBattery> {
+    (single_battery(), single_battery()).prop_map(|(left, right)| DualBattery { left, right })
+}
+
+pub(crate) fn firmware_version() -> impl Strategy<Value = FirmwareVersion> {
+    (0..100u8, 0..100u8).prop_map(|(major, minor)| FirmwareVersion::new(major, minor))
+}
+
+pub(crate) fn serial_number() -> impl Strategy<Value = SerialNumber> {
+    "[0-9A-F]{16}".prop_map(|serial_number| SerialNumber::from(serial_number.as_str()))
+}
+
+pub(crate) fn gender() -> impl Strategy<Value = Gender> {
+    any::<u8>().prop_map(Gender)
+}
+
+pub(crate) fn age_range() -> impl Strategy<Value = AgeRange> {
+    any::<u8>().prop_map(AgeRange)
+}
+
+pub(crate) fn volume_adjustments(num_bands: usize) -> impl Strategy<Value = VolumeAdjustments> {
+    vec(any::<u8>(), num_bands).prop_map(|bytes| {
+        VolumeAdjustments::from_bytes(&bytes).expect("number of bands should be valid")
+    })
+}
+
+pub(crate) fn stereo_volume_adjustments(
+    num_bands: usize,
+) -> impl Strategy<Value = StereoVolumeAdjustments> {
+    (volume_adjustments(num_bands), volume_adjustments(num_bands))
+        .prop_map(|(left, right)| StereoVolumeAdjustments { left, right })
+}
+
+/// Presets ignore the volume adjustments that are sent along with them, so only the preset's own
+/// 8 bands can be generated for them.
+pub(crate) fn equalizer_configuration() -> impl Strategy<Value = EqualizerConfiguration> {
+    prop_oneof![
+        select(PresetEqualizerProfile::iter().collect::<Vec<_>>())
+            .prop_map(EqualizerConfiguration::new_from_preset_profile),
+        volume_adjustments(8).prop_map(EqualizerConfiguration::new_custom_profile),
+    ]
+}
+
+pub(crate) fn stereo_equalizer_configuration() -> impl Strategy<Value = StereoEqualizerConfiguration>
+{
+    (equalizer_configuration(), volume_adjustments(8))
+        .prop_map(|(left, right)| StereoEqualizerConfiguration::new(left, right))
+}
+
+/// Both channels have the extra bands added, like
+/// [`StereoEqualizerConfiguration::take_with_two_extra_bands`] does.
+pub(crate) fn stereo_equalizer_configuration_with_two_extra_bands(
+) -> impl Strategy<Value = StereoEqualizerConfiguration> {
+    (stereo_equalizer_configuration(), any::<[u8; 4]>()).prop_map(
+        |(equalizer_configuration, [left_extra_1, left_extra_2, right_extra_1, right_extra_2])| {
+            equalizer_configuration.with_two_extra_eq_bands_values(TwoExtraEqBandsValues {
+                left_extra_1,
+                left_extra_2,
+                right_extra_1,
+                right_extra_2,
+            })
+        },
+    )
+}
+
+pub(crate) fn sound_modes() -> impl Strategy<Value = SoundModes> {
+    (
+        ambient_sound_mode(),
+        select(vec![
+            NoiseCancelingMode::Transport,
+            NoiseCancelingMode::Outdoor,
+            NoiseCancelingMode::Indoor,
+            NoiseCancelingMode::Custom,
+        ]),
+        transparency_mode(),
+        any::<u8>().prop_map(CustomNoiseCanceling::new),
+    )
+        .prop_map(
+            |(
+                ambient_sound_mode,
+                noise_canceling_mode,
+                transparency_mode,
+                custom_noise_canceling,
+            )| {
+                SoundModes {
+                    ambient_sound_mode,
+                    noise_canceling_mode,
+                    transparency_mode,
+                    custom_noise_canceling,
+                }
+            },
+        )
+}
+
+pub(crate) fn sound_modes_type_two() -> impl Strategy<Value = SoundModesTypeTwo> {
+    (
+        ambient_sound_mode(),
+        transparency_mode(),
+        select(vec![
+            AdaptiveNoiseCanceling::LowNoise,
+            AdaptiveNoiseCanceling::MediumNoise,
+            AdaptiveNoiseCanceling::HighNoise,
+        ]),
+        select(vec![
+            ManualNoiseCanceling::Weak,
+            ManualNoiseCanceling::Moderate,
+            ManualNoiseCanceling::Strong,
+        ]),
+        select(vec![
+            NoiseCancelingModeTypeTwo::Adaptive,
+            NoiseCancelingModeTypeTwo::Manual,
+        ]),
+        any::<bool>(),
+        any::<u8>(),
+    )
+        .prop_map(
+            |(
+                ambient_sound_mode,
+                transparency_mode,
+                adaptive_noise_canceling,
+                manual_noise_canceling,
+                noise_canceling_mode,
+                wind_noise_suppression,
+                noise_canceling_adaptive_sensitivity_level,
+            )| SoundModesTypeTwo {
+                ambient_sound_mode,
+                transparency_mode,
+                adaptive_noise_canceling,
+                manual_noise_canceling,
+                noise_canceling_mode,
+                wind_noise_suppression,
+                noise_canceling_adaptive_sensitivity_level,
+            },
+        )
+}
+
+fn ambient_sound_mode() -> impl Strategy<Value = AmbientSoundMode> {
+    select(vec![
+        AmbientSoundMode::NoiseCanceling,
+        AmbientSoundMode::Transparency,
+        AmbientSoundMode::Normal,
+    ])
+}
+
+fn transparency_mode() -> impl Strategy<Value = TransparencyMode> {
+    select(vec![
+        TransparencyMode::FullyTransparent,
+        TransparencyMode::VocalMode,
+    ])
+}
+
+pub(crate) fn ambient_sound_mode_cycle() -> impl Strategy<Value = AmbientSoundModeCycle> {
+    (any::<bool>(), any::<bool>(), any::<bool>()).prop_map(
+        |(noise_canceling_mode, transparency_mode, normal_mode)| AmbientSoundModeCycle {
+            noise_canceling_mode,
+            transparency_mode,
+            normal_mode,
+        },
+    )
+}
+
+pub(crate) fn basic_hear_id() -> impl Strategy<Value = BasicHearId> {
+    (any::<bool>(), stereo_volume_adjustments(8), any::<i32>()).prop_map(
+        |(is_enabled, volume_adjustments, time)| BasicHearId {
+            is_enabled,
+            volume_adjustments,
+            time,
+        },
+    )
+}
+
+/// Custom volume adjustments starting with 255 are how their absence is represented, so they're
+/// left out.
+pub(crate) fn custom_hear_id_with_all_fields() -> impl Strategy<Value = CustomHearId> {
+    (
+        any::<bool>(),
+        stereo_volume_adjustments(8),
+        any::<i32>(),
+        any::<u8>(),
+        any::<u8>(),
+        option::of(
+            stereo_volume_adjustments(8).prop_filter("first band is 255", |volume_adjustments| {
+                volume_adjustments.left.bytes().next() != Some(255)
+            }),
+        ),
+    )
+        .prop_map(
+            |(
+                is_enabled,
+                volume_adjustments,
+                time,
+                hear_id_type,
+                hear_id_music_type,
+                custom_volume_adjustments,
+            )| CustomHearId {
+                is_enabled,
+                volume_adjustments,
+                time,
+                hear_id_type: HearIdType(hear_id_type),
+                hear_id_music_type: HearIdMusicType(hear_id_music_type),
+                custom_volume_adjustments,
+            },
+        )
+}
+
+/// Without a music type, custom volume adjustments are always present.
+pub(crate) fn custom_hear_id_without_music_type(
+    num_bands: usize,
+) -> impl Strategy<Value = CustomHearId> {
+    (
+        any::<bool>(),
+        stereo_volume_adjustments(num_bands),
+        any::<i32>(),
+        any::<u8>(),
+        stereo_volume_adjustments(num_bands),
+    )
+        .prop_map(
+            |(is_enabled, volume_adjustments, time, hear_id_type, custom_volume_adjustments)| {
+                CustomHearId {
+                    is_enabled,
+                    volume_adjustments,
+                    time,
+                    hear_id_type: HearIdType(hear_id_type),
+                    hear_id_music_type: HearIdMusicType(0),
+                    custom_volume_adjustments: Some(custom_volume_adjustments),
+                }
+            },
+        )
+}
+
+pub(crate) fn button_action() -> impl Strategy<Value = ButtonAction> {
+    select(ButtonAction::iter().collect::<Vec<_>>())
+}
+
+pub(crate) fn internal_multi_button_configuration(
+) -> impl Strategy<Value = InternalMultiButtonConfiguration> {
+    let no_tws_button_configuration = || {
+        (button_action(), any::<bool>())
+            .prop_map(|(action, is_enabled)| NoTwsButtonConfiguration { action, is_enabled })
+    };
+    let tws_button_configuration = || {
+        (button_action(), button_action(), any::<bool>()).prop_map(
+            |(tws_connected_action, tws_disconnected_action, disconnected_switch)| {
+                TwsButtonConfiguration {
+                    tws_connected_action,
+                    tws_disconnected_action,
+                    disconnected_switch,
+                }
+            },
+        )
+    };
+    (
+        no_tws_button_configuration(),
+        tws_button_configuration(),
+        tws_button_configuration(),
+        no_tws_button_configuration(),
+        tws_button_configuration(),
+        tws_button_configuration(),
+    )
+        .prop_map(
+            |(
+                left_single_click,
+                left_double_click,
+                left_long_press,
+                right_single_click,
+                right_double_click,
+                right_long_press,
+            )| InternalMultiButtonConfiguration {
+                left_single_click,
+                left_double_click,
+                left_long_press,
+                right_single_click,
+                right_double_click,
+                right_long_press,
+            },
+        )
+}
@@ -20,6 +20,10 @@ pub struct TwsStatus {
 }
 
 impl TwsStatus {
+    pub fn bytes(&self) -> [u8; 2] {
+        [self.host_device as u8, self.is_connected.into()]
+    }
+
     pub(crate) fn take<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
         input: &'a [u8],
     ) -> IResult<&'a [u8], Self, E> {
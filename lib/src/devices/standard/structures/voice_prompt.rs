@@ -0,0 +1,29 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, IntoStaticStr};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Display, AsRefStr, IntoStaticStr, EnumIter,
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum VoicePrompt {
+    #[default]
+    English,
+    Chinese,
+}
+
+impl VoicePrompt {
+    /// Devices only report whether the Chinese voice prompts are enabled.
+    pub(crate) fn from_is_chinese(is_chinese: bool) -> Self {
+        if is_chinese {
+            Self::Chinese
+        } else {
+            Self::English
+        }
+    }
+
+    pub(crate) fn is_chinese(&self) -> bool {
+        *self == Self::Chinese
+    }
+}
@@ -109,13 +109,13 @@ impl VolumeAdjustments {
             .collect::<Arc<[f64]>>()
     }
 
-    fn signed_adjustment_to_packet_byte(adjustment: f64) -> u8 {
+    pub(crate) fn signed_adjustment_to_packet_byte(adjustment: f64) -> u8 {
         let clamped = adjustment.clamp(Self::MIN_VOLUME, Self::MAX_VOLUME);
         let shifted = (clamped - Self::MIN_VOLUME) * 10.0;
         shifted.round() as u8
     }
 
-    fn packet_byte_to_signed_adjustment(byte: u8) -> f64 {
+    pub(crate) fn packet_byte_to_signed_adjustment(byte: u8) -> f64 {
         (byte as f64) / 10.0 + Self::MIN_VOLUME
     }
 
@@ -269,10 +269,12 @@ impl ApproxEq for &VolumeAdjustments {
 
     fn approx_eq<M: Into<Self::Margin>>(self, other: Self, margin: M) -> bool {
         let margin = margin.into();
-        self.adjustments()
-            .iter()
-            .zip(other.adjustments().iter())
-            .all(|(left, right)| left.approx_eq(*right, margin))
+        self.volume_adjustments.len() == other.volume_adjustments.len()
+            && self
+                .adjustments()
+                .iter()
+                .zip(other.adjustments().iter())
+                .all(|(left, right)| left.approx_eq(*right, margin))
     }
 }
 
@@ -0,0 +1,481 @@
+//! Breaks raw frames down into their header, body fields, and checksum, for figuring out what a
+//! device is sending.
+mod fields;
+
+use std::fmt::Display;
+
+use nom::error::VerboseError;
+
+use crate::{
+    capture::{decode_hex, Capture, CaptureEntry, Direction},
+    devices::standard::{
+        packets::{
+            checksum::calculate_checksum,
+            inbound::{take_inbound_packet_header, AnyInboundPacket},
+            outbound::{
+                RequestBatteryChargingPacket, RequestBatteryLevelPacket,
+                RequestFirmwareVersionPacket, RequestStatePacket, SetAmbientSoundModeCyclePacket,
+                SetAutoPowerOffPacket, SetEqualizerAndCustomHearIdPacket, SetEqualizerPacket,
+                SetEqualizerWithDrcPacket, SetGameModePacket, SetLdacPacket,
+                SetMultiButtonConfigurationPacket, SetSoundModePacket, SetTouchTonePacket,
+                SetVoicePromptPacket, SetWearDetectionPacket,
+            },
+        },
+        structures::{Command, CommandDirection},
+    },
+};
+
+use self::fields::{context_path, fields};
+
+const HEADER_LENGTH: usize = 9;
+
+/// Outbound packets can only be built, not parsed, so they are recognized by command alone. Some
+/// commands are shared by packets for different models, in which case all of their names are given.
+const OUTBOUND_COMMANDS: &[(Command, &str)] = &[
+    (RequestStatePacket::COMMAND, "RequestState"),
+    (RequestBatteryLevelPacket::COMMAND, "RequestBatteryLevel"),
+    (
+        RequestBatteryChargingPacket::COMMAND,
+        "RequestBatteryCharging",
+    ),
+    (
+        RequestFirmwareVersionPacket::COMMAND,
+        "RequestFirmwareVersion",
+    ),
+    (
+        SetSoundModePacket::COMMAND,
+        "SetSoundMode or SetSoundModeTypeTwo",
+    ),
+    (
+        SetAmbientSoundModeCyclePacket::COMMAND,
+        "SetAmbientSoundModeCycle",
+    ),
+    (SetEqualizerPacket::COMMAND, "SetEqualizer"),
+    (SetEqualizerWithDrcPacket::COMMAND, "SetEqualizerWithDrc"),
+    (
+        SetEqualizerAndCustomHearIdPacket::COMMAND,
+        "SetEqualizerAndCustomHearId",
+    ),
+    (
+        SetEqualizerAndCustomHearIdPacket::COMMAND_WITHOUT_HEAR_ID,
+        "SetEqualizerAndCustomHearId",
+    ),
+    (
+        SetMultiButtonConfigurationPacket::COMMAND,
+        "SetMultiButtonConfiguration",
+    ),
+    (SetWearDetectionPacket::COMMAND, "SetWearDetection"),
+    (SetTouchTonePacket::COMMAND, "SetTouchTone"),
+    (SetAutoPowerOffPacket::COMMAND, "SetAutoPowerOff"),
+    (SetVoicePromptPacket::COMMAND, "SetVoicePrompt"),
+    (SetGameModePacket::COMMAND, "SetGameMode"),
+    (SetLdacPacket::COMMAND, "SetLdac"),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameDissection {
+    pub bytes: Vec<u8>,
+    pub command: Option<Command>,
+    /// Every byte of the frame belongs to exactly one field, in order.
+    pub fields: Vec<DissectedField>,
+    pub packet: Option<AnyInboundPacket>,
+    /// The name of the packet, if the frame is a known outbound one. Their bodies aren't decoded.
+    pub outbound_packet: Option<&'static str>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DissectedField {
+    pub offset: usize,
+    pub name: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Dissects one or more frames written as hex. Whitespace, commas, colons, and `0x` prefixes are
+/// ignored, so output copied from most tools can be pasted as is. Frames that were concatenated
+/// together are split apart using their length fields.
+pub fn dissect_hex(hex: &str) -> crate::Result<Vec<FrameDissection>> {
+    let digits = hex
+        .replace("0x", "")
+        .replace("0X", "")
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',' && *c != ':')
+        .collect::<String>();
+    let bytes = decode_hex(&digits).map_err(|message| crate::Error::ParseError { message })?;
+    Ok(split_frames(&bytes).into_iter().map(dissect).collect())
+}
+
+/// Dissects each entry of `capture`, additionally reporting frames whose header doesn't match the
+/// direction they were recorded going in.
+pub fn dissect_capture(capture: &Capture) -> Vec<(&CaptureEntry, FrameDissection)> {
+    capture
+        .entries
+        .iter()
+        .map(|entry| {
+            let mut dissection = dissect(&entry.data);
+            let expected_direction = match entry.direction {
+                Direction::Inbound => CommandDirection::Inbound,
+                Direction::OutboundWithResponse | Direction::OutboundWithoutResponse => {
+                    CommandDirection::Outbound
+                }
+            };
+            if let Some(command) = dissection.command {
+                if command.direction() != expected_direction {
+                    dissection.errors.push(format!(
+                        "recorded as {}, but the header is {:?}",
+                        entry.direction.as_ref(),
+                        command.direction(),
+                    ));
+                }
+            }
+            (entry, dissection)
+        })
+        .collect()
+}
+
+pub fn dissect(frame: &[u8]) -> FrameDissection {
+    let mut dissection = FrameDissection {
+        bytes: frame.to_vec(),
+        command: None,
+        fields: Vec::new(),
+        packet: None,
+        outbound_packet: None,
+        errors: Vec::new(),
+    };
+    if frame.len() < HEADER_LENGTH + 1 {
+        dissection.fields.push(DissectedField {
+            offset: 0,
+            name: "unknown".to_owned(),
+            bytes: frame.to_vec(),
+        });
+        dissection.errors.push(format!(
+            "frame is {} bytes, but the header and checksum alone take {} bytes",
+            frame.len(),
+            HEADER_LENGTH + 1,
+        ));
+        return dissection;
+    }
+
+    let checksum_offset = frame.len() - 1;
+    let length = u16::from_le_bytes([frame[7], frame[8]]);
+    dissection.fields.push(DissectedField {
+        offset: 0,
+        name: "command".to_owned(),
+        bytes: frame[..7].to_vec(),
+    });
+    dissection.fields.push(DissectedField {
+        offset: 7,
+        name: "length".to_owned(),
+        bytes: frame[7..HEADER_LENGTH].to_vec(),
+    });
+    if usize::from(length) != frame.len() {
+        dissection.errors.push(format!(
+            "length field is {length}, but the frame is {} bytes",
+            frame.len(),
+        ));
+    }
+    let expected_checksum = calculate_checksum(&frame[..checksum_offset]);
+    if frame[checksum_offset] != expected_checksum {
+        dissection.errors.push(format!(
+            "checksum is {:#04x}, expected {expected_checksum:#04x}",
+            frame[checksum_offset],
+        ));
+    }
+
+    let body_fields = match take_inbound_packet_header::<VerboseError<_>>(frame) {
+        Ok((body, command)) => {
+            dissection.command = Some(command);
+            match command.direction() {
+                CommandDirection::Inbound => decode_body(&mut dissection, command, body),
+                CommandDirection::Outbound => {
+                    identify_outbound(&mut dissection, command);
+                    None
+                }
+            }
+        }
+        Err(err) => {
+            dissection.errors.push(format!(
+                "invalid header: {}",
+                describe_error(frame, 0, &err)
+            ));
+            None
+        }
+    };
+    let body = &frame[HEADER_LENGTH..checksum_offset];
+    dissection
+        .fields
+        .extend(body_fields.unwrap_or_else(|| unknown_body(body)));
+    dissection.fields.push(DissectedField {
+        offset: checksum_offset,
+        name: "checksum".to_owned(),
+        bytes: vec![frame[checksum_offset]],
+    });
+    dissection
+}
+
+fn decode_body(
+    dissection: &mut FrameDissection,
+    command: Command,
+    body: &[u8],
+) -> Option<Vec<DissectedField>> {
+    let decoders = AnyInboundPacket::decoders()
+        .into_iter()
+        .filter(|decoder| decoder.command == command)
+        .collect::<Vec<_>>();
+    if decoders.is_empty() {
+        dissection.errors.push(format!(
+            "no known inbound packet has command {}",
+            hex_bytes(command.bytes()),
+        ));
+        return None;
+    }
+
+    let mut decode_errors = Vec::new();
+    for decoder in decoders {
+        match (decoder.take)(body) {
+            Ok((_, packet)) => {
+                dissection.packet = Some(packet);
+                return Some(fields(&decoder, body, HEADER_LENGTH));
+            }
+            Err(err) => decode_errors.push(format!(
+                "not a {}: {}",
+                decoder.name,
+                describe_error(body, HEADER_LENGTH, &err),
+            )),
+        }
+    }
+    dissection.errors.extend(decode_errors);
+    None
+}
+
+fn identify_outbound(dissection: &mut FrameDissection, command: Command) {
+    match OUTBOUND_COMMANDS
+        .iter()
+        .find(|(outbound_command, _)| *outbound_command == command)
+    {
+        Some((_, name)) => dissection.outbound_packet = Some(name),
+        None => dissection.errors.push(format!(
+            "no known outbound packet has command {}",
+            hex_bytes(command.bytes()),
+        )),
+    }
+}
+
+fn describe_error(input: &[u8], offset: usize, error: &nom::Err<VerboseError<&[u8]>>) -> String {
+    match error {
+        nom::Err::Error(error) | nom::Err::Failure(error) => {
+            let position = error
+                .errors
+                .first()
+                .map(|(remaining, _)| {
+                    offset + (remaining.as_ptr() as usize).saturating_sub(input.as_ptr() as usize)
+                })
+                .unwrap_or(offset);
+            let path = context_path(input, error)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>();
+            if path.is_empty() {
+                format!("failed at byte {position}")
+            } else {
+                format!("failed at byte {position} in {}", path.join(" > "))
+            }
+        }
+        nom::Err::Incomplete(_) => "ran out of data".to_owned(),
+    }
+}
+
+fn unknown_body(body: &[u8]) -> Vec<DissectedField> {
+    if body.is_empty() {
+        return Vec::new();
+    }
+    vec![DissectedField {
+        offset: HEADER_LENGTH,
+        name: "body".to_owned(),
+        bytes: body.to_vec(),
+    }]
+}
+
+fn split_frames(mut bytes: &[u8]) -> Vec<&[u8]> {
+    let mut frames = Vec::new();
+    while !bytes.is_empty() {
+        let length = bytes
+            .get(7..HEADER_LENGTH)
+            .map(|length| usize::from(u16::from_le_bytes([length[0], length[1]])))
+            .filter(|length| (HEADER_LENGTH + 1..=bytes.len()).contains(length))
+            .unwrap_or(bytes.len());
+        let (frame, rest) = bytes.split_at(length);
+        frames.push(frame);
+        bytes = rest;
+    }
+    frames
+}
+
+fn hex_bytes(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Display for FrameDissection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name_width = self
+            .fields
+            .iter()
+            .map(|field| field.name.len())
+            .max()
+            .unwrap_or_default();
+        for field in &self.fields {
+            writeln!(
+                f,
+                "{:>4}  {:<name_width$}  {}",
+                field.offset,
+                field.name,
+                hex_bytes(&field.bytes),
+            )?;
+        }
+        for error in &self.errors {
+            writeln!(f, "error: {error}")?;
+        }
+        if let Some(name) = self.outbound_packet {
+            writeln!(f, "outbound {name}")?;
+        }
+        if let Some(packet) = &self.packet {
+            writeln!(f, "{packet:#?}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(offset: usize, name: &str, bytes: &[u8]) -> DissectedField {
+        DissectedField {
+            offset,
+            name: name.to_owned(),
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    #[test]
+    fn it_dissects_a_tws_status_update() {
+        let dissections = dissect_hex("09 ff 00 00 01 01 02 0c 00 01 00 19").unwrap();
+        assert_eq!(1, dissections.len());
+        let dissection = &dissections[0];
+
+        assert_eq!(Vec::<String>::new(), dissection.errors);
+        assert_eq!(
+            "TwsStatusUpdate",
+            dissection.packet.as_ref().unwrap().name()
+        );
+        assert_eq!(
+            vec![
+                field(0, "command", &[0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x02]),
+                field(7, "length", &[0x0c, 0x00]),
+                field(9, "TwsStatusUpdatePacket > host device", &[0x01]),
+                field(10, "TwsStatusUpdatePacket", &[0x00]),
+                field(11, "checksum", &[0x19]),
+            ],
+            dissection.fields,
+        );
+    }
+
+    #[test]
+    fn it_dissects_state_updates_with_the_matching_model() {
+        let dissection = dissect(&[
+            0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01, 0x46, 0x00, 0x05, 0x00, 0x01, 0x00, 0x3c,
+            0xb4, 0x8f, 0xa0, 0x8e, 0xb4, 0x74, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x30, 0x32, 0x2e, 0x33, 0x30, 0x33, 0x30, 0x32,
+            0x39, 0x30, 0x38, 0x36, 0x45, 0x43, 0x38, 0x32, 0x46, 0x31, 0x32, 0x41, 0x43, 0x35,
+        ]);
+
+        assert_eq!(Vec::<String>::new(), dissection.errors);
+        assert_eq!(
+            "A3028StateUpdate",
+            dissection.packet.as_ref().unwrap().name()
+        );
+        assert!(dissection.fields.contains(&field(
+            45,
+            "a3028 state update packet > group of sound modes > noise canceling mode",
+            &[0x00],
+        )));
+        assert!(dissection.fields.contains(&field(
+            48,
+            "a3028 state update packet > firmware version",
+            &[0x30, 0x32, 0x2e, 0x33, 0x30],
+        )));
+        let total_length: usize = dissection
+            .fields
+            .iter()
+            .map(|field| field.bytes.len())
+            .sum();
+        assert_eq!(dissection.bytes.len(), total_length);
+    }
+
+    #[test]
+    fn it_splits_concatenated_frames() {
+        let dissections = dissect_hex(
+            "0x09,0xff,0x00,0x00,0x01,0x01,0x02,0x0c,0x00,0x01,0x00,0x19,09ff000001010f0b000125",
+        )
+        .unwrap();
+        let names = dissections
+            .iter()
+            .map(|dissection| dissection.packet.as_ref().unwrap().name())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["TwsStatusUpdate", "ChineseVoicePromptStateUpdate"],
+            names,
+        );
+    }
+
+    #[test]
+    fn it_reports_a_bad_checksum() {
+        let dissection = dissect(&[
+            0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x02, 0x0c, 0x00, 0x01, 0x00, 0x18,
+        ]);
+        assert_eq!(None, dissection.command);
+        assert_eq!(None, dissection.packet);
+        assert!(dissection.errors[0].contains("checksum"));
+        assert_eq!(field(9, "body", &[0x01, 0x00]), dissection.fields[2]);
+    }
+
+    #[test]
+    fn it_reports_unknown_commands() {
+        let dissection = dissect(&[0x09, 0xff, 0x00, 0x00, 0x01, 0xee, 0xee, 0x0a, 0x00, 0xef]);
+        assert_eq!(None, dissection.packet);
+        assert!(dissection.errors[0].contains("no known inbound packet"));
+    }
+
+    #[test]
+    fn it_identifies_outbound_frames() {
+        let dissection = dissect(&[
+            0x08, 0xee, 0x00, 0x00, 0x00, 0x01, 0xff, 0x0b, 0x00, 0x01, 0x02,
+        ]);
+        assert_eq!(Vec::<String>::new(), dissection.errors);
+        assert_eq!(None, dissection.packet);
+        assert_eq!(Some("SetLdac"), dissection.outbound_packet);
+        assert_eq!(field(9, "body", &[0x01]), dissection.fields[2]);
+    }
+
+    #[test]
+    fn it_reports_capture_entries_recorded_in_the_wrong_direction() {
+        let capture: Capture = "name Test\nmac_address 00:00:00:00:00:00\n\
+            0 outbound-with-response 08ee00000001010a0002\n\
+            12 outbound-without-response 09ff000001010f0b000125\n"
+            .parse()
+            .unwrap();
+        let dissections = dissect_capture(&capture);
+
+        assert_eq!(Vec::<String>::new(), dissections[0].1.errors);
+        assert_eq!(Some("RequestState"), dissections[0].1.outbound_packet);
+        assert_eq!(
+            vec!["recorded as outbound-without-response, but the header is Inbound".to_owned()],
+            dissections[1].1.errors,
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use nom::error::{VerboseError, VerboseErrorKind};
+
+use super::DissectedField;
+use crate::devices::standard::packets::inbound::InboundPacketDecoder;
+
+/// Works out which parser was responsible for each byte of `body` by truncating it after every
+/// byte and looking at which `context`s the resulting error passed through. Each distinct
+/// invocation of a parser becomes one field.
+pub(super) fn fields(
+    decoder: &InboundPacketDecoder,
+    body: &[u8],
+    body_offset: usize,
+) -> Vec<DissectedField> {
+    let mut paths = (0..body.len())
+        .map(|end| match (decoder.take)(&body[..end]) {
+            Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+                Some(context_path(body, &error))
+            }
+            // Either all remaining fields are optional, or we have no information to go on.
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    // A successful parse of a truncated body means the next byte starts an optional field, so
+    // it belongs with whatever comes after it.
+    for i in (0..paths.len()).rev() {
+        if paths[i].is_none() {
+            paths[i] = paths.get(i + 1).cloned().flatten();
+        }
+    }
+
+    let mut fields: Vec<DissectedField> = Vec::new();
+    let mut previous_path = None;
+    for (offset, path) in paths.into_iter().enumerate() {
+        if offset != 0 && previous_path == path {
+            if let Some(field) = fields.last_mut() {
+                field.bytes.push(body[offset]);
+            }
+            continue;
+        }
+        let name = match &path {
+            Some(path) if !path.is_empty() => path
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(" > "),
+            _ => decoder.name.to_owned(),
+        };
+        fields.push(DissectedField {
+            offset: body_offset + offset,
+            name,
+            bytes: vec![body[offset]],
+        });
+        previous_path = path;
+    }
+    fields
+}
+
+/// Names and starting offsets of the contexts an error passed through, outermost first.
+pub(super) fn context_path(body: &[u8], error: &VerboseError<&[u8]>) -> Vec<(&'static str, usize)> {
+    error
+        .errors
+        .iter()
+        .rev()
+        .filter_map(|(input, kind)| match kind {
+            VerboseErrorKind::Context(name) => Some((
+                *name,
+                (input.as_ptr() as usize).saturating_sub(body.as_ptr() as usize),
+            )),
+            _ => None,
+        })
+        .collect()
+}
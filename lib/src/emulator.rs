@@ -0,0 +1,7 @@
+mod commands;
+mod emulated_connection;
+mod emulated_connection_registry;
+mod state_update_packet;
+
+pub use emulated_connection::*;
+pub use emulated_connection_registry::*;
@@ -0,0 +1,273 @@
+use nom::{
+    bytes::complete::take,
+    combinator::{all_consuming, cond, map},
+    error::VerboseError,
+    multi::count,
+    number::complete::{be_i32, le_u16, le_u8},
+    sequence::{pair, tuple},
+};
+
+use crate::{
+    devices::{
+        a3936::structures::A3936InternalMultiButtonConfiguration,
+        standard::{
+            packets::{
+                outbound::{
+                    SetAmbientSoundModeCyclePacket, SetAutoPowerOffPacket,
+                    SetEqualizerAndCustomHearIdPacket, SetEqualizerPacket,
+                    SetEqualizerWithDrcPacket, SetGameModePacket, SetLdacPacket,
+                    SetMultiButtonConfigurationPacket, SetSoundModePacket, SetTouchTonePacket,
+                    SetVoicePromptPacket, SetWearDetectionPacket,
+                },
+                parsing::take_bool,
+            },
+            state::DeviceState,
+            structures::{
+                AgeRange, AmbientSoundModeCycle, AutoPowerOff, Command, EqualizerConfiguration,
+                Gender, HearId, HearIdType, InternalMultiButtonConfiguration,
+                PresetEqualizerProfile, SoundModes, SoundModesTypeTwo, StereoVolumeAdjustments,
+                VoicePrompt, VolumeAdjustments,
+            },
+        },
+    },
+    soundcore_device::device_model::DeviceModel,
+};
+
+type ParseError<'a> = nom::Err<VerboseError<&'a [u8]>>;
+
+/// Applies a command that changes a setting to `state`. Returns `Ok(false)` if the command isn't
+/// one that `model` would acknowledge, either because it isn't a setting or because `model`
+/// doesn't have that feature.
+pub(super) fn apply_command<'a>(
+    model: DeviceModel,
+    command: Command,
+    body: &'a [u8],
+    state: &mut DeviceState,
+) -> Result<bool, ParseError<'a>> {
+    let features = state.device_features;
+    match command {
+        SetWearDetectionPacket::COMMAND if features.has_wear_detection => {
+            state.wear_detection = Some(take_single_bool(body)?)
+        }
+        SetTouchTonePacket::COMMAND if features.has_touch_tone => {
+            state.touch_tone = Some(take_single_bool(body)?)
+        }
+        SetAutoPowerOffPacket::COMMAND if features.has_auto_power_off => {
+            let (is_enabled, index) = all_consuming(pair(take_bool, le_u8))(body)?.1;
+            state.auto_power_off = AutoPowerOff::from_index(is_enabled, index);
+        }
+        SetVoicePromptPacket::COMMAND if features.has_voice_prompt => {
+            state.voice_prompt = Some(VoicePrompt::from_is_chinese(take_single_bool(body)?))
+        }
+        SetGameModePacket::COMMAND if features.has_game_mode => {
+            state.game_mode = Some(take_single_bool(body)?)
+        }
+        SetLdacPacket::COMMAND if features.has_ldac => state.ldac = Some(take_single_bool(body)?),
+        SetEqualizerWithDrcPacket::COMMAND if !features.has_dynamic_range_compression => {
+            return Ok(false)
+        }
+        SetEqualizerPacket::COMMAND | SetEqualizerWithDrcPacket::COMMAND => {
+            // Bodies with drc repeat every channel, so they're twice as long
+            let num_channels = state.device_features.num_equalizer_channels.max(1);
+            let num_volume_adjustments = if command == SetEqualizerWithDrcPacket::COMMAND {
+                num_channels * 2
+            } else {
+                num_channels
+            };
+            let num_bands = body.len().saturating_sub(2) / num_volume_adjustments;
+            let (profile_id, channels) = all_consuming(tuple((
+                le_u16,
+                count(take_volume_adjustments(num_bands), num_channels),
+                take(num_bands * (num_volume_adjustments - num_channels)),
+            )))(body)
+            .map(|(_, (profile_id, channels, _drc))| (profile_id, channels))?;
+            set_equalizer_channels(state, profile_id, channels);
+        }
+        SetEqualizerAndCustomHearIdPacket::COMMAND
+        | SetEqualizerAndCustomHearIdPacket::COMMAND_WITHOUT_HEAR_ID
+            if features.has_hear_id =>
+        {
+            apply_equalizer_and_hear_id(
+                command == SetEqualizerAndCustomHearIdPacket::COMMAND,
+                body,
+                state,
+            )?
+        }
+        SetMultiButtonConfigurationPacket::COMMAND if features.has_button_configuration => {
+            state.button_configuration = Some(if model == DeviceModel::A3936 {
+                let is_tws_connected = state
+                    .tws_status
+                    .is_some_and(|tws_status| tws_status.is_connected);
+                all_consuming(A3936InternalMultiButtonConfiguration::take)(body)?
+                    .1
+                    .as_multi_button_configuration(is_tws_connected)
+            } else {
+                all_consuming(InternalMultiButtonConfiguration::take)(body)?
+                    .1
+                    .into()
+            })
+        }
+        SetSoundModePacket::COMMAND if state.sound_modes_type_two.is_some() => {
+            state.sound_modes_type_two = Some(all_consuming(SoundModesTypeTwo::take)(body)?.1)
+        }
+        SetSoundModePacket::COMMAND if state.sound_modes.is_some() => {
+            state.sound_modes = Some(all_consuming(SoundModes::take)(body)?.1)
+        }
+        SetAmbientSoundModeCyclePacket::COMMAND if features.has_ambient_sound_mode_cycle => {
+            state.ambient_sound_mode_cycle =
+                Some(all_consuming(AmbientSoundModeCycle::take)(body)?.1)
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+fn take_single_bool(body: &[u8]) -> Result<bool, ParseError<'_>> {
+    all_consuming(take_bool)(body).map(|(_, value)| value)
+}
+
+/// [`VolumeAdjustments::take`] panics on unsupported numbers of bands, which the length of a body
+/// from a misbehaving client could imply.
+fn take_volume_adjustments<'a>(
+    num_bands: usize,
+) -> impl FnMut(&'a [u8]) -> nom::IResult<&'a [u8], VolumeAdjustments, VerboseError<&'a [u8]>> {
+    move |input| {
+        if !VolumeAdjustments::VALID_NUMBER_OF_BANDS.contains(&num_bands) {
+            return Err(nom::Err::Failure(VerboseError {
+                errors: vec![(
+                    input,
+                    nom::error::VerboseErrorKind::Context("number of equalizer bands"),
+                )],
+            }));
+        }
+        VolumeAdjustments::take(num_bands)(input)
+    }
+}
+
+/// The body layout is the same whether or not hear id is supported, but without hear id support
+/// the hear id fields are filled with placeholder values and the eq hear index is left out.
+fn apply_equalizer_and_hear_id<'a>(
+    supports_hear_id: bool,
+    body: &'a [u8],
+    state: &mut DeviceState,
+) -> Result<(), ParseError<'a>> {
+    // profile id, eq hear index, gender, age range, unknown byte, time, hear id type
+    let fixed_length = if supports_hear_id { 12 } else { 10 };
+    // left and right eq, hear id, custom hear id, drc
+    let num_bands = body.len().saturating_sub(fixed_length) / 8;
+    let (
+        _,
+        (
+            profile_id,
+            _eq_hear_index,
+            channels,
+            gender,
+            age_range,
+            _unknown,
+            hear_id_volume_adjustments,
+            time,
+            hear_id_type,
+            custom_volume_adjustments,
+            _drc,
+        ),
+    ) = all_consuming(tuple((
+        le_u16,
+        cond(supports_hear_id, le_u16),
+        count(take_volume_adjustments(num_bands), 2),
+        le_u8,
+        le_u8,
+        le_u8,
+        map(
+            pair(
+                take_volume_adjustments(num_bands),
+                take_volume_adjustments(num_bands),
+            ),
+            |(left, right)| StereoVolumeAdjustments { left, right },
+        ),
+        be_i32,
+        HearIdType::take,
+        take(num_bands * 2),
+        take(num_bands * 2),
+    )))(body)?;
+
+    let num_channels = state.device_features.num_equalizer_channels.max(1);
+    set_equalizer_channels(state, profile_id, channels.into_iter().take(num_channels));
+    if !supports_hear_id {
+        return Ok(());
+    }
+
+    state.gender = Some(Gender(gender));
+    state.age_range = Some(AgeRange(age_range));
+    state.hear_id = match state.hear_id.take() {
+        Some(HearId::Basic(hear_id)) => Some(HearId::Basic(
+            crate::devices::standard::structures::BasicHearId {
+                volume_adjustments: hear_id_volume_adjustments,
+                time,
+                ..hear_id
+            },
+        )),
+        Some(HearId::Custom(hear_id)) => {
+            let custom_volume_adjustments: &[u8] = custom_volume_adjustments;
+            Some(HearId::Custom(
+                crate::devices::standard::structures::CustomHearId {
+                    volume_adjustments: hear_id_volume_adjustments,
+                    time,
+                    hear_id_type,
+                    custom_volume_adjustments: (custom_volume_adjustments[0] != u8::MAX).then(
+                        || {
+                            let (left, right) = custom_volume_adjustments.split_at(num_bands);
+                            StereoVolumeAdjustments {
+                                left: VolumeAdjustments::from_bytes(left)
+                                    .expect("band count was already validated"),
+                                right: VolumeAdjustments::from_bytes(right)
+                                    .expect("band count was already validated"),
+                            }
+                        },
+                    ),
+                    ..hear_id
+                },
+            ))
+        }
+        None => None,
+    };
+    Ok(())
+}
+
+fn set_equalizer_channels(
+    state: &mut DeviceState,
+    profile_id: u16,
+    channels: impl IntoIterator<Item = VolumeAdjustments>,
+) {
+    let mut channels = channels
+        .into_iter()
+        .map(|volume_adjustments| equalizer_configuration(profile_id, volume_adjustments));
+    let (Some(left), right) = (channels.next(), channels.next()) else {
+        return;
+    };
+    // Single channel devices that still report a right channel apply the left channel to both
+    if right.is_some() || state.right_equalizer_configuration.is_some() {
+        state.right_equalizer_configuration = Some(right.unwrap_or_else(|| left.to_owned()));
+    }
+    state.equalizer_configuration = left;
+}
+
+/// Preset profiles only cover 8 bands, so any bands past that are kept.
+fn equalizer_configuration(
+    profile_id: u16,
+    volume_adjustments: VolumeAdjustments,
+) -> EqualizerConfiguration {
+    match PresetEqualizerProfile::from_id(profile_id) {
+        Some(preset_profile) => {
+            let configuration = EqualizerConfiguration::new_from_preset_profile(preset_profile);
+            let extra_bands = volume_adjustments.adjustments();
+            if extra_bands.len() > 8 {
+                configuration
+                    .with_extra_bands(extra_bands[8..].iter().cloned())
+                    .expect("number of bands is unchanged")
+            } else {
+                configuration
+            }
+        }
+        None => EqualizerConfiguration::new_custom_profile(volume_adjustments),
+    }
+}
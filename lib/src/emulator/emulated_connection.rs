@@ -0,0 +1,607 @@
+use macaddr::MacAddr6;
+use nom::error::VerboseError;
+use tokio::sync::{mpsc, watch, Mutex};
+use uuid::Uuid;
+
+use crate::{
+    api::connection::{Connection, ConnectionStatus},
+    devices::standard::{
+        packets::{
+            inbound::{
+                state_update_packet::StateUpdatePacket, take_inbound_packet_header,
+                BatteryChargingUpdatePacket, BatteryLevelUpdatePacket, FirmwareVersionUpdatePacket,
+                GameModeUpdatePacket, InboundPacket, LdacStateUpdatePacket, SoundModeUpdatePacket,
+                TwsStatusUpdatePacket,
+            },
+            outbound::{
+                RequestBatteryChargingPacket, RequestBatteryLevelPacket,
+                RequestFirmwareVersionPacket, RequestStatePacket,
+            },
+        },
+        state::DeviceState,
+        structures::{
+            Battery, BatteryLevel, Command, DualBattery, DualFirmwareVersion, FirmwareVersion,
+            IsBatteryCharging, SerialNumber, SingleBattery, SoundModes, SoundModesTypeTwo,
+            TwsStatus,
+        },
+    },
+    soundcore_device::{device::Packet, device_model::DeviceModel},
+};
+
+use super::{commands::apply_command, state_update_packet::state_update_packet_bytes};
+
+/// A virtual device that speaks the same packets as the real thing. Requests for state are
+/// answered with a packet encoded the way `model` would encode it, settings that are changed are
+/// acknowledged and kept, and the device can be made to send updates on its own, so everything
+/// above the [`Connection`] layer can be exercised without hardware.
+#[derive(Debug)]
+pub struct EmulatedConnection {
+    model: DeviceModel,
+    pub(super) mac_address: MacAddr6,
+    serial_number: SerialNumber,
+    firmware_version: DualFirmwareVersion,
+    state: Mutex<DeviceState>,
+    inbound_sender: Mutex<Option<mpsc::Sender<Vec<u8>>>>,
+    connection_status_sender: watch::Sender<ConnectionStatus>,
+}
+
+impl EmulatedConnection {
+    /// Returns `None` if the state update packet of `model` isn't known, since the device
+    /// couldn't be identified without one.
+    pub fn new(model: DeviceModel, mac_address: MacAddr6) -> Option<Self> {
+        // Model number followed by the mac address with its pairs in reverse order, like the
+        // real thing
+        let serial_number = SerialNumber::from(
+            format!(
+                "{}{}",
+                &model.as_ref()[1..],
+                mac_address
+                    .as_bytes()
+                    .iter()
+                    .rev()
+                    .map(|byte| format!("{byte:02X}"))
+                    .collect::<String>(),
+            )
+            .as_str(),
+        );
+        let firmware_version =
+            DualFirmwareVersion::new(FirmwareVersion::new(2, 0), FirmwareVersion::new(2, 0));
+        let state = initial_state(model, &serial_number, firmware_version)?;
+        Some(Self {
+            model,
+            mac_address,
+            serial_number,
+            firmware_version,
+            state: Mutex::new(state),
+            inbound_sender: Mutex::new(None),
+            connection_status_sender: watch::channel(ConnectionStatus::Connected).0,
+        })
+    }
+
+    pub fn model(&self) -> DeviceModel {
+        self.model
+    }
+
+    pub fn name(&self) -> String {
+        format!("Emulated {}", self.model)
+    }
+
+    /// The state as a client would see it after fetching it from the device
+    pub async fn state(&self) -> DeviceState {
+        self.state.lock().await.to_owned()
+    }
+
+    pub fn set_connection_status(&self, connection_status: ConnectionStatus) {
+        self.connection_status_sender
+            .send_replace(connection_status);
+    }
+
+    pub async fn set_battery(&self, battery: Battery) {
+        self.state.lock().await.battery = battery;
+        let (levels, charging) = battery_bytes(battery);
+        self.send(BatteryLevelUpdatePacket::command(), levels).await;
+        self.send(BatteryChargingUpdatePacket::command(), charging)
+            .await;
+    }
+
+    pub async fn set_tws_status(&self, tws_status: TwsStatus) {
+        self.state.lock().await.tws_status = Some(tws_status);
+        self.send(
+            TwsStatusUpdatePacket::command(),
+            tws_status.bytes().to_vec(),
+        )
+        .await;
+    }
+
+    pub async fn set_sound_modes(&self, sound_modes: SoundModes) {
+        self.state.lock().await.sound_modes = Some(sound_modes);
+        self.send(
+            SoundModeUpdatePacket::command(),
+            sound_modes.bytes().to_vec(),
+        )
+        .await;
+    }
+
+    pub async fn set_sound_modes_type_two(&self, sound_modes: SoundModesTypeTwo) {
+        self.state.lock().await.sound_modes_type_two = Some(sound_modes);
+        self.send(
+            SoundModeUpdatePacket::command(),
+            sound_modes.bytes().to_vec(),
+        )
+        .await;
+    }
+
+    pub async fn set_game_mode(&self, is_enabled: bool) {
+        self.state.lock().await.game_mode = Some(is_enabled);
+        self.send(GameModeUpdatePacket::command(), vec![is_enabled.into()])
+            .await;
+    }
+
+    pub async fn set_ldac(&self, is_enabled: bool) {
+        self.state.lock().await.ldac = Some(is_enabled);
+        self.send(LdacStateUpdatePacket::command(), vec![is_enabled.into()])
+            .await;
+    }
+
+    /// Changes any part of the state and then sends it in full, like devices do after changes
+    /// that don't have an update packet of their own.
+    pub async fn modify_state(&self, modify: impl FnOnce(&mut DeviceState)) {
+        let bytes = {
+            let mut state = self.state.lock().await;
+            modify(&mut state);
+            self.state_update_packet_bytes(&state)
+        };
+        if let Some(bytes) = bytes {
+            self.send_bytes(bytes).await;
+        }
+    }
+
+    async fn send(&self, command: Command, body: Vec<u8>) {
+        self.send_bytes(Packet { command, body }.bytes()).await
+    }
+
+    async fn send_bytes(&self, bytes: Vec<u8>) {
+        let inbound_sender = self.inbound_sender.lock().await;
+        let Some(sender) = inbound_sender.as_ref() else {
+            return;
+        };
+        if let Err(err) = sender.send(bytes).await {
+            tracing::debug!("inbound packet receiver closed: {err:?}");
+        }
+    }
+
+    async fn handle_write(&self, data: &[u8]) -> crate::Result<()> {
+        let (body, command) = match take_inbound_packet_header::<VerboseError<_>>(data) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                tracing::warn!(
+                    "{}: ignoring malformed packet {data:02x?}: {err:?}",
+                    self.model
+                );
+                return Ok(());
+            }
+        };
+        if command == RequestStatePacket::COMMAND {
+            let bytes = self.state_update_packet_bytes(&*self.state.lock().await);
+            if let Some(bytes) = bytes {
+                self.send_bytes(bytes).await;
+            }
+        } else if let Some(response) = self.response(command, body).await {
+            self.send(command.to_inbound(), response).await;
+        }
+        Ok(())
+    }
+
+    async fn response(&self, command: Command, body: &[u8]) -> Option<Vec<u8>> {
+        let mut state = self.state.lock().await;
+        match command {
+            RequestFirmwareVersionPacket::COMMAND => Some(firmware_version_update_packet_body(
+                &self.serial_number,
+                self.firmware_version,
+            )),
+            RequestBatteryLevelPacket::COMMAND => Some(battery_bytes(state.battery).0),
+            RequestBatteryChargingPacket::COMMAND => Some(battery_bytes(state.battery).1),
+            _ => match apply_command(self.model, command, body, &mut state) {
+                // Acknowledgements don't have a body
+                Ok(true) => Some(Vec::new()),
+                Ok(false) => {
+                    tracing::warn!(
+                        "{}: ignoring unknown or unsupported command {command:?}",
+                        self.model
+                    );
+                    None
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "{}: ignoring {command:?} with malformed body {body:02x?}: {err:?}",
+                        self.model,
+                    );
+                    None
+                }
+            },
+        }
+    }
+
+    fn state_update_packet_bytes(&self, state: &DeviceState) -> Option<Vec<u8>> {
+        state_update_packet_bytes(
+            self.model,
+            &self.serial_number,
+            self.firmware_version,
+            state,
+        )
+    }
+}
+
+/// Runs the packet the device would send through the same parsing a client does, so the state
+/// starts out exactly how a client would see it.
+fn initial_state(
+    model: DeviceModel,
+    serial_number: &SerialNumber,
+    firmware_version: DualFirmwareVersion,
+) -> Option<DeviceState> {
+    let battery = SingleBattery {
+        is_charging: IsBatteryCharging::No,
+        level: BatteryLevel(4),
+    };
+    let state = DeviceState {
+        battery: Battery::DualBattery(DualBattery {
+            left: battery,
+            right: battery,
+        }),
+        ..Default::default()
+    };
+    let bytes = state_update_packet_bytes(model, serial_number, firmware_version, &state)?;
+    let body = take_inbound_packet_header::<VerboseError<_>>(&bytes)
+        .ok()?
+        .0;
+    let packet = match StateUpdatePacket::take::<VerboseError<_>>(body) {
+        Ok((_, packet)) => packet,
+        Err(err) => {
+            tracing::warn!("{model}: failed to parse own state update packet: {err:?}");
+            return None;
+        }
+    };
+    let implementation = (packet.device_profile.implementation)();
+    let mut state = match implementation.initialize(body) {
+        Ok(state) => state,
+        Err(err) => {
+            tracing::warn!("{model}: failed to initialize from own state update packet: {err:?}");
+            return None;
+        }
+    };
+    // Clients request the firmware version packet to get the serial number in this case
+    if state.serial_number.is_none() {
+        if let Some(handler) = implementation
+            .packet_handlers()
+            .get(&FirmwareVersionUpdatePacket::command())
+        {
+            state = handler(
+                &firmware_version_update_packet_body(serial_number, firmware_version),
+                state,
+            );
+        }
+    }
+    Some(state)
+}
+
+fn firmware_version_update_packet_body(
+    serial_number: &SerialNumber,
+    firmware_version: DualFirmwareVersion,
+) -> Vec<u8> {
+    [
+        firmware_version.left.bytes(),
+        firmware_version.right.bytes(),
+        serial_number.bytes().to_vec(),
+    ]
+    .concat()
+}
+
+/// Battery levels and whether each battery is charging are sent in separate packets
+fn battery_bytes(battery: Battery) -> (Vec<u8>, Vec<u8>) {
+    match battery {
+        Battery::SingleBattery(battery) => {
+            let [level, is_charging] = battery.bytes();
+            (vec![level], vec![is_charging])
+        }
+        Battery::DualBattery(battery) => {
+            let [left_level, right_level, left_is_charging, right_is_charging] = battery.bytes();
+            (
+                vec![left_level, right_level],
+                vec![left_is_charging, right_is_charging],
+            )
+        }
+    }
+}
+
+impl Connection for EmulatedConnection {
+    async fn name(&self) -> crate::Result<String> {
+        Ok(EmulatedConnection::name(self))
+    }
+
+    async fn mac_address(&self) -> crate::Result<MacAddr6> {
+        Ok(self.mac_address)
+    }
+
+    fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
+        self.connection_status_sender.subscribe()
+    }
+
+    async fn write_with_response(&self, data: &[u8]) -> crate::Result<()> {
+        self.handle_write(data).await
+    }
+
+    async fn write_without_response(&self, data: &[u8]) -> crate::Result<()> {
+        self.handle_write(data).await
+    }
+
+    async fn inbound_packets_channel(&self) -> crate::Result<mpsc::Receiver<Vec<u8>>> {
+        let (sender, receiver) = mpsc::channel(100);
+        *self.inbound_sender.lock().await = Some(sender);
+        Ok(receiver)
+    }
+
+    fn service_uuid(&self) -> Uuid {
+        Uuid::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use macaddr::MacAddr6;
+    use strum::VariantArray;
+    use tokio::sync::{mpsc, watch, Mutex};
+    use uuid::Uuid;
+
+    use super::EmulatedConnection;
+    use crate::{
+        api::{
+            connection::{Connection, ConnectionStatus},
+            device::Device,
+        },
+        devices::standard::{
+            packets::outbound::{OutboundPacketBytesExt, RequestStatePacket, SetGameModePacket},
+            structures::{
+                AmbientSoundMode, Battery, BatteryLevel, EqualizerConfiguration, IsBatteryCharging,
+                SingleBattery, SoundModes, SoundModesTypeTwo, TransparencyMode, VoicePrompt,
+                VolumeAdjustments,
+            },
+        },
+        futures::TokioFutures,
+        soundcore_device::{device::SoundcoreDevice, device_model::DeviceModel},
+    };
+
+    fn emulated_connections() -> Vec<Arc<EmulatedConnection>> {
+        DeviceModel::VARIANTS
+            .iter()
+            .filter(|model| **model != DeviceModel::A3029)
+            .map(|model| {
+                Arc::new(
+                    EmulatedConnection::new(*model, MacAddr6::nil())
+                        .unwrap_or_else(|| panic!("{model} should be emulated")),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn it_is_seen_as_the_emulated_model() {
+        for connection in emulated_connections() {
+            let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+                .await
+                .unwrap();
+            let model = connection.model();
+            let state = device.state().await;
+            assert_eq!(connection.state().await, state, "{model}");
+            assert_eq!(
+                Some(model.as_ref()[1..].to_owned()),
+                state
+                    .serial_number
+                    .map(|serial_number| serial_number.model_number().to_owned()),
+                "{model}",
+            );
+        }
+    }
+
+    /// Responses to state requests are also handled as unprompted state updates once the device's
+    /// packet handler gets to them. Time only advances while paused once every task is idle, so
+    /// this waits for that to happen.
+    async fn wait_for_packet_handler() {
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_keeps_changed_settings() {
+        for connection in emulated_connections() {
+            let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+                .await
+                .unwrap();
+            device.set_verify_writes(true);
+            let model = connection.model();
+            wait_for_packet_handler().await;
+            let state = device.state().await;
+
+            let equalizer_configuration = EqualizerConfiguration::new_custom_profile(
+                VolumeAdjustments::new(
+                    (0..state.device_features.num_equalizer_bands).map(|i| i as f64 / 2.0),
+                )
+                .unwrap(),
+            );
+            device
+                .set_equalizer_configuration(equalizer_configuration)
+                .await
+                .unwrap_or_else(|err| panic!("{model}: {err:?}"));
+            wait_for_packet_handler().await;
+            if let Some(sound_modes) = state.sound_modes {
+                device
+                    .set_sound_modes(SoundModes {
+                        ambient_sound_mode: AmbientSoundMode::Transparency,
+                        transparency_mode: TransparencyMode::VocalMode,
+                        ..sound_modes
+                    })
+                    .await
+                    .unwrap_or_else(|err| panic!("{model}: {err:?}"));
+                wait_for_packet_handler().await;
+            }
+            if let Some(sound_modes) = state.sound_modes_type_two {
+                device
+                    .set_sound_modes_type_two(SoundModesTypeTwo {
+                        ambient_sound_mode: AmbientSoundMode::Transparency,
+                        transparency_mode: TransparencyMode::VocalMode,
+                        ..sound_modes
+                    })
+                    .await
+                    .unwrap_or_else(|err| panic!("{model}: {err:?}"));
+                wait_for_packet_handler().await;
+            }
+            // Only reported by its own packet, so it must survive the full state update that
+            // verification requests afterwards
+            if state.device_features.has_voice_prompt {
+                device
+                    .set_voice_prompt(VoicePrompt::Chinese)
+                    .await
+                    .unwrap_or_else(|err| panic!("{model}: {err:?}"));
+                wait_for_packet_handler().await;
+            }
+
+            assert_eq!(connection.state().await, device.state().await, "{model}");
+        }
+    }
+
+    #[tokio::test]
+    async fn it_sends_updates_on_its_own() {
+        for connection in emulated_connections() {
+            let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+                .await
+                .unwrap();
+            let mut state_receiver = device.subscribe_to_state_updates().await;
+            let battery = SingleBattery {
+                is_charging: IsBatteryCharging::Yes,
+                level: BatteryLevel(2),
+            };
+            let battery = match device.state().await.battery {
+                Battery::SingleBattery(_) => Battery::SingleBattery(battery),
+                Battery::DualBattery(mut dual_battery) => {
+                    dual_battery.left = battery;
+                    Battery::DualBattery(dual_battery)
+                }
+            };
+
+            connection.set_battery(battery).await;
+            state_receiver
+                .wait_for(|state| state.battery == battery)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_only_acknowledges_commands_for_features_the_model_has() {
+        for (model, is_acknowledged) in [(DeviceModel::A3027, false), (DeviceModel::A3936, true)] {
+            let connection = EmulatedConnection::new(model, MacAddr6::nil()).unwrap();
+            let mut inbound_packets = connection.inbound_packets_channel().await.unwrap();
+            let game_mode = connection.state().await.game_mode;
+
+            connection
+                .write_with_response(&SetGameModePacket { is_enabled: true }.bytes())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+
+            assert_eq!(
+                is_acknowledged,
+                inbound_packets.try_recv().is_ok(),
+                "{model}"
+            );
+            assert_eq!(
+                if is_acknowledged {
+                    Some(true)
+                } else {
+                    game_mode
+                },
+                connection.state().await.game_mode,
+                "{model}",
+            );
+        }
+    }
+
+    /// Changes the battery of the emulated device right before the next state request reaches it
+    #[derive(Debug)]
+    struct BatteryChangingConnection {
+        inner: Arc<EmulatedConnection>,
+        battery: Mutex<Option<Battery>>,
+    }
+
+    impl BatteryChangingConnection {
+        async fn handle_write(&self, data: &[u8]) {
+            if data == RequestStatePacket::new().bytes() {
+                if let Some(battery) = self.battery.lock().await.take() {
+                    self.inner.set_battery(battery).await;
+                }
+            }
+        }
+    }
+
+    impl Connection for BatteryChangingConnection {
+        async fn name(&self) -> crate::Result<String> {
+            Ok(self.inner.name())
+        }
+
+        async fn mac_address(&self) -> crate::Result<MacAddr6> {
+            self.inner.mac_address().await
+        }
+
+        fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
+            self.inner.connection_status()
+        }
+
+        async fn write_with_response(&self, data: &[u8]) -> crate::Result<()> {
+            self.handle_write(data).await;
+            self.inner.write_with_response(data).await
+        }
+
+        async fn write_without_response(&self, data: &[u8]) -> crate::Result<()> {
+            self.handle_write(data).await;
+            self.inner.write_without_response(data).await
+        }
+
+        async fn inbound_packets_channel(&self) -> crate::Result<mpsc::Receiver<Vec<u8>>> {
+            self.inner.inbound_packets_channel().await
+        }
+
+        fn service_uuid(&self) -> Uuid {
+            self.inner.service_uuid()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_verifies_only_the_changed_setting() {
+        let connection = Arc::new(BatteryChangingConnection {
+            inner: Arc::new(EmulatedConnection::new(DeviceModel::A3951, MacAddr6::nil()).unwrap()),
+            battery: Mutex::new(None),
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+            .await
+            .unwrap();
+        device.set_verify_writes(true);
+        wait_for_packet_handler().await;
+        let state = device.state().await;
+        let Battery::DualBattery(mut battery) = state.battery else {
+            panic!("A3951 should have two batteries");
+        };
+        battery.left.level = BatteryLevel(1);
+        let battery = Battery::DualBattery(battery);
+        *connection.battery.lock().await = Some(battery);
+
+        let sound_modes = SoundModes {
+            ambient_sound_mode: AmbientSoundMode::Transparency,
+            ..state.sound_modes.unwrap()
+        };
+        device.set_sound_modes(sound_modes).await.unwrap();
+
+        let state = device.state().await;
+        assert_eq!(Some(sound_modes), state.sound_modes);
+        assert_eq!(battery, state.battery);
+    }
+}
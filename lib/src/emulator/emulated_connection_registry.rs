@@ -0,0 +1,92 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use macaddr::MacAddr6;
+use strum::VariantArray;
+
+use crate::{
+    api::connection::{ConnectionDescriptor, ConnectionRegistry, GenericConnectionDescriptor},
+    soundcore_device::device_model::DeviceModel,
+};
+
+use super::EmulatedConnection;
+
+#[derive(Debug)]
+pub struct EmulatedConnectionRegistry {
+    connections:
+        HashMap<GenericConnectionDescriptor, Arc<<Self as ConnectionRegistry>::ConnectionType>>,
+}
+
+impl EmulatedConnectionRegistry {
+    pub fn new(connections: impl IntoIterator<Item = EmulatedConnection>) -> Self {
+        Self {
+            connections: connections
+                .into_iter()
+                .map(|connection| {
+                    (
+                        GenericConnectionDescriptor::new(connection.name(), connection.mac_address),
+                        Arc::new(connection),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// One emulated device for each model that can be emulated, each with its own mac address
+    pub fn with_all_models() -> Self {
+        Self::new(
+            DeviceModel::VARIANTS
+                .iter()
+                .zip(1..)
+                .filter_map(|(model, index)| {
+                    EmulatedConnection::new(*model, MacAddr6::new(0x02, 0, 0, 0, 0, index))
+                }),
+        )
+    }
+}
+
+impl ConnectionRegistry for EmulatedConnectionRegistry {
+    type ConnectionType = EmulatedConnection;
+    type DescriptorType = GenericConnectionDescriptor;
+
+    async fn connection_descriptors(&self) -> crate::Result<HashSet<Self::DescriptorType>> {
+        Ok(self.connections.keys().cloned().collect())
+    }
+
+    async fn connection(
+        &self,
+        mac_address: MacAddr6,
+    ) -> crate::Result<Option<Arc<Self::ConnectionType>>> {
+        Ok(self
+            .connections
+            .iter()
+            .find(|(descriptor, _connection)| descriptor.mac_address() == mac_address)
+            .map(|(_descriptor, connection)| connection)
+            .cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::connection::{ConnectionDescriptor, ConnectionRegistry};
+
+    use super::EmulatedConnectionRegistry;
+
+    #[tokio::test]
+    async fn it_has_a_connection_for_each_emulated_model() {
+        let registry = EmulatedConnectionRegistry::with_all_models();
+        let descriptors = registry.connection_descriptors().await.unwrap();
+        // Everything but the A3029, which has no known state update packet
+        assert_eq!(14, descriptors.len());
+        for descriptor in descriptors {
+            let connection = registry
+                .connection(descriptor.mac_address())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(descriptor.name(), connection.name());
+        }
+    }
+}
@@ -0,0 +1,417 @@
+use crate::{
+    devices::{
+        a3027::packets::A3027StateUpdatePacket,
+        a3028::packets::{A3028StateUpdatePacket, ExtraFields},
+        a3031::packets::A3031StateUpdatePacket,
+        a3033::packets::A3033StateUpdatePacket,
+        a3926::packets::A3926StateUpdatePacket,
+        a3930::packets::A3930StateUpdatePacket,
+        a3931::packets::A3931StateUpdatePacket,
+        a3933::packets::inbound::A3933StateUpdatePacket,
+        a3936::{
+            packets::A3936StateUpdatePacket,
+            structures::{A3936InternalMultiButtonConfiguration, A3936TwsButtonAction},
+        },
+        a3945::packets::A3945StateUpdatePacket,
+        a3951::packets::A3951StateUpdatePacket,
+        standard::{
+            packets::inbound::InboundPacketBytesExt,
+            quirks::TwoExtraEqBandsValues,
+            state::DeviceState,
+            structures::{
+                AgeRange, BasicHearId, Battery, BatteryLevel, ButtonAction, ButtonConfiguration,
+                CustomHearId, DualBattery, DualFirmwareVersion, Gender, HearId, HearIdMusicType,
+                HearIdType, HostDevice, InternalMultiButtonConfiguration, MultiButtonConfiguration,
+                NoTwsButtonConfiguration, SerialNumber, SingleBattery,
+                StereoEqualizerConfiguration, StereoVolumeAdjustments, TwsButtonConfiguration,
+                TwsStatus, VolumeAdjustments,
+            },
+        },
+    },
+    soundcore_device::device_model::DeviceModel,
+};
+
+const DEFAULT_BUTTON_CONFIGURATION: MultiButtonConfiguration = MultiButtonConfiguration {
+    left_single_click: ButtonConfiguration {
+        action: ButtonAction::PlayPause,
+        is_enabled: true,
+    },
+    left_double_click: ButtonConfiguration {
+        action: ButtonAction::PreviousSong,
+        is_enabled: true,
+    },
+    left_long_press: ButtonConfiguration {
+        action: ButtonAction::AmbientSoundMode,
+        is_enabled: true,
+    },
+    right_single_click: ButtonConfiguration {
+        action: ButtonAction::PlayPause,
+        is_enabled: true,
+    },
+    right_double_click: ButtonConfiguration {
+        action: ButtonAction::NextSong,
+        is_enabled: true,
+    },
+    right_long_press: ButtonConfiguration {
+        action: ButtonAction::VoiceAssistant,
+        is_enabled: true,
+    },
+};
+
+/// Builds the state update packet that `model` sends for `state`. Fields that aren't in `state`
+/// fall back to what a device that was just reset would send. Returns `None` for models without a
+/// known packet layout.
+pub(crate) fn state_update_packet_bytes(
+    model: DeviceModel,
+    serial_number: &SerialNumber,
+    firmware_version: DualFirmwareVersion,
+    state: &DeviceState,
+) -> Option<Vec<u8>> {
+    let bytes = match model {
+        DeviceModel::A3027 | DeviceModel::A3030 => A3027StateUpdatePacket {
+            battery: single_battery(state),
+            equalizer_configuration: state.equalizer_configuration.to_owned(),
+            gender: gender(state),
+            age_range: age_range(state),
+            hear_id: basic_hear_id(state),
+            sound_modes: state.sound_modes.unwrap_or_default(),
+            firmware_version: firmware_version.left,
+            serial_number: serial_number.to_owned(),
+            wear_detection: state.wear_detection.unwrap_or_default(),
+            touch_func: false,
+        }
+        .bytes(),
+        DeviceModel::A3028 => A3028StateUpdatePacket {
+            battery: single_battery(state),
+            equalizer_configuration: state.equalizer_configuration.to_owned(),
+            gender: gender(state),
+            age_range: age_range(state),
+            hear_id: basic_hear_id(state),
+            sound_modes: state.sound_modes.unwrap_or_default(),
+            firmware_version: firmware_version.left,
+            serial_number: serial_number.to_owned(),
+            // Without the extra fields, the packet would be parsed as an A3027 packet
+            extra_fields: Some(ExtraFields::default()),
+        }
+        .bytes(),
+        DeviceModel::A3029 => return None,
+        DeviceModel::A3031 => {
+            let (auto_power_off_on, auto_power_off_on_index) = auto_power_off(state);
+            A3031StateUpdatePacket {
+                tws_status: tws_status(state),
+                battery: dual_battery(state),
+                equalizer_configuration: stereo_equalizer_configuration(state),
+                button_configuration: button_configuration(state),
+                sound_modes: state.sound_modes.unwrap_or_default(),
+                side_tone: false,
+                touch_tone: state.touch_tone.unwrap_or_default(),
+                auto_power_off_on,
+                auto_power_off_on_index,
+            }
+            .bytes()
+        }
+        DeviceModel::A3033 => A3033StateUpdatePacket {
+            battery: single_battery(state),
+            equalizer_configuration: state.equalizer_configuration.to_owned(),
+            firmware_version: firmware_version.left,
+            serial_number: serial_number.to_owned(),
+            wear_detection: state.wear_detection.unwrap_or_default(),
+        }
+        .bytes(),
+        DeviceModel::A3926 => A3926StateUpdatePacket {
+            tws_status: tws_status(state),
+            battery: dual_battery(state),
+            equalizer_configuration: stereo_equalizer_configuration(state),
+            gender: gender(state),
+            age_range: age_range(state),
+            hear_id: basic_hear_id(state),
+            button_configuration: button_configuration(state),
+        }
+        .bytes(),
+        DeviceModel::A3930 => A3930StateUpdatePacket {
+            tws_status: tws_status(state),
+            battery: dual_battery(state),
+            equalizer_configuration: stereo_equalizer_configuration(state),
+            gender: gender(state),
+            age_range: age_range(state),
+            custom_hear_id: custom_hear_id(state, 8),
+            button_configuration: button_configuration(state),
+            sound_modes: state.sound_modes.unwrap_or_default(),
+            side_tone: false,
+            hear_id_eq_index: None,
+        }
+        .bytes(),
+        DeviceModel::A3931 | DeviceModel::A3935 => {
+            let (auto_power_off_on, auto_power_off_index) = auto_power_off(state);
+            A3931StateUpdatePacket {
+                tws_status: tws_status(state),
+                battery: dual_battery(state),
+                equalizer_configuration: stereo_equalizer_configuration(state),
+                button_configuration: button_configuration(state),
+                sound_modes: state.sound_modes.unwrap_or_default(),
+                side_tone: false,
+                touch_tone: state.touch_tone.unwrap_or_default(),
+                auto_power_off_on,
+                auto_power_off_index,
+            }
+            .bytes()
+        }
+        DeviceModel::A3933 | DeviceModel::A3939 => {
+            let equalizer_configuration =
+                stereo_equalizer_configuration_with_two_extra_bands(state);
+            A3933StateUpdatePacket {
+                tws_status: tws_status(state),
+                battery: dual_battery(state),
+                left_firmware: firmware_version.left,
+                right_firmware: firmware_version.right,
+                serial_number: serial_number.to_owned(),
+                left_equalizer_configuration: equalizer_configuration.left,
+                right_equalizer_configuration: equalizer_configuration.right,
+                age_range: age_range(state),
+                hear_id: Some(custom_hear_id(state, 10)),
+                button_configuration: button_configuration(state),
+                ambient_sound_mode_cycle: state.ambient_sound_mode_cycle.unwrap_or_default(),
+                sound_modes: state.sound_modes.unwrap_or_default(),
+                touch_tone_switch: Some(state.touch_tone.unwrap_or_default()),
+                wear_detection_switch: Some(state.wear_detection.unwrap_or_default()),
+                game_mode_switch: Some(state.game_mode.unwrap_or_default()),
+                charging_case_battery_level: Some(charging_case_battery_level(state)),
+                device_color: 0,
+                wind_noise_detection: false,
+            }
+            .bytes()
+        }
+        DeviceModel::A3936 => {
+            let equalizer_configuration =
+                stereo_equalizer_configuration_with_two_extra_bands(state);
+            let (auto_power_off_switch, auto_power_off_index) = auto_power_off(state);
+            A3936StateUpdatePacket {
+                tws_status: tws_status(state),
+                battery: dual_battery(state),
+                left_firmware: firmware_version.left,
+                right_firmware: firmware_version.right,
+                serial_number: serial_number.to_owned(),
+                equalizer_configuration,
+                age_range: age_range(state),
+                custom_hear_id: custom_hear_id(state, 10),
+                sound_modes: state.sound_modes_type_two.unwrap_or_default(),
+                ambient_sound_mode_cycle: state.ambient_sound_mode_cycle.unwrap_or_default(),
+                button_configuration: a3936_button_configuration(state),
+                touch_tone: state.touch_tone.unwrap_or_default(),
+                charging_case_battery: charging_case_battery_level(state),
+                color: 0,
+                ldac: state.ldac.unwrap_or_default(),
+                supports_two_cnn_switch: false,
+                auto_power_off_switch,
+                auto_power_off_index,
+                game_mode_switch: state.game_mode.unwrap_or_default(),
+            }
+            .bytes()
+        }
+        DeviceModel::A3945 => {
+            let equalizer_configuration =
+                stereo_equalizer_configuration_with_two_extra_bands(state);
+            A3945StateUpdatePacket {
+                tws_status: tws_status(state),
+                battery: dual_battery(state),
+                left_firmware: firmware_version.left,
+                right_firmware: firmware_version.right,
+                serial_number: serial_number.to_owned(),
+                left_equalizer_configuration: equalizer_configuration.left,
+                right_equalizer_configuration: equalizer_configuration.right,
+                button_configuration: button_configuration(state),
+                touch_tone_switch: state.touch_tone.unwrap_or_default(),
+                wear_detection_switch: state.wear_detection.unwrap_or_default(),
+                game_mode_switch: state.game_mode.unwrap_or_default(),
+                charging_case_battery_level: charging_case_battery_level(state),
+                bass_up_switch: false,
+                device_color: 0,
+            }
+            .bytes()
+        }
+        DeviceModel::A3951 => A3951StateUpdatePacket {
+            tws_status: tws_status(state),
+            battery: dual_battery(state),
+            equalizer_configuration: stereo_equalizer_configuration(state),
+            gender: gender(state),
+            age_range: age_range(state),
+            custom_hear_id: custom_hear_id(state, 8),
+            button_configuration: button_configuration(state),
+            sound_modes: state.sound_modes.unwrap_or_default(),
+            side_tone: false,
+            wear_detection: state.wear_detection.unwrap_or_default(),
+            touch_tone: state.touch_tone.unwrap_or_default(),
+            // Without the optional fields, the packet would be parsed as an A3930 packet
+            hear_id_eq_preset: Some(0),
+            supports_new_battery: false,
+            left_new_battery: 0,
+            right_new_battery: 0,
+        }
+        .bytes(),
+    };
+    Some(bytes)
+}
+
+fn single_battery(state: &DeviceState) -> SingleBattery {
+    match state.battery {
+        Battery::SingleBattery(battery) => battery,
+        Battery::DualBattery(battery) => battery.left,
+    }
+}
+
+fn dual_battery(state: &DeviceState) -> DualBattery {
+    match state.battery {
+        Battery::SingleBattery(battery) => DualBattery {
+            left: battery,
+            right: battery,
+        },
+        Battery::DualBattery(battery) => battery,
+    }
+}
+
+fn charging_case_battery_level(state: &DeviceState) -> BatteryLevel {
+    state.charging_case_battery_level.unwrap_or(BatteryLevel(5))
+}
+
+fn tws_status(state: &DeviceState) -> TwsStatus {
+    state.tws_status.unwrap_or(TwsStatus {
+        is_connected: true,
+        host_device: HostDevice::Left,
+    })
+}
+
+fn gender(state: &DeviceState) -> Gender {
+    state.gender.unwrap_or(Gender(0))
+}
+
+/// A device that hasn't taken a hear id test doesn't have an age range.
+fn age_range(state: &DeviceState) -> AgeRange {
+    state.age_range.unwrap_or(AgeRange(u8::MAX))
+}
+
+fn stereo_equalizer_configuration(state: &DeviceState) -> StereoEqualizerConfiguration {
+    state
+        .stereo_equalizer_configuration()
+        .unwrap_or_else(|| StereoEqualizerConfiguration {
+            left: state.equalizer_configuration.to_owned(),
+            right: state.equalizer_configuration.to_owned(),
+        })
+}
+
+/// Models with two extra bands always report all 10, so any that are missing are flat.
+fn stereo_equalizer_configuration_with_two_extra_bands(
+    state: &DeviceState,
+) -> StereoEqualizerConfiguration {
+    let equalizer_configuration = stereo_equalizer_configuration(state);
+    if equalizer_configuration
+        .two_extra_eq_bands_values()
+        .is_some()
+    {
+        return equalizer_configuration;
+    }
+    let flat = VolumeAdjustments::signed_adjustment_to_packet_byte(0.0);
+    equalizer_configuration.with_two_extra_eq_bands_values(TwoExtraEqBandsValues {
+        left_extra_1: flat,
+        left_extra_2: flat,
+        right_extra_1: flat,
+        right_extra_2: flat,
+    })
+}
+
+fn basic_hear_id(state: &DeviceState) -> BasicHearId {
+    match &state.hear_id {
+        Some(HearId::Basic(hear_id)) => hear_id.to_owned(),
+        Some(HearId::Custom(hear_id)) => BasicHearId {
+            is_enabled: hear_id.is_enabled,
+            volume_adjustments: hear_id.volume_adjustments.to_owned(),
+            time: hear_id.time,
+        },
+        None => BasicHearId {
+            is_enabled: false,
+            volume_adjustments: StereoVolumeAdjustments::default(),
+            time: 0,
+        },
+    }
+}
+
+fn custom_hear_id(state: &DeviceState, num_bands: usize) -> CustomHearId {
+    match &state.hear_id {
+        Some(HearId::Custom(hear_id)) => hear_id.to_owned(),
+        Some(HearId::Basic(hear_id)) => CustomHearId {
+            is_enabled: hear_id.is_enabled,
+            volume_adjustments: hear_id.volume_adjustments.to_owned(),
+            time: hear_id.time,
+            hear_id_type: HearIdType::default(),
+            hear_id_music_type: HearIdMusicType::default(),
+            custom_volume_adjustments: None,
+        },
+        None => {
+            let flat = VolumeAdjustments::new(vec![0.0; num_bands])
+                .expect("both 8 and 10 bands are valid");
+            CustomHearId {
+                is_enabled: false,
+                volume_adjustments: StereoVolumeAdjustments {
+                    left: flat.to_owned(),
+                    right: flat,
+                },
+                time: 0,
+                hear_id_type: HearIdType::default(),
+                hear_id_music_type: HearIdMusicType::default(),
+                custom_volume_adjustments: None,
+            }
+        }
+    }
+}
+
+/// Devices keep separate actions for when both earbuds are connected and when only one is, but
+/// [`MultiButtonConfiguration`] only has the active ones, so both are set to the same action.
+fn button_configuration(state: &DeviceState) -> InternalMultiButtonConfiguration {
+    let buttons = state
+        .button_configuration
+        .unwrap_or(DEFAULT_BUTTON_CONFIGURATION);
+    let is_tws_connected = tws_status(state).is_connected;
+    let tws = |button: ButtonConfiguration| TwsButtonConfiguration {
+        tws_connected_action: button.action,
+        tws_disconnected_action: button.action,
+        disconnected_switch: !is_tws_connected,
+    };
+    let no_tws = |button: ButtonConfiguration| NoTwsButtonConfiguration {
+        action: button.action,
+        is_enabled: button.is_enabled,
+    };
+    InternalMultiButtonConfiguration {
+        left_single_click: no_tws(buttons.left_single_click),
+        left_double_click: tws(buttons.left_double_click),
+        left_long_press: tws(buttons.left_long_press),
+        right_single_click: no_tws(buttons.right_single_click),
+        right_double_click: tws(buttons.right_double_click),
+        right_long_press: tws(buttons.right_long_press),
+    }
+}
+
+fn a3936_button_configuration(state: &DeviceState) -> A3936InternalMultiButtonConfiguration {
+    let buttons = state
+        .button_configuration
+        .unwrap_or(DEFAULT_BUTTON_CONFIGURATION);
+    let action = |button: ButtonConfiguration| A3936TwsButtonAction {
+        tws_connected_action: button.action,
+        tws_disconnected_action: button.action,
+        tws_connected_is_enabled: button.is_enabled,
+        tws_disconnected_is_enabled: button.is_enabled,
+    };
+    A3936InternalMultiButtonConfiguration {
+        left_single_click: action(buttons.left_single_click),
+        right_single_click: action(buttons.right_single_click),
+        left_double_click: action(buttons.left_double_click),
+        right_double_click: action(buttons.right_double_click),
+        left_long_press: action(buttons.left_long_press),
+        right_long_press: action(buttons.right_long_press),
+    }
+}
+
+fn auto_power_off(state: &DeviceState) -> (bool, u8) {
+    state
+        .auto_power_off
+        .map(|auto_power_off| (auto_power_off.is_enabled, auto_power_off.index()))
+        .unwrap_or_default()
+}
@@ -3,6 +3,8 @@ use std::panic::Location;
 use macaddr::MacAddr6;
 use uuid::Uuid;
 
+use crate::devices::standard::state::DeviceState;
+
 type InnerError = Box<dyn std::error::Error + Send + Sync>;
 
 #[derive(thiserror::Error, Debug)]
@@ -42,6 +44,26 @@ pub enum Error {
     #[error("timed out: {action}")]
     TimedOut { action: &'static str },
 
+    #[error(
+        "failed to apply {setting} (change {index}); rollback {}: {source}",
+        if *rolled_back { "succeeded" } else { "failed" },
+    )]
+    ApplyFailed {
+        /// Position of the failed change in the batch
+        index: usize,
+        setting: &'static str,
+        /// Whether the failed change and the ones before it were all reverted. If some of them
+        /// couldn't be, the rest were still reverted.
+        rolled_back: bool,
+        source: Box<Error>,
+    },
+
+    #[error("device state does not match what was set: expected {expected:?}, got {actual:?}")]
+    VerificationFailed {
+        expected: Box<DeviceState>,
+        actual: Box<DeviceState>,
+    },
+
     #[error("parse error: {message:?}")]
     ParseError { message: String },
 
@@ -20,8 +20,14 @@ impl Futures for TokioFutures {
         TokioJoinHandle(join_handle)
     }
 
-    fn spawn_local(_future: impl Future + 'static) -> Self::JoinHandleType {
-        unimplemented!("TokioFutures::spawn_local")
+    /// # Panics
+    ///
+    /// Panics if called from outside of a [`tokio::task::LocalSet`].
+    fn spawn_local(future: impl Future + 'static) -> Self::JoinHandleType {
+        let join_handle = tokio::task::spawn_local(async move {
+            future.await;
+        });
+        TokioJoinHandle(join_handle)
     }
 
     async fn sleep(duration: Duration) {
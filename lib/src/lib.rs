@@ -5,10 +5,13 @@
 // This crate should not be used outside of this git repository, so breaking api changes are fine.
 #![allow(clippy::type_complexity, async_fn_in_trait)]
 pub mod api;
+pub mod capture;
 pub mod demo;
 pub mod device_profile;
 pub mod device_utils;
 pub mod devices;
+pub mod dissector;
+pub mod emulator;
 mod error;
 pub mod futures;
 pub mod soundcore_device;
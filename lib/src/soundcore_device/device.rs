@@ -1,11 +1,16 @@
 pub(crate) mod device_implementation;
 mod multi_queue;
 mod packet;
+mod packet_framer;
 mod packet_io_controller;
+mod reconnect_policy;
+mod retry_policy;
 pub(crate) mod soundcore_command;
 mod soundcore_device;
 mod soundcore_device_registry;
 
 pub(crate) use packet::*;
+pub use reconnect_policy::*;
+pub use retry_policy::*;
 pub use soundcore_device::*;
 pub use soundcore_device_registry::*;
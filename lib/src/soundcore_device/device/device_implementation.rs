@@ -3,8 +3,9 @@ use std::collections::HashMap;
 use crate::devices::standard::{
     state::DeviceState,
     structures::{
-        AmbientSoundModeCycle, Command, EqualizerConfiguration, HearId, MultiButtonConfiguration,
-        SoundModes, SoundModesTypeTwo,
+        AmbientSoundModeCycle, AutoPowerOff, Command, EqualizerConfiguration, HearId,
+        MultiButtonConfiguration, SoundModes, SoundModesTypeTwo, StereoEqualizerConfiguration,
+        VoicePrompt,
     },
 };
 
@@ -41,6 +42,12 @@ pub trait DeviceImplementation {
         equalizer_configuration: EqualizerConfiguration,
     ) -> crate::Result<CommandResponse>;
 
+    fn set_stereo_equalizer_configuration(
+        &self,
+        state: DeviceState,
+        equalizer_configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<CommandResponse>;
+
     fn set_hear_id(&self, state: DeviceState, hear_id: HearId) -> crate::Result<CommandResponse>;
 
     fn set_multi_button_configuration(
@@ -48,4 +55,33 @@ pub trait DeviceImplementation {
         state: DeviceState,
         button_configuration: MultiButtonConfiguration,
     ) -> crate::Result<CommandResponse>;
+
+    fn set_wear_detection(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse>;
+
+    fn set_touch_tone(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<CommandResponse>;
+
+    fn set_auto_power_off(
+        &self,
+        state: DeviceState,
+        auto_power_off: AutoPowerOff,
+    ) -> crate::Result<CommandResponse>;
+
+    fn set_game_mode(&self, state: DeviceState, is_enabled: bool)
+        -> crate::Result<CommandResponse>;
+
+    fn set_ldac(&self, state: DeviceState, is_enabled: bool) -> crate::Result<CommandResponse>;
+
+    fn set_voice_prompt(
+        &self,
+        state: DeviceState,
+        voice_prompt: VoicePrompt,
+    ) -> crate::Result<CommandResponse>;
 }
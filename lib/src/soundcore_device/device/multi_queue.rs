@@ -56,6 +56,17 @@ impl<K: Hash + Eq, V> MultiQueue<K, V> {
         }
         false
     }
+
+    /// Takes `handle` out of the queue for `key` without it receiving a value, for when nothing
+    /// will ever be popped for it. Unlike [`MultiQueueHandle::cancel`], the next value popped goes
+    /// to the one after it.
+    pub fn remove(&self, key: &K, handle: &MultiQueueHandle<V>) {
+        let mut queues = self.queues.lock().expect(LOCK_HELD_ERROR);
+        if let Some(queue) = queues.get_mut(key) {
+            queue.retain(|item| !Arc::ptr_eq(item, &handle.current));
+        }
+        handle.cancel();
+    }
 }
 
 pub struct MultiQueueHandle<T> {
@@ -120,4 +131,23 @@ mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn test_remove() {
+        let queues: MultiQueue<i8, i8> = MultiQueue::new();
+        let removed = queues.add(1);
+        let cancelled = queues.add(1);
+        let next = queues.add(1);
+
+        queues.remove(&1, &removed);
+        cancelled.cancel();
+        next.wait_for_start().await;
+        queues.pop(&1, Some(1));
+        queues.pop(&1, Some(2));
+
+        assert_eq!(None, removed.wait_for_end().await);
+        // A cancelled handle still takes its value when it arrives
+        assert_eq!(Some(1), cancelled.wait_for_end().await);
+        assert_eq!(Some(2), next.wait_for_end().await);
+    }
 }
@@ -0,0 +1,191 @@
+use crate::devices::standard::packets::checksum::calculate_checksum;
+
+const PREFIX: [u8; 2] = [0x09, 0xff];
+const HEADER_LENGTH: usize = 9;
+/// Comfortably more than the largest known packet, a state update of around 150 bytes, while still
+/// small enough that a false prefix with a garbage length doesn't hold up frames for long.
+const MAX_LENGTH: usize = 1024;
+
+/// Reassembles inbound frames from a byte stream. Transports are free to split a frame across
+/// several reads or to deliver several frames in one read, so bytes are buffered until the length
+/// field of the header says a frame is complete. Anything that doesn't look like the start of a
+/// frame is skipped until the next `09 ff` prefix, including prefixes with an implausible length or
+/// a wrong checksum, and prefixes still waiting for more bytes when a complete frame follows them.
+#[derive(Debug, Default)]
+pub(crate) struct PacketFramer {
+    buffer: Vec<u8>,
+}
+
+impl PacketFramer {
+    /// Buffers `bytes` and returns every frame that is now complete, in order.
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<Vec<u8>> {
+        self.buffer.extend_from_slice(bytes);
+        let mut frames = Vec::new();
+        loop {
+            self.skip_to_prefix();
+            let Some(length) = self.buffer.get(7..HEADER_LENGTH) else {
+                break;
+            };
+            let length = usize::from(u16::from_le_bytes([length[0], length[1]]));
+            if length <= HEADER_LENGTH {
+                self.skip_false_prefix("length is too short");
+                continue;
+            }
+            if length > MAX_LENGTH {
+                self.skip_false_prefix("length is too long");
+                continue;
+            }
+            if self.buffer.len() < length {
+                if self.has_complete_frame_after_start() {
+                    self.skip_false_prefix("a complete packet follows before its end");
+                    continue;
+                }
+                break;
+            }
+            if !Self::is_complete_frame(&self.buffer) {
+                self.skip_false_prefix("checksum does not match");
+                continue;
+            }
+            frames.push(self.buffer.drain(..length).collect());
+        }
+        frames
+    }
+
+    /// Whether `bytes` starts with a prefix, a plausible length, and a matching checksum.
+    fn is_complete_frame(bytes: &[u8]) -> bool {
+        let Some(length) = bytes.get(7..HEADER_LENGTH) else {
+            return false;
+        };
+        let length = usize::from(u16::from_le_bytes([length[0], length[1]]));
+        bytes.starts_with(&PREFIX)
+            && (HEADER_LENGTH + 1..=MAX_LENGTH).contains(&length)
+            && bytes.len() >= length
+            && bytes[length - 1] == calculate_checksum(&bytes[..length - 1])
+    }
+
+    /// Only offsets that start with a prefix are checked, since computing a checksum at every offset
+    /// on every push is quadratic in the length of a frame that arrives in small pieces.
+    fn has_complete_frame_after_start(&self) -> bool {
+        self.buffer
+            .windows(PREFIX.len())
+            .enumerate()
+            .skip(1)
+            .any(|(start, window)| {
+                window == PREFIX && Self::is_complete_frame(&self.buffer[start..])
+            })
+    }
+
+    fn skip_to_prefix(&mut self) {
+        let start = self
+            .buffer
+            .windows(PREFIX.len())
+            .position(|window| window == PREFIX)
+            // The last byte may be the first half of a prefix that hasn't fully arrived yet
+            .unwrap_or_else(|| match self.buffer.last() {
+                Some(&byte) if byte == PREFIX[0] => self.buffer.len() - 1,
+                _ => self.buffer.len(),
+            });
+        if start != 0 {
+            tracing::warn!(
+                "skipping {start} bytes that aren't part of a packet: {:?}",
+                &self.buffer[..start],
+            );
+            self.buffer.drain(..start);
+        }
+    }
+
+    fn skip_false_prefix(&mut self, reason: &str) {
+        tracing::warn!("discarding what looked like the start of a packet: {reason}");
+        self.buffer.drain(..1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TWS_STATUS: [u8; 12] = [
+        0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x02, 0x0c, 0x00, 0x01, 0x00, 0x19,
+    ];
+    const ACK: [u8; 10] = [0x09, 0xff, 0x00, 0x00, 0x01, 0x06, 0x81, 0x0a, 0x00, 0x9a];
+
+    #[test]
+    fn it_passes_through_whole_frames() {
+        let mut framer = PacketFramer::default();
+        assert_eq!(vec![TWS_STATUS.to_vec()], framer.push(&TWS_STATUS));
+        assert_eq!(vec![ACK.to_vec()], framer.push(&ACK));
+    }
+
+    #[test]
+    fn it_reassembles_fragmented_frames() {
+        let mut framer = PacketFramer::default();
+        assert!(framer.push(&TWS_STATUS[..1]).is_empty());
+        assert!(framer.push(&TWS_STATUS[1..8]).is_empty());
+        assert!(framer.push(&TWS_STATUS[8..11]).is_empty());
+        assert_eq!(vec![TWS_STATUS.to_vec()], framer.push(&TWS_STATUS[11..]));
+    }
+
+    #[test]
+    fn it_reassembles_a_frame_of_the_maximum_length_pushed_one_byte_at_a_time() {
+        let mut frame = vec![0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01];
+        frame.extend((MAX_LENGTH as u16).to_le_bytes());
+        frame.resize(MAX_LENGTH - 1, 0x09);
+        frame.push(calculate_checksum(&frame));
+
+        let mut framer = PacketFramer::default();
+        let (last, rest) = frame.split_last().unwrap();
+        for byte in rest {
+            assert!(framer.push(&[*byte]).is_empty());
+        }
+        assert_eq!(vec![frame.to_owned()], framer.push(&[*last]));
+    }
+
+    #[test]
+    fn it_splits_coalesced_frames() {
+        let mut framer = PacketFramer::default();
+        let mut bytes = TWS_STATUS.to_vec();
+        bytes.extend(ACK);
+        bytes.extend(&TWS_STATUS[..5]);
+        assert_eq!(vec![TWS_STATUS.to_vec(), ACK.to_vec()], framer.push(&bytes));
+        assert_eq!(vec![TWS_STATUS.to_vec()], framer.push(&TWS_STATUS[5..]));
+    }
+
+    #[test]
+    fn it_skips_garbage_between_frames() {
+        let mut framer = PacketFramer::default();
+        let mut bytes = vec![0x01, 0x09, 0x02];
+        bytes.extend(TWS_STATUS);
+        bytes.extend([0xff, 0x09]);
+        bytes.extend(ACK);
+        assert_eq!(vec![TWS_STATUS.to_vec(), ACK.to_vec()], framer.push(&bytes));
+    }
+
+    #[test]
+    fn it_resynchronizes_after_a_false_prefix() {
+        let mut framer = PacketFramer::default();
+        // Looks like a header, but the checksum at the position given by the length is wrong
+        let mut bytes = vec![0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01, 0x0a, 0x00, 0x00];
+        bytes.extend(ACK);
+        assert_eq!(vec![ACK.to_vec()], framer.push(&bytes));
+    }
+
+    #[test]
+    fn it_skips_false_prefixes_with_a_large_length() {
+        let mut framer = PacketFramer::default();
+        // Would take 65535 bytes to complete
+        let mut bytes = vec![0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01, 0xff, 0xff];
+        bytes.extend(ACK);
+        assert_eq!(vec![ACK.to_vec()], framer.push(&bytes));
+        assert_eq!(vec![TWS_STATUS.to_vec()], framer.push(&TWS_STATUS));
+    }
+
+    #[test]
+    fn it_skips_false_prefixes_waiting_for_more_bytes_than_follow() {
+        let mut framer = PacketFramer::default();
+        // Within the maximum length, but complete frames arrive before it would be complete
+        let mut bytes = vec![0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00, 0x01];
+        bytes.extend(ACK);
+        assert_eq!(vec![ACK.to_vec()], framer.push(&bytes));
+        assert_eq!(vec![TWS_STATUS.to_vec()], framer.push(&TWS_STATUS));
+    }
+}
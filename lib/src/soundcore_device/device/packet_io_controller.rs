@@ -1,4 +1,7 @@
-use std::{marker::PhantomData, sync::Arc, time::Duration};
+use std::{
+    marker::PhantomData,
+    sync::{self, Arc},
+};
 
 use nom::error::VerboseError;
 use tokio::{select, sync::mpsc};
@@ -9,12 +12,13 @@ use crate::{
     futures::{Futures, JoinHandle},
 };
 
-use super::{multi_queue::MultiQueue, Packet};
+use super::{multi_queue::MultiQueue, packet_framer::PacketFramer, Packet, RetryPolicy};
 
 pub struct PacketIOController<ConnectionType: Connection, FuturesType: Futures> {
     connection: Arc<ConnectionType>,
     packet_queues: Arc<MultiQueue<Command, Packet>>,
     handle: FuturesType::JoinHandleType,
+    retry_policy: sync::Mutex<RetryPolicy>,
     _futures: PhantomData<FuturesType>,
 }
 
@@ -31,6 +35,7 @@ impl<ConnectionType: Connection, FuturesType: Futures>
 {
     pub async fn new(
         connection: Arc<ConnectionType>,
+        retry_policy: RetryPolicy,
     ) -> crate::Result<(Self, mpsc::Receiver<Packet>)> {
         let packet_queues = Arc::new(MultiQueue::new());
         let incoming_receiver = connection.inbound_packets_channel().await?;
@@ -41,6 +46,7 @@ impl<ConnectionType: Connection, FuturesType: Futures>
                 connection,
                 packet_queues,
                 handle,
+                retry_policy: sync::Mutex::new(retry_policy),
                 _futures: PhantomData,
             },
             outgoing_receiver,
@@ -53,55 +59,97 @@ impl<ConnectionType: Connection, FuturesType: Futures>
     ) -> (FuturesType::JoinHandleType, mpsc::Receiver<Packet>) {
         let (outgoing_sender, outgoing_receiver) = mpsc::channel(100);
         let handle = FuturesType::spawn(async move {
+            let mut framer = PacketFramer::default();
             while let Some(bytes) = incoming_receiver.recv().await {
-                let (body, header) = match take_inbound_packet_header::<VerboseError<_>>(&bytes) {
-                    Ok(parsed) => parsed,
-                    Err(err) => {
-                        tracing::warn!("failed to parse packet: {err:?}");
-                        continue;
-                    }
-                };
-                let packet = Packet {
-                    command: header,
-                    body: body.to_vec(),
-                };
-                packet_queues.pop(&header, Some(packet.clone()));
-                match outgoing_sender
-                            .send(packet)
-                            .await
-                        {
-                            Ok(_) => (),
-                            Err(err) => tracing::debug!(
-                                "received packet that wasn't an ok, but the channel is closed, so it won't be forwarded: {err:?}"
-                            ),
+                for frame in framer.push(&bytes) {
+                    let (body, header) = match take_inbound_packet_header::<VerboseError<_>>(&frame)
+                    {
+                        Ok(parsed) => parsed,
+                        Err(err) => {
+                            tracing::warn!("failed to parse packet: {err:?}");
+                            continue;
                         }
+                    };
+                    let packet = Packet {
+                        command: header,
+                        body: body.to_vec(),
+                    };
+                    packet_queues.pop(&header, Some(packet.clone()));
+                    match outgoing_sender
+                                .send(packet)
+                                .await
+                            {
+                                Ok(_) => (),
+                                Err(err) => tracing::debug!(
+                                    "received packet that wasn't an ok, but the channel is closed, so it won't be forwarded: {err:?}"
+                                ),
+                            }
+                }
             }
         });
         (handle, outgoing_receiver)
     }
 
+    pub fn retry_policy(&self) -> RetryPolicy {
+        *self.retry_policy.lock().unwrap()
+    }
+
+    pub fn set_retry_policy(&self, retry_policy: RetryPolicy) {
+        *self.retry_policy.lock().unwrap() = retry_policy;
+    }
+
     pub async fn send(&self, packet: &Packet) -> crate::Result<Packet> {
-        let handle = self.packet_queues.add(packet.command().to_inbound());
-
-        handle.wait_for_start().await;
-
-        // retry
-        for i in 1..=3 {
-            self.connection.write_with_response(&packet.bytes()).await?;
-            let result = select! {
-                result = handle.wait_for_end() => result,
-                _ = FuturesType::sleep(Duration::from_millis(500 * i)) => None,
-            };
-            if let Some(response) = result {
-                return Ok(response);
-            }
-        }
+        self.send_with_retry_policy(packet, self.retry_policy())
+            .await
+    }
 
-        handle.cancel();
+    /// Like [`Self::send`], but overrides the controller's retry policy for this packet only.
+    pub async fn send_with_retry_policy(
+        &self,
+        packet: &Packet,
+        retry_policy: RetryPolicy,
+    ) -> crate::Result<Packet> {
+        let command = packet.command().to_inbound();
+        let handle = self.packet_queues.add(command);
 
-        Err(crate::Error::TimedOut {
-            action: "resending packet until ack received",
-        })
+        let mut was_written = false;
+        let attempts = async {
+            handle.wait_for_start().await;
+            for attempt in 1..=retry_policy.attempts.max(1) {
+                self.connection.write_with_response(&packet.bytes()).await?;
+                was_written = true;
+                let result = select! {
+                    result = handle.wait_for_end() => result,
+                    _ = FuturesType::sleep(retry_policy.attempt_timeout(attempt)) => None,
+                };
+                if let Some(response) = result {
+                    return Ok(response);
+                }
+            }
+            Err(crate::Error::TimedOut {
+                action: "resending packet until ack received",
+            })
+        };
+        let result = match retry_policy.deadline {
+            Some(deadline) => select! {
+                result = attempts => result,
+                _ = FuturesType::sleep(deadline) => Err(crate::Error::TimedOut {
+                    action: "waiting for ack before the retry deadline",
+                }),
+            },
+            None => attempts.await,
+        };
+
+        if result.is_err() {
+            if was_written {
+                // A response could still arrive late, so it should go to this packet rather than
+                // the next one with the same command
+                handle.cancel();
+            } else {
+                self.packet_queues.remove(&command, &handle);
+            }
+        }
+        result
     }
 }
 
@@ -116,6 +164,7 @@ mod tests {
             OutboundPacket, SetAmbientSoundModeCyclePacket, SetSoundModePacket,
         },
         futures::TokioFutures,
+        soundcore_device::device::Backoff,
         stub::connection::StubConnection,
     };
 
@@ -136,7 +185,7 @@ mod tests {
             connection.push_write_return(Ok(())).await;
         }
         let controller = Arc::new(
-            PacketIOController::<_, TokioFutures>::new(connection)
+            PacketIOController::<_, TokioFutures>::new(connection, RetryPolicy::default())
                 .await
                 .unwrap()
                 .0,
@@ -184,6 +233,156 @@ mod tests {
         assert!(handle2.is_finished());
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_policy_limits_attempts() {
+        let (connection, _sender) = create_test_connection().await;
+        for _ in 0..5 {
+            connection.push_write_return(Ok(())).await;
+        }
+        let (controller, _) = PacketIOController::<_, TokioFutures>::new(
+            connection.clone(),
+            RetryPolicy {
+                attempts: 2,
+                timeout: Duration::from_millis(100),
+                backoff: Backoff::Constant,
+                deadline: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let result = controller.send(&SetSoundModePacket::default().into()).await;
+        assert!(matches!(result, Err(crate::Error::TimedOut { .. })));
+        assert_eq!(3, connection.write_return_queue_length().await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_policy_can_be_overridden_per_call() {
+        let (connection, _sender) = create_test_connection().await;
+        for _ in 0..5 {
+            connection.push_write_return(Ok(())).await;
+        }
+        let (controller, _) =
+            PacketIOController::<_, TokioFutures>::new(connection.clone(), RetryPolicy::default())
+                .await
+                .unwrap();
+
+        let result = controller
+            .send_with_retry_policy(
+                &SetSoundModePacket::default().into(),
+                RetryPolicy::fail_fast(),
+            )
+            .await;
+        assert!(matches!(result, Err(crate::Error::TimedOut { .. })));
+        assert_eq!(4, connection.write_return_queue_length().await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_policy_deadline() {
+        let (connection, _sender) = create_test_connection().await;
+        for _ in 0..5 {
+            connection.push_write_return(Ok(())).await;
+        }
+        let (controller, _) = PacketIOController::<_, TokioFutures>::new(
+            connection.clone(),
+            RetryPolicy {
+                attempts: 5,
+                timeout: Duration::from_millis(100),
+                backoff: Backoff::Exponential,
+                deadline: Some(Duration::from_millis(250)),
+            },
+        )
+        .await
+        .unwrap();
+
+        let start = tokio::time::Instant::now();
+        let result = controller.send(&SetSoundModePacket::default().into()).await;
+        assert!(matches!(result, Err(crate::Error::TimedOut { .. })));
+        assert_eq!(Duration::from_millis(250), start.elapsed());
+        // 100ms, then 200ms, which is cut short by the deadline
+        assert_eq!(3, connection.write_return_queue_length().await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_policy_deadline_includes_time_spent_queued() {
+        let (connection, _sender) = create_test_connection().await;
+        for _ in 0..5 {
+            connection.push_write_return(Ok(())).await;
+        }
+        let (controller, _) =
+            PacketIOController::<_, TokioFutures>::new(connection.clone(), RetryPolicy::default())
+                .await
+                .unwrap();
+        let packet: Packet = SetSoundModePacket::default().into();
+
+        let start = tokio::time::Instant::now();
+        let queued = async {
+            let result = controller
+                .send_with_retry_policy(
+                    &packet,
+                    RetryPolicy {
+                        deadline: Some(Duration::from_millis(250)),
+                        ..Default::default()
+                    },
+                )
+                .await;
+            (result, start.elapsed())
+        };
+        // The first packet holds up the queue until all of its attempts time out
+        let (first_result, (queued_result, queued_elapsed)) =
+            tokio::join!(controller.send(&packet), queued);
+
+        assert!(matches!(first_result, Err(crate::Error::TimedOut { .. })));
+        assert!(matches!(queued_result, Err(crate::Error::TimedOut { .. })));
+        assert_eq!(Duration::from_millis(250), queued_elapsed);
+        // Only the first packet was written
+        assert_eq!(2, connection.write_return_queue_length().await);
+    }
+
+    #[tokio::test]
+    async fn test_fragmented_and_coalesced_responses() {
+        let (connection, sender) = create_test_connection().await;
+        for _ in 1..10 {
+            connection.push_write_return(Ok(())).await;
+        }
+        let (controller, mut receiver) =
+            PacketIOController::<_, TokioFutures>::new(connection, RetryPolicy::default())
+                .await
+                .unwrap();
+        let controller = Arc::new(controller);
+
+        let handle = tokio::spawn({
+            let controller = controller.clone();
+            async move {
+                controller
+                    .send(&SetSoundModePacket::default().into())
+                    .await
+                    .expect("should receive ack");
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        let ack = [0x09, 0xff, 0x00, 0x00, 0x01, 0x06, 0x81, 0x0a, 0x00, 0x9a];
+        let tws_status = [
+            0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x02, 0x0c, 0x00, 0x01, 0x00, 0x19,
+        ];
+        sender.send(ack[..4].to_vec()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        assert!(!handle.is_finished());
+
+        let mut rest = ack[4..].to_vec();
+        rest.extend(tws_status);
+        sender.send(rest).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        assert!(handle.is_finished());
+
+        assert_eq!(ack[..7], receiver.recv().await.unwrap().command.bytes()[..]);
+        assert_eq!(
+            tws_status[..7],
+            receiver.recv().await.unwrap().command.bytes()[..]
+        );
+    }
+
     #[tokio::test]
     async fn test_out_of_order_responses() {
         let (connection, sender) = create_test_connection().await;
@@ -191,7 +390,7 @@ mod tests {
             connection.push_write_return(Ok(())).await;
         }
         let controller = Arc::new(
-            PacketIOController::<_, TokioFutures>::new(connection)
+            PacketIOController::<_, TokioFutures>::new(connection, RetryPolicy::default())
                 .await
                 .unwrap()
                 .0,
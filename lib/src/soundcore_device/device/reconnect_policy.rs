@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use super::Backoff;
+
+/// How a reconnect supervisor waits between attempts to re-establish a lost connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReconnectPolicy {
+    /// How long to wait before the first attempt. Later attempts scale this according to
+    /// `backoff`, up to `max_delay`.
+    pub delay: Duration,
+    pub backoff: Backoff,
+    pub max_delay: Duration,
+    /// Gives up and reports [`ConnectionStatus::Disconnected`](crate::api::connection::ConnectionStatus::Disconnected)
+    /// after this many failed attempts. `None` keeps trying until the device is dropped.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_secs(1),
+            backoff: Backoff::Exponential,
+            max_delay: Duration::from_secs(30),
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// How long to wait before `attempt`, starting from 1.
+    pub fn attempt_delay(&self, attempt: u32) -> Duration {
+        self.backoff.scale(self.delay, attempt).min(self.max_delay)
+    }
+
+    pub fn should_attempt(&self, attempt: u32) -> bool {
+        self.max_attempts
+            .is_none_or(|max_attempts| attempt <= max_attempts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_is_capped() {
+        let policy = ReconnectPolicy {
+            delay: Duration::from_secs(1),
+            backoff: Backoff::Exponential,
+            max_delay: Duration::from_secs(5),
+            max_attempts: Some(5),
+        };
+        let delays = (1..=5)
+            .map(|attempt| policy.attempt_delay(attempt).as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 4, 5, 5], delays);
+        assert!(policy.should_attempt(5));
+        assert!(!policy.should_attempt(6));
+    }
+}
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+/// How long to wait for a device to acknowledge a packet, and how many times to resend it before
+/// giving up with [`Error::TimedOut`](crate::Error::TimedOut).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetryPolicy {
+    /// Number of times the packet is written, including the first. Values below 1 are treated as 1.
+    pub attempts: u32,
+    /// How long to wait for a response to the first attempt. Later attempts scale this according to
+    /// `backoff`.
+    pub timeout: Duration,
+    pub backoff: Backoff,
+    /// Gives up once this much time has passed since the packet was queued, even if attempts remain.
+    /// This includes time spent waiting for earlier packets with the same command.
+    pub deadline: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Backoff {
+    /// Every attempt waits for `timeout`.
+    Constant,
+    /// Attempt `n` waits for `timeout * n`.
+    #[default]
+    Linear,
+    /// Attempt `n` waits for `timeout * 2^(n - 1)`.
+    Exponential,
+}
+
+impl Backoff {
+    /// Scales `base` for `attempt`, starting from 1.
+    pub fn scale(self, base: Duration, attempt: u32) -> Duration {
+        let attempt = attempt.max(1);
+        match self {
+            Backoff::Constant => base,
+            Backoff::Linear => base.saturating_mul(attempt),
+            Backoff::Exponential => base.saturating_mul(2u32.saturating_pow(attempt - 1)),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            timeout: Duration::from_millis(500),
+            backoff: Backoff::Linear,
+            deadline: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A single attempt with a short timeout, for frontends that would rather report an error
+    /// quickly than keep the user waiting.
+    pub fn fail_fast() -> Self {
+        Self {
+            attempts: 1,
+            timeout: Duration::from_millis(500),
+            backoff: Backoff::Constant,
+            deadline: None,
+        }
+    }
+
+    /// How long to wait for a response to `attempt`, starting from 1.
+    pub fn attempt_timeout(&self, attempt: u32) -> Duration {
+        self.backoff.scale(self.timeout, attempt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_curves() {
+        let timeouts = |backoff| {
+            let policy = RetryPolicy {
+                attempts: 4,
+                timeout: Duration::from_millis(100),
+                backoff,
+                deadline: None,
+            };
+            (1..=policy.attempts)
+                .map(|attempt| policy.attempt_timeout(attempt).as_millis())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![100, 100, 100, 100], timeouts(Backoff::Constant));
+        assert_eq!(vec![100, 200, 300, 400], timeouts(Backoff::Linear));
+        assert_eq!(vec![100, 200, 400, 800], timeouts(Backoff::Exponential));
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let policy = RetryPolicy::default();
+        assert_eq!(3, policy.attempts);
+        assert_eq!(Duration::from_millis(1500), policy.attempt_timeout(3));
+    }
+}
@@ -1,27 +1,43 @@
-use std::{collections::HashMap, mem, sync::Arc};
+use std::{
+    collections::HashMap,
+    mem,
+    rc::Rc,
+    sync::{
+        self,
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use macaddr::MacAddr6;
-use tokio::sync::{mpsc, watch, Mutex};
-use tracing::{trace, warn};
+use tokio::sync::{broadcast, mpsc, watch, Mutex};
+use tracing::{debug, trace, warn};
 use uuid::Uuid;
 
 use crate::{
     api::{
         self,
-        connection::{Connection, ConnectionStatus},
+        connection::{Connection, ConnectionRegistry, ConnectionStatus},
+        device::{apply_with_rollback, ChangeTarget, SettingChange},
     },
     devices::standard::{
+        device_event::{event_channel, send_state, DeviceEvent},
         packets::{
             inbound::{
-                state_update_packet::StateUpdatePacket, FirmwareVersionUpdatePacket,
-                TryIntoInboundPacket,
+                state_update_packet::StateUpdatePacket, AnyInboundPacket,
+                FirmwareVersionUpdatePacket, TryIntoInboundPacket,
+            },
+            outbound::{
+                RequestBatteryChargingPacket, RequestBatteryLevelPacket,
+                RequestFirmwareVersionPacket, RequestStatePacket,
             },
-            outbound::{RequestFirmwareVersionPacket, RequestStatePacket},
         },
         state::DeviceState,
         structures::{
-            AmbientSoundModeCycle, Command, EqualizerConfiguration, HearId,
-            MultiButtonConfiguration, SoundModes, SoundModesTypeTwo,
+            AmbientSoundModeCycle, AutoPowerOff, Command, DualFirmwareVersion,
+            EqualizerConfiguration, HearId, MultiButtonConfiguration, SoundModes,
+            SoundModesTypeTwo, StereoEqualizerConfiguration, VoicePrompt, STATE_UPDATE,
         },
     },
     futures::{Futures, JoinHandle},
@@ -29,7 +45,7 @@ use crate::{
 
 use super::{
     device_implementation::DeviceImplementation, packet_io_controller::PacketIOController,
-    soundcore_command::CommandResponse, Packet,
+    soundcore_command::CommandResponse, Packet, ReconnectPolicy, RetryPolicy,
 };
 
 pub struct SoundcoreDevice<ConnectionType, FuturesType>
@@ -37,30 +53,124 @@ where
     ConnectionType: Connection,
     FuturesType: Futures,
 {
-    controller: PacketIOController<ConnectionType, FuturesType>,
-    connection: Arc<ConnectionType>,
+    session: Arc<sync::RwLock<Arc<Session<ConnectionType, FuturesType>>>>,
     state_sender: Arc<Mutex<watch::Sender<DeviceState>>>,
-    join_handle: FuturesType::JoinHandleType,
+    event_sender: broadcast::Sender<DeviceEvent>,
+    connection_status_sender: Arc<watch::Sender<ConnectionStatus>>,
+    connection_status_handle: sync::Mutex<FuturesType::JoinHandleType>,
+    battery_polling_handle: sync::Mutex<Option<FuturesType::JoinHandleType>>,
+    verify_writes: AtomicBool,
     implementation: Arc<dyn DeviceImplementation + Send + Sync>,
 }
 
+/// Everything that is tied to one particular connection, so that it can be swapped out as a whole
+/// when reconnecting.
+struct Session<ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    controller: Arc<PacketIOController<ConnectionType, FuturesType>>,
+    connection: Arc<ConnectionType>,
+    inbound_handle: FuturesType::JoinHandleType,
+}
+
+impl<ConnectionType, FuturesType> Drop for Session<ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    fn drop(&mut self) {
+        self.inbound_handle.abort();
+    }
+}
+
 impl<ConnectionType, FuturesType> SoundcoreDevice<ConnectionType, FuturesType>
 where
     ConnectionType: Connection,
     FuturesType: Futures,
 {
     pub async fn new(connection: Arc<ConnectionType>) -> crate::Result<Self> {
-        let (controller, receiver) = PacketIOController::new(connection.clone()).await?;
-        let (initial_state, implementation) = Self::fetch_initial_state(&controller).await?;
+        Self::new_with_retry_policy(connection, RetryPolicy::default()).await
+    }
 
-        let is_serial_number_missing = initial_state.serial_number.is_none();
-        let packet_handlers = implementation.packet_handlers();
+    pub async fn new_with_retry_policy(
+        connection: Arc<ConnectionType>,
+        retry_policy: RetryPolicy,
+    ) -> crate::Result<Self> {
+        let event_sender = event_channel();
+        let (session, state_sender, implementation) =
+            Self::start_session(connection, retry_policy, None, event_sender.to_owned()).await?;
+
+        let connection_status = session.connection.connection_status();
+        let (connection_status_sender, _) = watch::channel(*connection_status.borrow());
+        let connection_status_sender = Arc::new(connection_status_sender);
+        let connection_status_handle = Self::spawn_connection_status_forwarder(
+            connection_status,
+            connection_status_sender.to_owned(),
+        );
 
-        let (state_sender, _) = watch::channel(initial_state);
-        let state_sender = Arc::new(Mutex::new(state_sender));
+        Ok(Self {
+            session: Arc::new(sync::RwLock::new(Arc::new(session))),
+            state_sender,
+            event_sender,
+            connection_status_sender,
+            connection_status_handle: sync::Mutex::new(connection_status_handle),
+            battery_polling_handle: Default::default(),
+            verify_writes: AtomicBool::new(false),
+            implementation,
+        })
+    }
+
+    /// Fetches the state over `connection` and starts handling its inbound packets. When
+    /// reconnecting, the existing `state_sender` is passed in so that the new state reaches
+    /// current subscribers. Otherwise, a new one is created.
+    async fn start_session(
+        connection: Arc<ConnectionType>,
+        retry_policy: RetryPolicy,
+        state_sender: Option<Arc<Mutex<watch::Sender<DeviceState>>>>,
+        event_sender: broadcast::Sender<DeviceEvent>,
+    ) -> crate::Result<(
+        Session<ConnectionType, FuturesType>,
+        Arc<Mutex<watch::Sender<DeviceState>>>,
+        Arc<dyn DeviceImplementation + Send + Sync>,
+    )> {
+        let (controller, receiver) =
+            PacketIOController::new(connection.clone(), retry_policy).await?;
+        let (mut initial_state, implementation) =
+            Self::fetch_initial_state_with_retry_policy(&controller, retry_policy).await?;
+
+        let state_sender = match state_sender {
+            Some(state_sender) => {
+                let sender = state_sender.lock().await;
+                if initial_state.serial_number.is_none() {
+                    // Already requested when the device was first connected to
+                    let previous_state = sender.borrow();
+                    initial_state.serial_number = previous_state.serial_number.to_owned();
+                    initial_state.dual_firmware_version =
+                        previous_state.dual_firmware_version.to_owned();
+                    mem::drop(previous_state);
+                }
+                send_state(&sender, &event_sender, initial_state);
+                mem::drop(sender);
+                state_sender
+            }
+            None => Arc::new(Mutex::new(watch::channel(initial_state).0)),
+        };
 
-        let join_handle =
-            Self::spawn_inbound_packet_handler(packet_handlers, receiver, state_sender.to_owned());
+        let is_serial_number_missing = state_sender.lock().await.borrow().serial_number.is_none();
+        let packet_handlers = implementation.packet_handlers();
+        let inbound_handle = Self::spawn_inbound_packet_handler(
+            packet_handlers,
+            receiver,
+            state_sender.to_owned(),
+            event_sender,
+        );
+        let session = Session {
+            controller: Arc::new(controller),
+            connection,
+            inbound_handle,
+        };
 
         if is_serial_number_missing {
             tracing::debug!(
@@ -69,30 +179,218 @@ where
             // Ideally we would fire and forget so the implementation can handle the response, but then we have a race condition
             // where we receive the response here and return the device before it is handled by the implementation, which would cause
             // a device with a None serial number to be returned.
-            let packet: FirmwareVersionUpdatePacket = controller
+            let packet: FirmwareVersionUpdatePacket = session
+                .controller
                 .send(&RequestFirmwareVersionPacket::new().into())
                 .await?
                 .try_into_inbound_packet()?;
-            state_sender
-                .lock()
-                .await
-                .send_modify(|state| state.serial_number = Some(packet.serial_number));
+            state_sender.lock().await.send_modify(|state| {
+                state.serial_number = Some(packet.serial_number);
+                state.dual_firmware_version = Some(DualFirmwareVersion::new(
+                    packet.left_firmware_version,
+                    packet.right_firmware_version,
+                ));
+            });
         }
 
-        Ok(Self {
-            controller,
-            connection,
-            join_handle,
-            state_sender,
-            implementation,
+        Ok((session, state_sender, implementation))
+    }
+
+    fn session(&self) -> Arc<Session<ConnectionType, FuturesType>> {
+        self.session.read().unwrap().to_owned()
+    }
+
+    /// Watches the connection, and once it is lost, re-acquires it from `connection_registry`,
+    /// waiting between attempts according to `policy`. While that is happening,
+    /// [`connection_status`](api::device::Device::connection_status) reports
+    /// [`ConnectionStatus::Reconnecting`] rather than [`ConnectionStatus::Disconnected`], which is
+    /// only reported if `policy` runs out of attempts. After reconnecting, the state is fetched
+    /// again and sent to existing state subscribers.
+    ///
+    /// Must be called from somewhere that [`Futures::spawn_local`] works. Replaces any supervisor
+    /// that was previously started.
+    pub fn start_reconnect_supervisor<RegistryType>(
+        &self,
+        connection_registry: Rc<RegistryType>,
+        mac_address: MacAddr6,
+        policy: ReconnectPolicy,
+    ) where
+        RegistryType: ConnectionRegistry<ConnectionType = ConnectionType> + 'static,
+        ConnectionType: 'static,
+        FuturesType: 'static,
+    {
+        let session = self.session.to_owned();
+        let state_sender = self.state_sender.to_owned();
+        let event_sender = self.event_sender.to_owned();
+        let connection_status_sender = self.connection_status_sender.to_owned();
+        let handle = FuturesType::spawn_local(async move {
+            loop {
+                let mut connection_status = session.read().unwrap().connection.connection_status();
+                // The returned borrow must not be held, or the connection couldn't report
+                // any further statuses
+                let disconnected = connection_status
+                    .wait_for(|status| {
+                        if *status == ConnectionStatus::Disconnected {
+                            return true;
+                        }
+                        connection_status_sender.send_replace(*status);
+                        false
+                    })
+                    .await
+                    .is_ok();
+                if !disconnected {
+                    debug!("connection status sender for {mac_address} was dropped, stopping reconnect supervisor");
+                    return;
+                }
+
+                connection_status_sender.send_replace(ConnectionStatus::Reconnecting);
+                let retry_policy = session.read().unwrap().controller.retry_policy();
+                let mut attempt = 1;
+                let new_session = loop {
+                    if !policy.should_attempt(attempt) {
+                        warn!(
+                            "giving up on reconnecting to {mac_address} after {} attempts",
+                            attempt - 1,
+                        );
+                        connection_status_sender.send_replace(ConnectionStatus::Disconnected);
+                        return;
+                    }
+                    FuturesType::sleep(policy.attempt_delay(attempt)).await;
+                    match Self::reconnect(
+                        &*connection_registry,
+                        mac_address,
+                        retry_policy,
+                        state_sender.to_owned(),
+                        event_sender.to_owned(),
+                    )
+                    .await
+                    {
+                        Ok(new_session) => break new_session,
+                        Err(err) => {
+                            warn!("reconnect attempt {attempt} to {mac_address} failed: {err:?}")
+                        }
+                    }
+                    attempt += 1;
+                };
+                debug!("reconnected to {mac_address} after {attempt} attempts");
+                *session.write().unwrap() = Arc::new(new_session);
+            }
+        });
+        let previous_handle =
+            mem::replace(&mut *self.connection_status_handle.lock().unwrap(), handle);
+        previous_handle.abort();
+    }
+
+    async fn reconnect<RegistryType>(
+        connection_registry: &RegistryType,
+        mac_address: MacAddr6,
+        retry_policy: RetryPolicy,
+        state_sender: Arc<Mutex<watch::Sender<DeviceState>>>,
+        event_sender: broadcast::Sender<DeviceEvent>,
+    ) -> crate::Result<Session<ConnectionType, FuturesType>>
+    where
+        RegistryType: ConnectionRegistry<ConnectionType = ConnectionType>,
+    {
+        let connection = connection_registry
+            .connection(mac_address)
+            .await?
+            .ok_or(crate::Error::DeviceNotFound { source: None })?;
+        let (session, _, _) =
+            Self::start_session(connection, retry_policy, Some(state_sender), event_sender).await?;
+        Ok(session)
+    }
+
+    fn spawn_connection_status_forwarder(
+        mut connection_status: watch::Receiver<ConnectionStatus>,
+        connection_status_sender: Arc<watch::Sender<ConnectionStatus>>,
+    ) -> FuturesType::JoinHandleType {
+        FuturesType::spawn(async move {
+            loop {
+                connection_status_sender.send_replace(*connection_status.borrow_and_update());
+                if connection_status.changed().await.is_err() {
+                    break;
+                }
+            }
         })
     }
 
+    /// Periodically requests the battery level and charging status, since devices don't always
+    /// send updates on their own. Responses are applied to the state just like unprompted updates.
+    /// Replaces any polling that was previously started.
+    ///
+    /// Sending packets isn't `Send` for every connection type, so like
+    /// [`start_reconnect_supervisor`](Self::start_reconnect_supervisor), this must be called from
+    /// somewhere that [`Futures::spawn_local`] works. With
+    /// [`TokioFutures`](crate::futures::TokioFutures), that means within a
+    /// [`tokio::task::LocalSet`], and it panics otherwise.
+    pub fn start_battery_polling(&self, interval: Duration)
+    where
+        ConnectionType: 'static,
+        FuturesType: 'static,
+    {
+        let session = self.session.to_owned();
+        let handle = FuturesType::spawn_local(async move {
+            loop {
+                FuturesType::sleep(interval).await;
+                // Looked up every time, since the session is replaced when reconnecting
+                let controller = session.read().unwrap().controller.to_owned();
+                let packets: [Packet; 2] = [
+                    RequestBatteryLevelPacket::new().into(),
+                    RequestBatteryChargingPacket::new().into(),
+                ];
+                for packet in packets {
+                    if let Err(err) = controller.send(&packet).await {
+                        warn!("error polling battery: {err:?}");
+                    }
+                }
+            }
+        });
+        if let Some(previous_handle) = self.battery_polling_handle.lock().unwrap().replace(handle) {
+            previous_handle.abort();
+        }
+    }
+
+    pub fn stop_battery_polling(&self) {
+        if let Some(handle) = self.battery_polling_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.session().controller.retry_policy()
+    }
+
+    /// Changes the retry policy used for all packets sent from now on.
+    pub fn set_retry_policy(&self, retry_policy: RetryPolicy) {
+        self.session().controller.set_retry_policy(retry_policy);
+    }
+
+    pub fn verify_writes(&self) -> bool {
+        self.verify_writes.load(Ordering::Relaxed)
+    }
+
+    /// When enabled, the state is requested from the device after every change, and if it doesn't
+    /// match what was set, [`Error::VerificationFailed`](crate::Error::VerificationFailed) is
+    /// returned. Some firmware acknowledges values that it then clamps or ignores, which would
+    /// otherwise go unnoticed. This costs an extra round trip per change, so it is off by default.
+    pub fn set_verify_writes(&self, verify_writes: bool) {
+        self.verify_writes.store(verify_writes, Ordering::Relaxed);
+    }
+
     pub async fn fetch_initial_state(
         controller: &PacketIOController<ConnectionType, FuturesType>,
+    ) -> crate::Result<(DeviceState, Arc<dyn DeviceImplementation + Send + Sync>)> {
+        Self::fetch_initial_state_with_retry_policy(controller, RetryPolicy::default()).await
+    }
+
+    pub async fn fetch_initial_state_with_retry_policy(
+        controller: &PacketIOController<ConnectionType, FuturesType>,
+        retry_policy: RetryPolicy,
     ) -> crate::Result<(DeviceState, Arc<dyn DeviceImplementation + Send + Sync>)> {
         tracing::debug!("requesting state to determine model");
-        let unparsed = controller.send(&RequestStatePacket::new().into()).await?;
+        let unparsed = controller
+            .send_with_retry_policy(&RequestStatePacket::new().into(), retry_policy)
+            .await?;
         let parsed: StateUpdatePacket = unparsed.try_into_inbound_packet()?;
         let implementation = (parsed.device_profile.implementation)();
         let state = implementation.initialize(&unparsed.body)?;
@@ -107,6 +405,7 @@ where
         >,
         mut inbound_receiver: mpsc::Receiver<Packet>,
         state_sender_lock: Arc<Mutex<watch::Sender<DeviceState>>>,
+        event_sender: broadcast::Sender<DeviceEvent>,
     ) -> FuturesType::JoinHandleType {
         FuturesType::spawn(async move {
             while let Some(packet) = inbound_receiver.recv().await {
@@ -118,14 +417,22 @@ where
                         if new_state != *state {
                             trace!(event = "state_update", old_state = ?state, new_state = ?new_state);
                             mem::drop(state);
-                            state_sender.send_replace(new_state);
+                            send_state(&state_sender, &event_sender, new_state);
                         }
                     }
                     None => {
                         if packet.body.is_empty() {
                             trace!("got ACK: {packet:?}");
                         } else {
-                            warn!("no packet handler found for packet: {packet:?}")
+                            match AnyInboundPacket::try_from(&packet) {
+                                Ok(decoded) => warn!(
+                                    "no packet handler found for {}: {decoded:?}",
+                                    decoded.name()
+                                ),
+                                Err(err) => {
+                                    warn!("no packet handler found for packet: {packet:?} ({err})")
+                                }
+                            }
                         }
                     }
                 }
@@ -133,57 +440,61 @@ where
         })
     }
 
-    async fn handle_response(
-        &self,
-        response: CommandResponse,
-        state_sender: &watch::Sender<DeviceState>,
-    ) -> crate::Result<()> {
-        self.send_packets(&response.packets).await?;
-        state_sender.send_replace(response.new_state);
-        Ok(())
-    }
-
-    async fn send_packets(&self, packets: &[Packet]) -> crate::Result<()> {
-        for packet in packets {
-            self.controller.send(packet).await?;
+    async fn set_setting(&self, change: SettingChange) -> crate::Result<()> {
+        let state_sender = self.state_sender.lock().await;
+        let state = state_sender.borrow().to_owned();
+        if let Some(response) = self.prepare(state, change.to_owned())? {
+            self.handle_response(response, &[change], &state_sender)
+                .await?;
         }
         Ok(())
     }
-}
-
-impl<ConnectionType, FuturesType> api::device::Device
-    for SoundcoreDevice<ConnectionType, FuturesType>
-where
-    ConnectionType: Connection,
-    FuturesType: Futures,
-{
-    async fn subscribe_to_state_updates(&self) -> watch::Receiver<DeviceState> {
-        self.state_sender.lock().await.subscribe()
-    }
-
-    async fn mac_address(&self) -> crate::Result<MacAddr6> {
-        self.connection.mac_address().await
-    }
-
-    async fn name(&self) -> crate::Result<String> {
-        self.connection.name().await
-    }
-
-    fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
-        self.connection.connection_status()
-    }
-
-    fn service_uuid(&self) -> Uuid {
-        self.connection.service_uuid()
-    }
 
-    async fn state(&self) -> DeviceState {
-        self.state_sender.lock().await.borrow().to_owned()
+    /// Checks that `change` can be made, and returns the packets needed to make it. Returns `None`
+    /// if the setting already has the requested value.
+    fn prepare(
+        &self,
+        state: DeviceState,
+        change: SettingChange,
+    ) -> crate::Result<Option<CommandResponse>> {
+        match change {
+            SettingChange::SoundModes(sound_modes) => self.prepare_sound_modes(state, sound_modes),
+            SettingChange::SoundModesTypeTwo(sound_modes) => {
+                self.prepare_sound_modes_type_two(state, sound_modes)
+            }
+            SettingChange::AmbientSoundModeCycle(cycle) => {
+                self.prepare_ambient_sound_mode_cycle(state, cycle)
+            }
+            SettingChange::EqualizerConfiguration(equalizer_configuration) => {
+                self.prepare_equalizer_configuration(state, equalizer_configuration)
+            }
+            SettingChange::StereoEqualizerConfiguration(equalizer_configuration) => {
+                self.prepare_stereo_equalizer_configuration(state, equalizer_configuration)
+            }
+            SettingChange::HearId(hear_id) => self.prepare_hear_id(state, hear_id),
+            SettingChange::MultiButtonConfiguration(button_configuration) => {
+                self.prepare_multi_button_configuration(state, button_configuration)
+            }
+            SettingChange::WearDetection(is_enabled) => {
+                self.prepare_wear_detection(state, is_enabled)
+            }
+            SettingChange::TouchTone(is_enabled) => self.prepare_touch_tone(state, is_enabled),
+            SettingChange::AutoPowerOff(auto_power_off) => {
+                self.prepare_auto_power_off(state, auto_power_off)
+            }
+            SettingChange::GameMode(is_enabled) => self.prepare_game_mode(state, is_enabled),
+            SettingChange::Ldac(is_enabled) => self.prepare_ldac(state, is_enabled),
+            SettingChange::VoicePrompt(voice_prompt) => {
+                self.prepare_voice_prompt(state, voice_prompt)
+            }
+        }
     }
 
-    async fn set_sound_modes(&self, sound_modes: SoundModes) -> crate::Result<()> {
-        let state_sender = self.state_sender.lock().await;
-        let state = state_sender.borrow().to_owned();
+    fn prepare_sound_modes(
+        &self,
+        state: DeviceState,
+        sound_modes: SoundModes,
+    ) -> crate::Result<Option<CommandResponse>> {
         // don't actually verify that the sound modes we're setting are supported by the device,
         // since that would make it impossible to get the settings out of a state where multiple
         // different sound mode options are invalid
@@ -198,39 +509,38 @@ where
             });
         };
         if prev_sound_modes == sound_modes {
-            return Ok(());
+            return Ok(None);
         }
 
-        let response = self.implementation.set_sound_modes(state, sound_modes)?;
-        self.handle_response(response, &state_sender).await?;
-        Ok(())
+        self.implementation
+            .set_sound_modes(state, sound_modes)
+            .map(Some)
     }
 
-    async fn set_sound_modes_type_two(&self, sound_modes: SoundModesTypeTwo) -> crate::Result<()> {
-        let state_sender = self.state_sender.lock().await;
-        let state = state_sender.borrow().to_owned();
+    fn prepare_sound_modes_type_two(
+        &self,
+        state: DeviceState,
+        sound_modes: SoundModesTypeTwo,
+    ) -> crate::Result<Option<CommandResponse>> {
         let Some(prev_sound_modes) = state.sound_modes_type_two else {
             return Err(crate::Error::MissingData {
                 name: "sound modes type two",
             });
         };
         if prev_sound_modes == sound_modes {
-            return Ok(());
+            return Ok(None);
         }
 
-        let response = self
-            .implementation
-            .set_sound_modes_type_two(state, sound_modes)?;
-        self.handle_response(response, &state_sender).await?;
-        Ok(())
+        self.implementation
+            .set_sound_modes_type_two(state, sound_modes)
+            .map(Some)
     }
 
-    async fn set_ambient_sound_mode_cycle(
+    fn prepare_ambient_sound_mode_cycle(
         &self,
+        state: DeviceState,
         cycle: AmbientSoundModeCycle,
-    ) -> crate::Result<()> {
-        let state_sender = self.state_sender.lock().await;
-        let state = state_sender.borrow().to_owned();
+    ) -> crate::Result<Option<CommandResponse>> {
         if !state.device_features.has_ambient_sound_mode_cycle {
             return Err(crate::Error::FeatureNotSupported {
                 feature_name: "ambient sound mode cycle",
@@ -242,71 +552,99 @@ where
             });
         };
         if prev_cycle == cycle {
-            return Ok(());
+            return Ok(None);
         }
 
-        let response = self
-            .implementation
-            .set_ambient_sound_mode_cycle(state, cycle)?;
-        self.handle_response(response, &state_sender).await?;
-        Ok(())
+        self.implementation
+            .set_ambient_sound_mode_cycle(state, cycle)
+            .map(Some)
     }
 
-    async fn set_equalizer_configuration(
+    fn prepare_equalizer_configuration(
         &self,
+        state: DeviceState,
         equalizer_configuration: EqualizerConfiguration,
-    ) -> crate::Result<()> {
-        let state_sender = self.state_sender.lock().await;
-        let state = state_sender.borrow().to_owned();
-
+    ) -> crate::Result<Option<CommandResponse>> {
         if state.device_features.num_equalizer_channels == 0 {
             return Err(crate::Error::FeatureNotSupported {
                 feature_name: "equalizer",
             });
         }
-        if equalizer_configuration
-            .volume_adjustments()
-            .adjustments()
-            .len()
-            != state.device_features.num_equalizer_bands
+        if !equalizer_configuration
+            .is_valid_for_number_of_bands(state.device_features.num_equalizer_bands)
         {
             return Err(crate::Error::FeatureNotSupported {
                 feature_name: "wrong number of equalizer bands",
             });
         }
-        if equalizer_configuration == state.equalizer_configuration {
-            return Ok(());
+        // Configurations with only 8 bands leave the rest as they are on devices with more
+        let is_unchanged = |current: &EqualizerConfiguration| {
+            equalizer_configuration.with_extra_bands_from(current) == *current
+        };
+        if is_unchanged(&state.equalizer_configuration)
+            && state
+                .right_equalizer_configuration
+                .as_ref()
+                .is_none_or(is_unchanged)
+        {
+            return Ok(None);
         }
 
-        let response = self
-            .implementation
-            .set_equalizer_configuration(state, equalizer_configuration)?;
-        self.handle_response(response, &state_sender).await?;
-        Ok(())
+        self.implementation
+            .set_equalizer_configuration(state, equalizer_configuration)
+            .map(Some)
     }
 
-    async fn set_hear_id(&self, hear_id: HearId) -> crate::Result<()> {
-        let state_sender = self.state_sender.lock().await;
-        let state = state_sender.borrow().to_owned();
+    fn prepare_stereo_equalizer_configuration(
+        &self,
+        state: DeviceState,
+        equalizer_configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<Option<CommandResponse>> {
+        if state.device_features.num_equalizer_channels != 2 {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "stereo equalizer",
+            });
+        }
+        let num_bands = state.device_features.num_equalizer_bands;
+        if [
+            &equalizer_configuration.left,
+            &equalizer_configuration.right,
+        ]
+        .iter()
+        .any(|channel| !channel.is_valid_for_number_of_bands(num_bands))
+        {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "wrong number of equalizer bands",
+            });
+        }
+        if state.stereo_equalizer_configuration().as_ref() == Some(&equalizer_configuration) {
+            return Ok(None);
+        }
+
+        self.implementation
+            .set_stereo_equalizer_configuration(state, equalizer_configuration)
+            .map(Some)
+    }
 
+    fn prepare_hear_id(
+        &self,
+        state: DeviceState,
+        hear_id: HearId,
+    ) -> crate::Result<Option<CommandResponse>> {
         if !state.device_features.has_hear_id {
             return Err(crate::Error::FeatureNotSupported {
                 feature_name: "hear id",
             });
         }
 
-        let response = self.implementation.set_hear_id(state, hear_id)?;
-        self.handle_response(response, &state_sender).await?;
-        Ok(())
+        self.implementation.set_hear_id(state, hear_id).map(Some)
     }
 
-    async fn set_multi_button_configuration(
+    fn prepare_multi_button_configuration(
         &self,
+        state: DeviceState,
         button_configuration: MultiButtonConfiguration,
-    ) -> crate::Result<()> {
-        let state_sender = self.state_sender.lock().await;
-        let state = state_sender.borrow().to_owned();
-
+    ) -> crate::Result<Option<CommandResponse>> {
         if !state.device_features.has_button_configuration {
             return Err(crate::Error::FeatureNotSupported {
                 feature_name: "custom button model",
@@ -320,75 +658,464 @@ where
                     name: "custom button model",
                 })?;
         if button_configuration == prev_button_configuration {
-            return Ok(());
+            return Ok(None);
         }
 
-        let response = self
-            .implementation
-            .set_multi_button_configuration(state, button_configuration)?;
-        self.handle_response(response, &state_sender).await?;
-        Ok(())
+        self.implementation
+            .set_multi_button_configuration(state, button_configuration)
+            .map(Some)
     }
-}
 
-impl<ConnectionType, FuturesType> Drop for SoundcoreDevice<ConnectionType, FuturesType>
-where
-    ConnectionType: Connection,
-    FuturesType: Futures,
-{
-    fn drop(&mut self) {
-        self.join_handle.abort();
-    }
-}
+    fn prepare_wear_detection(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<Option<CommandResponse>> {
+        if !state.device_features.has_wear_detection {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "wear detection",
+            });
+        }
+        let Some(prev_wear_detection) = state.wear_detection else {
+            return Err(crate::Error::MissingData {
+                name: "wear detection",
+            });
+        };
+        if prev_wear_detection == is_enabled {
+            return Ok(None);
+        }
 
-impl<ConnectionType, FuturesType> std::fmt::Debug for SoundcoreDevice<ConnectionType, FuturesType>
-where
-    ConnectionType: Connection,
-    FuturesType: Futures,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("SoundcoreDevice").finish()
+        self.implementation
+            .set_wear_detection(state, is_enabled)
+            .map(Some)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::{sync::Arc, time::Duration};
+    fn prepare_touch_tone(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<Option<CommandResponse>> {
+        if !state.device_features.has_touch_tone {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "touch tone",
+            });
+        }
+        let Some(prev_touch_tone) = state.touch_tone else {
+            return Err(crate::Error::MissingData { name: "touch tone" });
+        };
+        if prev_touch_tone == is_enabled {
+            return Ok(None);
+        }
 
-    use macaddr::MacAddr6;
-    use tokio::sync::{mpsc, Mutex};
+        self.implementation
+            .set_touch_tone(state, is_enabled)
+            .map(Some)
+    }
 
-    use super::SoundcoreDevice;
-    use crate::{
-        api::device::Device,
-        devices::standard::{
-            packets::{
-                inbound::{FirmwareVersionUpdatePacket, InboundPacket},
-                outbound::{OutboundPacket, SetEqualizerPacket, SetSoundModePacket},
-            },
-            structures::{
-                AmbientSoundMode, CustomNoiseCanceling, EqualizerConfiguration, NoiseCancelingMode,
-                SoundModes, VolumeAdjustments,
-            },
-        },
-        futures::TokioFutures,
-        soundcore_device::device::Packet,
-        stub::connection::StubConnection,
-    };
+    fn prepare_auto_power_off(
+        &self,
+        state: DeviceState,
+        auto_power_off: AutoPowerOff,
+    ) -> crate::Result<Option<CommandResponse>> {
+        if !state.device_features.has_auto_power_off {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "auto power off",
+            });
+        }
+        let Some(prev_auto_power_off) = state.auto_power_off else {
+            return Err(crate::Error::MissingData {
+                name: "auto power off",
+            });
+        };
+        if prev_auto_power_off == auto_power_off {
+            return Ok(None);
+        }
 
-    fn example_state_update_packet() -> Vec<u8> {
-        vec![
-            0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01, 0x46, 0x00, 0x05, 0x00, 0xfe, 0xfe, 0x3c,
-            0xb4, 0x8f, 0xa0, 0x8e, 0xb4, 0x74, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x30, 0x32, 0x2e, 0x33, 0x30, 0x33, 0x30, 0x32,
-            0x39, 0x30, 0x38, 0x36, 0x45, 0x43, 0x38, 0x32, 0x46, 0x31, 0x32, 0x41, 0x43, 0x30,
-        ]
+        self.implementation
+            .set_auto_power_off(state, auto_power_off)
+            .map(Some)
     }
 
-    fn example_firmware_version_packet() -> Vec<u8> {
-        Packet {
-            command: FirmwareVersionUpdatePacket::command(),
+    fn prepare_game_mode(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<Option<CommandResponse>> {
+        if !state.device_features.has_game_mode {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "game mode",
+            });
+        }
+        let Some(prev_game_mode) = state.game_mode else {
+            return Err(crate::Error::MissingData { name: "game mode" });
+        };
+        if prev_game_mode == is_enabled {
+            return Ok(None);
+        }
+
+        self.implementation
+            .set_game_mode(state, is_enabled)
+            .map(Some)
+    }
+
+    fn prepare_ldac(
+        &self,
+        state: DeviceState,
+        is_enabled: bool,
+    ) -> crate::Result<Option<CommandResponse>> {
+        if !state.device_features.has_ldac {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "LDAC",
+            });
+        }
+        let Some(prev_ldac) = state.ldac else {
+            return Err(crate::Error::MissingData { name: "LDAC" });
+        };
+        if prev_ldac == is_enabled {
+            return Ok(None);
+        }
+
+        self.implementation.set_ldac(state, is_enabled).map(Some)
+    }
+
+    fn prepare_voice_prompt(
+        &self,
+        state: DeviceState,
+        voice_prompt: VoicePrompt,
+    ) -> crate::Result<Option<CommandResponse>> {
+        if !state.device_features.has_voice_prompt {
+            return Err(crate::Error::FeatureNotSupported {
+                feature_name: "voice prompt",
+            });
+        }
+        // The current voice prompt is only known once the device reports a change, so allow
+        // setting it anyway. Otherwise a device stuck on Chinese prompts could never be fixed.
+        if state.voice_prompt == Some(voice_prompt) {
+            return Ok(None);
+        }
+
+        self.implementation
+            .set_voice_prompt(state, voice_prompt)
+            .map(Some)
+    }
+
+    async fn handle_response(
+        &self,
+        response: CommandResponse,
+        changes: &[SettingChange],
+        state_sender: &watch::Sender<DeviceState>,
+    ) -> crate::Result<()> {
+        self.send_packets(&response.packets).await?;
+        self.publish_verified_state(response.new_state, changes, state_sender)
+            .await
+    }
+
+    /// Publishes `expected`, or if writes are being verified, whatever the device reports instead.
+    /// Only the settings in `changes` are verified, since anything else may have changed on its
+    /// own in the meantime. Settings that the device's state update doesn't include can't be
+    /// verified, so they are logged and assumed to have been applied.
+    async fn publish_verified_state(
+        &self,
+        expected: DeviceState,
+        changes: &[SettingChange],
+        state_sender: &watch::Sender<DeviceState>,
+    ) -> crate::Result<()> {
+        if !self.verify_writes() {
+            send_state(state_sender, &self.event_sender, expected);
+            return Ok(());
+        }
+
+        let (reported, actual) = self.request_state(&expected).await?;
+        let mut is_applied = true;
+        let mut unverified = Vec::new();
+        for change in changes {
+            match change.is_same_in(&expected, &reported) {
+                Some(is_same) => is_applied &= is_same,
+                None => unverified.push(change.name()),
+            }
+        }
+        if !unverified.is_empty() {
+            warn!("device state does not include {unverified:?}, so they were not verified");
+        }
+
+        if is_applied {
+            send_state(state_sender, &self.event_sender, actual);
+            Ok(())
+        } else {
+            warn!("device did not apply change: expected {expected:?}, got {actual:?}");
+            send_state(state_sender, &self.event_sender, actual.to_owned());
+            Err(crate::Error::VerificationFailed {
+                expected: Box::new(expected),
+                actual: Box::new(actual),
+            })
+        }
+    }
+
+    /// Requests the state from the device. Returns only what its state update packet contains,
+    /// with everything else left unknown, followed by that packet applied on top of `state` the
+    /// same way unprompted updates are.
+    async fn request_state(
+        &self,
+        state: &DeviceState,
+    ) -> crate::Result<(DeviceState, DeviceState)> {
+        let packet = self
+            .session()
+            .controller
+            .send(&RequestStatePacket::new().into())
+            .await?;
+        let reported = self.implementation.initialize(&packet.body)?;
+        let actual = match self.implementation.packet_handlers().get(&STATE_UPDATE) {
+            Some(handler) => handler(&packet.body, state.to_owned()),
+            None => reported.to_owned(),
+        };
+        Ok((reported, actual))
+    }
+
+    async fn send_packets(&self, packets: &[Packet]) -> crate::Result<()> {
+        let controller = self.session().controller.to_owned();
+        for packet in packets {
+            controller.send(packet).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Sends changes to a [`SoundcoreDevice`] while keeping track of the resulting state, so it can be
+/// published once after the whole batch.
+struct UnpublishedChanges<'a, ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    device: &'a SoundcoreDevice<ConnectionType, FuturesType>,
+    state: DeviceState,
+}
+
+impl<ConnectionType, FuturesType> ChangeTarget
+    for UnpublishedChanges<'_, ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    async fn state(&self) -> DeviceState {
+        self.state.to_owned()
+    }
+
+    async fn send(&mut self, change: SettingChange) -> crate::Result<()> {
+        if let Some(response) = self.device.prepare(self.state.to_owned(), change)? {
+            // If one of several packets fails, the device may have applied the others, so the new
+            // state is assumed either way. That way, reverting the change sends it in full.
+            let result = self.device.send_packets(&response.packets).await;
+            self.state = response.new_state;
+            result?;
+        }
+        Ok(())
+    }
+}
+
+impl<ConnectionType, FuturesType> api::device::Device
+    for SoundcoreDevice<ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    async fn subscribe_to_state_updates(&self) -> watch::Receiver<DeviceState> {
+        self.state_sender.lock().await.subscribe()
+    }
+
+    fn subscribe_to_events(&self) -> broadcast::Receiver<DeviceEvent> {
+        self.event_sender.subscribe()
+    }
+
+    async fn mac_address(&self) -> crate::Result<MacAddr6> {
+        self.session().connection.mac_address().await
+    }
+
+    async fn name(&self) -> crate::Result<String> {
+        self.session().connection.name().await
+    }
+
+    fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
+        self.connection_status_sender.subscribe()
+    }
+
+    fn service_uuid(&self) -> Uuid {
+        self.session().connection.service_uuid()
+    }
+
+    async fn state(&self) -> DeviceState {
+        self.state_sender.lock().await.borrow().to_owned()
+    }
+
+    async fn apply(&self, changes: Vec<SettingChange>) -> crate::Result<()> {
+        let state_sender = self.state_sender.lock().await;
+        let mut target = UnpublishedChanges {
+            device: self,
+            state: state_sender.borrow().to_owned(),
+        };
+        match apply_with_rollback(&mut target, changes.to_owned()).await {
+            Ok(()) => {
+                self.publish_verified_state(target.state, &changes, &state_sender)
+                    .await
+            }
+            Err(err) => {
+                // Not verified, since the device is expected to be back where it started
+                send_state(&state_sender, &self.event_sender, target.state);
+                Err(err)
+            }
+        }
+    }
+
+    async fn set_sound_modes(&self, sound_modes: SoundModes) -> crate::Result<()> {
+        self.set_setting(SettingChange::SoundModes(sound_modes))
+            .await
+    }
+
+    async fn set_sound_modes_type_two(&self, sound_modes: SoundModesTypeTwo) -> crate::Result<()> {
+        self.set_setting(SettingChange::SoundModesTypeTwo(sound_modes))
+            .await
+    }
+
+    async fn set_ambient_sound_mode_cycle(
+        &self,
+        cycle: AmbientSoundModeCycle,
+    ) -> crate::Result<()> {
+        self.set_setting(SettingChange::AmbientSoundModeCycle(cycle))
+            .await
+    }
+
+    async fn set_equalizer_configuration(
+        &self,
+        equalizer_configuration: EqualizerConfiguration,
+    ) -> crate::Result<()> {
+        self.set_setting(SettingChange::EqualizerConfiguration(
+            equalizer_configuration,
+        ))
+        .await
+    }
+
+    async fn set_stereo_equalizer_configuration(
+        &self,
+        equalizer_configuration: StereoEqualizerConfiguration,
+    ) -> crate::Result<()> {
+        self.set_setting(SettingChange::StereoEqualizerConfiguration(
+            equalizer_configuration,
+        ))
+        .await
+    }
+
+    async fn set_hear_id(&self, hear_id: HearId) -> crate::Result<()> {
+        self.set_setting(SettingChange::HearId(hear_id)).await
+    }
+
+    async fn set_multi_button_configuration(
+        &self,
+        button_configuration: MultiButtonConfiguration,
+    ) -> crate::Result<()> {
+        self.set_setting(SettingChange::MultiButtonConfiguration(
+            button_configuration,
+        ))
+        .await
+    }
+
+    async fn set_wear_detection(&self, is_enabled: bool) -> crate::Result<()> {
+        self.set_setting(SettingChange::WearDetection(is_enabled))
+            .await
+    }
+
+    async fn set_touch_tone(&self, is_enabled: bool) -> crate::Result<()> {
+        self.set_setting(SettingChange::TouchTone(is_enabled)).await
+    }
+
+    async fn set_auto_power_off(&self, auto_power_off: AutoPowerOff) -> crate::Result<()> {
+        self.set_setting(SettingChange::AutoPowerOff(auto_power_off))
+            .await
+    }
+
+    async fn set_game_mode(&self, is_enabled: bool) -> crate::Result<()> {
+        self.set_setting(SettingChange::GameMode(is_enabled)).await
+    }
+
+    async fn set_ldac(&self, is_enabled: bool) -> crate::Result<()> {
+        self.set_setting(SettingChange::Ldac(is_enabled)).await
+    }
+
+    async fn set_voice_prompt(&self, voice_prompt: VoicePrompt) -> crate::Result<()> {
+        self.set_setting(SettingChange::VoicePrompt(voice_prompt))
+            .await
+    }
+}
+
+impl<ConnectionType, FuturesType> Drop for SoundcoreDevice<ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    fn drop(&mut self) {
+        self.connection_status_handle.lock().unwrap().abort();
+        self.stop_battery_polling();
+        self.session().inbound_handle.abort();
+    }
+}
+
+impl<ConnectionType, FuturesType> std::fmt::Debug for SoundcoreDevice<ConnectionType, FuturesType>
+where
+    ConnectionType: Connection,
+    FuturesType: Futures,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SoundcoreDevice").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, rc::Rc, sync::Arc, time::Duration};
+
+    use macaddr::MacAddr6;
+    use tokio::sync::{mpsc, Mutex};
+
+    use super::SoundcoreDevice;
+    use crate::{
+        api::{
+            connection::{ConnectionStatus, GenericConnectionDescriptor},
+            device::{Device, SettingChange},
+        },
+        devices::standard::{
+            device_event::DeviceEvent,
+            packets::{
+                inbound::{FirmwareVersionUpdatePacket, InboundPacket},
+                outbound::{OutboundPacket, SetEqualizerPacket, SetSoundModePacket},
+            },
+            structures::{
+                AmbientSoundMode, Battery, BatteryLevel, Command, CustomNoiseCanceling,
+                DualFirmwareVersion, EqualizerConfiguration, FirmwareVersion, HostDevice,
+                IsBatteryCharging, NoiseCancelingMode, SingleBattery, SoundModes, TwsStatus,
+                VoicePrompt, VolumeAdjustments, STATE_UPDATE,
+            },
+        },
+        emulator::EmulatedConnection,
+        futures::TokioFutures,
+        soundcore_device::{
+            device::{Backoff, Packet, ReconnectPolicy},
+            device_model::DeviceModel,
+        },
+        stub::connection::{StubConnection, StubConnectionRegistry},
+    };
+
+    fn example_state_update_packet() -> Vec<u8> {
+        vec![
+            0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01, 0x46, 0x00, 0x05, 0x00, 0xfe, 0xfe, 0x3c,
+            0xb4, 0x8f, 0xa0, 0x8e, 0xb4, 0x74, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x30, 0x32, 0x2e, 0x33, 0x30, 0x33, 0x30, 0x32,
+            0x39, 0x30, 0x38, 0x36, 0x45, 0x43, 0x38, 0x32, 0x46, 0x31, 0x32, 0x41, 0x43, 0x30,
+        ]
+    }
+
+    fn example_firmware_version_packet() -> Vec<u8> {
+        Packet {
+            command: FirmwareVersionUpdatePacket::command(),
             body: "02.0002.000000000000003028".as_bytes().to_vec(),
         }
         .bytes()
@@ -449,79 +1176,508 @@ mod tests {
                     .send(example_state_update_packet())
                     .await
                     .unwrap();
-                tokio::time::sleep(Duration::from_millis(1500)).await;
+                tokio::time::sleep(Duration::from_millis(1500)).await;
+                sender
+                    .lock()
+                    .await
+                    .send(example_firmware_version_packet())
+                    .await
+                    .unwrap();
+            }
+        });
+        let connection_clone = connection.clone();
+        SoundcoreDevice::<_, TokioFutures>::new(connection_clone)
+            .await
+            .unwrap();
+        assert_eq!(0, connection.write_return_queue_length().await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_new_max_retries() {
+        let (connection, _) = create_test_connection().await;
+
+        let connection_clone = connection.clone();
+        let result = SoundcoreDevice::<_, TokioFutures>::new(connection_clone).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_ambient_sound_mode_update_packet() {
+        let (connection, sender) = create_test_connection().await;
+        connection.push_write_return(Ok(())).await;
+        let sender_copy = sender.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_state_update_packet())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_firmware_version_packet())
+                .await
+                .unwrap();
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+            .await
+            .unwrap();
+        let state = device.state().await;
+        let sound_modes = state.sound_modes.unwrap();
+        assert_eq!(AmbientSoundMode::Normal, sound_modes.ambient_sound_mode);
+        assert_eq!(
+            NoiseCancelingMode::Transport,
+            sound_modes.noise_canceling_mode
+        );
+
+        // alert from device that sound mode changed
+        sender
+            .send(vec![
+                0x09, 0xff, 0x00, 0x00, 0x01, 0x06, 0x01, 0x0e, 0x00, 0x00, 0x01, 0x01, 0x00, 0x20,
+            ])
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        let state = device.state().await;
+        let sound_modes = state.sound_modes.unwrap();
+
+        assert_eq!(
+            AmbientSoundMode::NoiseCanceling,
+            sound_modes.ambient_sound_mode,
+        );
+        assert_eq!(
+            NoiseCancelingMode::Outdoor,
+            sound_modes.noise_canceling_mode,
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_firmware_version_update_packet_keeps_both_versions() {
+        let (connection, sender) = create_test_connection().await;
+        let sender_copy = sender.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_state_update_packet())
+                .await
+                .unwrap();
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+            .await
+            .unwrap();
+
+        sender
+            .send(
+                Packet {
+                    command: FirmwareVersionUpdatePacket::command(),
+                    body: "02.3002.290000000000003028".as_bytes().to_vec(),
+                }
+                .bytes(),
+            )
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        assert_eq!(
+            Some(DualFirmwareVersion::new(
+                FirmwareVersion::new(2, 30),
+                FirmwareVersion::new(2, 29),
+            )),
+            device.state().await.dual_firmware_version,
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_game_mode_update_packet() {
+        let (connection, sender) = create_test_connection().await;
+        let sender_copy = sender.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_state_update_packet())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_firmware_version_packet())
+                .await
+                .unwrap();
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+            .await
+            .unwrap();
+        assert_eq!(None, device.state().await.game_mode);
+
+        sender
+            .send(vec![
+                0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x11, 0x0b, 0x00, 0x01, 0x27,
+            ])
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        assert_eq!(Some(true), device.state().await.game_mode);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_tws_status_update_packet() {
+        let (connection, sender) = create_test_connection().await;
+        let sender_copy = sender.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_state_update_packet())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_firmware_version_packet())
+                .await
+                .unwrap();
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+            .await
+            .unwrap();
+        let mut state_receiver = device.subscribe_to_state_updates().await;
+
+        sender
+            .send(vec![
+                0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x02, 0x0c, 0x00, 0x01, 0x00, 0x19,
+            ])
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        let expected = Some(TwsStatus {
+            is_connected: false,
+            host_device: HostDevice::Right,
+        });
+        assert!(state_receiver.has_changed().unwrap());
+        assert_eq!(expected, state_receiver.borrow_and_update().tws_status);
+        assert_eq!(expected, device.state().await.tws_status);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_subscribe_to_events_receives_every_change() {
+        let (connection, sender) = create_test_connection().await;
+        let sender_copy = sender.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_state_update_packet())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_firmware_version_packet())
+                .await
+                .unwrap();
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+            .await
+            .unwrap();
+        let mut event_receiver = device.subscribe_to_events();
+        let initial_tws_status = device.state().await.tws_status;
+
+        // Sent back to back, so a state subscriber would only see the second
+        for packet in [
+            vec![
+                0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x02, 0x0c, 0x00, 0x01, 0x00, 0x19,
+            ],
+            vec![
+                0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x02, 0x0c, 0x00, 0x00, 0x01, 0x19,
+            ],
+        ] {
+            sender.send(packet).await.unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        let changed_tws_status = Some(TwsStatus {
+            is_connected: false,
+            host_device: HostDevice::Right,
+        });
+        let final_tws_status = Some(TwsStatus {
+            is_connected: true,
+            host_device: HostDevice::Left,
+        });
+        assert_eq!(
+            DeviceEvent::TwsStatusChanged {
+                old: initial_tws_status,
+                new: changed_tws_status,
+            },
+            event_receiver.try_recv().unwrap(),
+        );
+        assert_eq!(
+            DeviceEvent::TwsStatusChanged {
+                old: changed_tws_status,
+                new: final_tws_status,
+            },
+            event_receiver.try_recv().unwrap(),
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_voice_prompt_update_packet() {
+        let (connection, sender) = create_test_connection().await;
+        let sender_copy = sender.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_state_update_packet())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender_copy
+                .send(example_firmware_version_packet())
+                .await
+                .unwrap();
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+            .await
+            .unwrap();
+        assert_eq!(None, device.state().await.voice_prompt);
+
+        sender
+            .send(vec![
+                0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x0f, 0x0b, 0x00, 0x01, 0x25,
+            ])
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        assert_eq!(
+            Some(VoicePrompt::Chinese),
+            device.state().await.voice_prompt
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_set_voice_prompt_not_supported() {
+        let (connection, sender) = create_test_connection().await;
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender.send(example_state_update_packet()).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender
+                .send(example_firmware_version_packet())
+                .await
+                .unwrap();
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+            .await
+            .unwrap();
+
+        let result = device.set_voice_prompt(VoicePrompt::English).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::FeatureNotSupported {
+                feature_name: "voice prompt"
+            })
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_battery_polling() {
+        tokio::task::LocalSet::new()
+            .run_until(async {
+                let (connection, sender) = create_test_connection().await;
+                let sender_copy = sender.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    sender_copy
+                        .send(example_state_update_packet())
+                        .await
+                        .unwrap();
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    sender_copy
+                        .send(example_firmware_version_packet())
+                        .await
+                        .unwrap();
+                });
+                let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+                    .await
+                    .unwrap();
+                // request battery level packet
+                connection.push_write_return(Ok(())).await;
+                // request battery charging packet
+                connection.push_write_return(Ok(())).await;
+
+                device.start_battery_polling(Duration::from_secs(60));
+                tokio::time::sleep(Duration::from_secs(59)).await;
+                assert_eq!(2, connection.write_return_queue_length().await);
+
+                tokio::time::sleep(Duration::from_millis(1001)).await;
+                assert_eq!(1, connection.write_return_queue_length().await);
                 sender
-                    .lock()
+                    .send(vec![
+                        0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x03, 0x0b, 0x00, 0x02, 0x1a,
+                    ])
                     .await
-                    .send(example_firmware_version_packet())
+                    .unwrap();
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                assert_eq!(0, connection.write_return_queue_length().await);
+                sender
+                    .send(vec![
+                        0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x04, 0x0b, 0x00, 0x01, 0x1a,
+                    ])
                     .await
                     .unwrap();
-            }
-        });
-        let connection_clone = connection.clone();
-        SoundcoreDevice::<_, TokioFutures>::new(connection_clone)
-            .await
-            .unwrap();
-        assert_eq!(0, connection.write_return_queue_length().await);
+                tokio::time::sleep(Duration::from_millis(1)).await;
+
+                assert_eq!(
+                    Battery::SingleBattery(SingleBattery {
+                        is_charging: IsBatteryCharging::Yes,
+                        level: BatteryLevel(2),
+                    }),
+                    device.state().await.battery,
+                );
+            })
+            .await;
     }
 
     #[tokio::test(start_paused = true)]
-    async fn test_new_max_retries() {
-        let (connection, _) = create_test_connection().await;
+    async fn test_stop_battery_polling() {
+        tokio::task::LocalSet::new()
+            .run_until(async {
+                let (connection, sender) = create_test_connection().await;
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    sender.send(example_state_update_packet()).await.unwrap();
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    sender
+                        .send(example_firmware_version_packet())
+                        .await
+                        .unwrap();
+                });
+                let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+                    .await
+                    .unwrap();
+                connection.push_write_return(Ok(())).await;
 
-        let connection_clone = connection.clone();
-        let result = SoundcoreDevice::<_, TokioFutures>::new(connection_clone).await;
-        assert!(result.is_err());
+                device.start_battery_polling(Duration::from_secs(60));
+                device.stop_battery_polling();
+                tokio::time::sleep(Duration::from_secs(120)).await;
+                assert_eq!(1, connection.write_return_queue_length().await);
+            })
+            .await;
     }
 
     #[tokio::test(start_paused = true)]
-    async fn test_ambient_sound_mode_update_packet() {
+    #[should_panic(expected = "LocalSet")]
+    async fn test_battery_polling_requires_local_set_with_tokio() {
         let (connection, sender) = create_test_connection().await;
-        connection.push_write_return(Ok(())).await;
-        let sender_copy = sender.clone();
         tokio::spawn(async move {
             tokio::time::sleep(Duration::from_millis(1)).await;
-            sender_copy
-                .send(example_state_update_packet())
-                .await
-                .unwrap();
+            sender.send(example_state_update_packet()).await.unwrap();
             tokio::time::sleep(Duration::from_millis(1)).await;
-            sender_copy
+            sender
                 .send(example_firmware_version_packet())
                 .await
                 .unwrap();
         });
-        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
             .await
             .unwrap();
-        let state = device.state().await;
-        let sound_modes = state.sound_modes.unwrap();
-        assert_eq!(AmbientSoundMode::Normal, sound_modes.ambient_sound_mode);
-        assert_eq!(
-            NoiseCancelingMode::Transport,
-            sound_modes.noise_canceling_mode
-        );
 
-        // alert from device that sound mode changed
-        sender
-            .send(vec![
-                0x09, 0xff, 0x00, 0x00, 0x01, 0x06, 0x01, 0x0e, 0x00, 0x00, 0x01, 0x01, 0x00, 0x20,
-            ])
-            .await
-            .unwrap();
-        tokio::time::sleep(Duration::from_millis(1)).await;
+        device.start_battery_polling(Duration::from_secs(60));
+    }
 
-        let state = device.state().await;
-        let sound_modes = state.sound_modes.unwrap();
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnect_supervisor() {
+        tokio::task::LocalSet::new()
+            .run_until(async {
+                let (connection, sender) = create_test_connection().await;
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    sender.send(example_state_update_packet()).await.unwrap();
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    sender
+                        .send(example_firmware_version_packet())
+                        .await
+                        .unwrap();
+                });
+                let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+                    .await
+                    .unwrap();
+                let connection_registry = Rc::new(StubConnectionRegistry::new(HashMap::from([(
+                    GenericConnectionDescriptor::new("Soundcore Q30", MacAddr6::nil()),
+                    connection.to_owned(),
+                )])));
+                device.start_reconnect_supervisor(
+                    connection_registry,
+                    MacAddr6::nil(),
+                    ReconnectPolicy {
+                        delay: Duration::from_secs(1),
+                        backoff: Backoff::Constant,
+                        max_delay: Duration::from_secs(1),
+                        max_attempts: None,
+                    },
+                );
+                let mut state_receiver = device.subscribe_to_state_updates().await;
+                let connection_status = device.connection_status();
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                assert_eq!(ConnectionStatus::Connected, *connection_status.borrow());
 
-        assert_eq!(
-            AmbientSoundMode::NoiseCanceling,
-            sound_modes.ambient_sound_mode,
-        );
-        assert_eq!(
-            NoiseCancelingMode::Outdoor,
-            sound_modes.noise_canceling_mode,
-        );
+                connection.set_connection_status(ConnectionStatus::Disconnected);
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                assert_eq!(ConnectionStatus::Reconnecting, *connection_status.borrow());
+
+                let (sender, receiver) = mpsc::channel(100);
+                connection.set_inbound_packets_channel(Ok(receiver)).await;
+                connection.set_connection_status(ConnectionStatus::Connected);
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(1010)).await;
+                    sender.send(example_state_update_packet()).await.unwrap();
+                });
+                state_receiver.mark_unchanged();
+                tokio::time::sleep(Duration::from_millis(1100)).await;
+
+                assert_eq!(ConnectionStatus::Connected, *connection_status.borrow());
+                assert!(state_receiver.has_changed().unwrap());
+                assert_ne!(None, state_receiver.borrow().serial_number);
+            })
+            .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnect_supervisor_gives_up() {
+        tokio::task::LocalSet::new()
+            .run_until(async {
+                let (connection, sender) = create_test_connection().await;
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    sender.send(example_state_update_packet()).await.unwrap();
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    sender
+                        .send(example_firmware_version_packet())
+                        .await
+                        .unwrap();
+                });
+                let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+                    .await
+                    .unwrap();
+                // The device is no longer known to the registry, so every attempt fails
+                let connection_registry = Rc::new(StubConnectionRegistry::new(HashMap::new()));
+                device.start_reconnect_supervisor(
+                    connection_registry,
+                    MacAddr6::nil(),
+                    ReconnectPolicy {
+                        delay: Duration::from_secs(1),
+                        backoff: Backoff::Linear,
+                        max_delay: Duration::from_secs(10),
+                        max_attempts: Some(3),
+                    },
+                );
+                let connection_status = device.connection_status();
+                tokio::time::sleep(Duration::from_millis(1)).await;
+
+                connection.set_connection_status(ConnectionStatus::Disconnected);
+                tokio::time::sleep(Duration::from_millis(5999)).await;
+                assert_eq!(ConnectionStatus::Reconnecting, *connection_status.borrow());
+                tokio::time::sleep(Duration::from_millis(2)).await;
+                assert_eq!(ConnectionStatus::Disconnected, *connection_status.borrow());
+            })
+            .await;
     }
 
     #[tokio::test(start_paused = true)]
@@ -577,6 +1733,235 @@ mod tests {
         device.set_sound_modes(sound_modes).await.unwrap();
     }
 
+    fn ack(command: Command) -> Vec<u8> {
+        Packet {
+            command: command.to_inbound(),
+            body: Vec::new(),
+        }
+        .bytes()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_apply() {
+        let (connection, sender) = create_test_connection().await;
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender.send(example_state_update_packet()).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender
+                .send(example_firmware_version_packet())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            sender
+                .send(ack(SetSoundModePacket::default().command()))
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender.send(ack(SetEqualizerPacket::COMMAND)).await.unwrap();
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+            .await
+            .unwrap();
+        // let the packet handler catch up on the state update packet
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let sound_modes = SoundModes {
+            ambient_sound_mode: AmbientSoundMode::Transparency,
+            ..device.state().await.sound_modes.unwrap()
+        };
+        let equalizer_configuration = EqualizerConfiguration::new_custom_profile(
+            VolumeAdjustments::new([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]).unwrap(),
+        );
+
+        device
+            .apply(vec![
+                SettingChange::SoundModes(sound_modes),
+                SettingChange::EqualizerConfiguration(equalizer_configuration.to_owned()),
+            ])
+            .await
+            .unwrap();
+
+        let state = device.state().await;
+        assert_eq!(Some(sound_modes), state.sound_modes);
+        assert_eq!(equalizer_configuration, state.equalizer_configuration);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_apply_rolls_back_when_a_change_fails() {
+        let (connection, sender) = create_test_connection().await;
+        // request state update packet
+        connection.push_write_return(Ok(())).await;
+        // set sound modes
+        connection.push_write_return(Ok(())).await;
+        // set equalizer configuration
+        connection
+            .push_write_return(Err(crate::Error::MissingData {
+                name: "set equalizer configuration fails",
+            }))
+            .await;
+        // set equalizer configuration back, since it may have been partly applied
+        connection.push_write_return(Ok(())).await;
+        // set sound modes back
+        connection.push_write_return(Ok(())).await;
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender.send(example_state_update_packet()).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender
+                .send(example_firmware_version_packet())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(9)).await;
+            for command in [
+                SetSoundModePacket::default().command(),
+                SetEqualizerPacket::COMMAND,
+                SetSoundModePacket::default().command(),
+            ] {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                sender.send(ack(command)).await.unwrap();
+            }
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+            .await
+            .unwrap();
+        // let the packet handler catch up on the state update packet
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let initial_state = device.state().await;
+        let mut event_receiver = device.subscribe_to_events();
+
+        let result = device
+            .apply(vec![
+                SettingChange::SoundModes(SoundModes {
+                    ambient_sound_mode: AmbientSoundMode::Transparency,
+                    ..initial_state.sound_modes.unwrap()
+                }),
+                SettingChange::EqualizerConfiguration(EqualizerConfiguration::new_custom_profile(
+                    VolumeAdjustments::new([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]).unwrap(),
+                )),
+            ])
+            .await;
+
+        assert!(
+            matches!(
+                result,
+                Err(crate::Error::ApplyFailed {
+                    index: 1,
+                    setting: "EqualizerConfiguration",
+                    rolled_back: true,
+                    ..
+                })
+            ),
+            "{result:?}",
+        );
+        assert_eq!(0, connection.write_return_queue_length().await);
+        assert_eq!(initial_state, device.state().await);
+        // Nothing is published until the whole batch is done, and it ended where it started
+        assert!(event_receiver.try_recv().is_err());
+    }
+
+    fn example_state_update_packet_with_ambient_sound_mode(
+        ambient_sound_mode: AmbientSoundMode,
+    ) -> Vec<u8> {
+        let bytes = example_state_update_packet();
+        // Strip the header, length, and checksum
+        let mut body = bytes[9..bytes.len() - 1].to_vec();
+        body[35] = ambient_sound_mode.id();
+        Packet {
+            command: STATE_UPDATE,
+            body,
+        }
+        .bytes()
+    }
+
+    /// Creates a device that acknowledges `command`, and then responds to the state request with
+    /// the example state update packet using `verified_ambient_sound_mode`.
+    async fn create_verifying_device(
+        command: Command,
+        verified_ambient_sound_mode: AmbientSoundMode,
+    ) -> SoundcoreDevice<StubConnection, TokioFutures> {
+        let (connection, sender) = create_test_connection().await;
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender.send(example_state_update_packet()).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            sender.send(ack(command)).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            sender
+                .send(example_state_update_packet_with_ambient_sound_mode(
+                    verified_ambient_sound_mode,
+                ))
+                .await
+                .unwrap();
+        });
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection)
+            .await
+            .unwrap();
+        device.set_verify_writes(true);
+        // let the packet handler catch up on the state update packet
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        device
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_verify_writes() {
+        let device = create_verifying_device(
+            SetSoundModePacket::default().command(),
+            AmbientSoundMode::Transparency,
+        )
+        .await;
+        let sound_modes = SoundModes {
+            ambient_sound_mode: AmbientSoundMode::Transparency,
+            ..device.state().await.sound_modes.unwrap()
+        };
+
+        device.set_sound_modes(sound_modes).await.unwrap();
+
+        assert_eq!(Some(sound_modes), device.state().await.sound_modes);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_verify_writes_fails_when_device_ignores_change() {
+        let device = create_verifying_device(
+            SetSoundModePacket::default().command(),
+            AmbientSoundMode::Normal,
+        )
+        .await;
+        let initial_state = device.state().await;
+        let sound_modes = SoundModes {
+            ambient_sound_mode: AmbientSoundMode::Transparency,
+            ..initial_state.sound_modes.unwrap()
+        };
+
+        let result = device.set_sound_modes(sound_modes).await;
+
+        let Err(crate::Error::VerificationFailed { expected, actual }) = result else {
+            panic!("expected verification to fail: {result:?}");
+        };
+        assert_eq!(Some(sound_modes), expected.sound_modes);
+        assert_eq!(initial_state, *actual);
+        // The device's word is taken over what was sent
+        assert_eq!(initial_state, device.state().await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_verify_writes_compares_with_what_the_device_reports() {
+        // The equalizer configuration in state updates is not applied on top of the existing
+        // state, so it must still be compared with the one the device sent
+        let device =
+            create_verifying_device(SetEqualizerPacket::COMMAND, AmbientSoundMode::Normal).await;
+        let equalizer_configuration =
+            EqualizerConfiguration::new_custom_profile(VolumeAdjustments::new([1.0; 8]).unwrap());
+
+        let result = device
+            .set_equalizer_configuration(equalizer_configuration.to_owned())
+            .await;
+
+        let Err(crate::Error::VerificationFailed { expected, .. }) = result else {
+            panic!("expected verification to fail: {result:?}");
+        };
+        assert_eq!(equalizer_configuration, expected.equalizer_configuration);
+    }
+
     #[tokio::test(start_paused = true)]
     async fn test_set_equalizer_configuration_called_twice() {
         let (connection, sender) = create_test_connection().await;
@@ -635,24 +2020,54 @@ mod tests {
     }
 
     #[tokio::test(start_paused = true)]
-    async fn test_fetches_serial_number_when_not_included_in_state_update_packet() {
-        const A3931_STATE_UPDATE_PACKET_BYTES: [u8; 54] = [
-            0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01, // command
-            0, 54, // length
-            0, 0, // tws status
-            0, 0, 0, 0, // battery
-            0, 0, // eq preset
-            120, 120, 120, 120, 120, 120, 120, 120, // left eq config
-            120, 120, 120, 120, 120, 120, 120, 120, // right eq config
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // button config
-            0, 0, 0, 0, // sound modes
-            0, // side tone
-            0, // touch tone
-            0, // auto power off
-            0, // auto power off index
-            193,
-        ];
+    async fn test_set_eight_band_equalizer_configuration_on_ten_band_device_called_twice() {
+        let connection =
+            Arc::new(EmulatedConnection::new(DeviceModel::A3936, MacAddr6::nil()).unwrap());
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+            .await
+            .unwrap();
+        let equalizer_configuration = EqualizerConfiguration::new_custom_profile(
+            VolumeAdjustments::new([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]).unwrap(),
+        );
+        device
+            .set_equalizer_configuration(equalizer_configuration.to_owned())
+            .await
+            .unwrap();
+
+        let state = device.state().await;
+        assert_eq!(
+            10,
+            state
+                .equalizer_configuration
+                .volume_adjustments()
+                .adjustments()
+                .len()
+        );
+        assert!(device
+            .prepare_equalizer_configuration(state, equalizer_configuration)
+            .unwrap()
+            .is_none());
+    }
 
+    const A3931_STATE_UPDATE_PACKET_BYTES: [u8; 54] = [
+        0x09, 0xff, 0x00, 0x00, 0x01, 0x01, 0x01, // command
+        54, 0, // length
+        0, 0, // tws status
+        0, 0, 0, 0, // battery
+        0, 0, // eq preset
+        120, 120, 120, 120, 120, 120, 120, 120, // left eq config
+        120, 120, 120, 120, 120, 120, 120, 120, // right eq config
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // button config
+        0, 0, 0, 0, // sound modes
+        0, // side tone
+        0, // touch tone
+        0, // auto power off
+        0, // auto power off index
+        193,
+    ];
+
+    #[tokio::test(start_paused = true)]
+    async fn test_fetches_serial_number_when_not_included_in_state_update_packet() {
         let (connection, sender) = create_test_connection().await;
         // request firmware version packet
         connection.push_write_return(Ok(())).await;
@@ -679,4 +2094,43 @@ mod tests {
             .unwrap();
         assert_ne!(None, device.state().await.serial_number);
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_keeps_serial_number_when_not_included_in_state_update_packet() {
+        let (connection, sender) = create_test_connection().await;
+        // request firmware version packet
+        connection.push_write_return(Ok(())).await;
+        // request state update packet
+        connection.push_write_return(Ok(())).await;
+
+        {
+            let sender = sender.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                sender
+                    .send(A3931_STATE_UPDATE_PACKET_BYTES.to_vec())
+                    .await
+                    .unwrap();
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                sender
+                    .send(example_firmware_version_packet())
+                    .await
+                    .unwrap();
+            });
+        }
+        let device = SoundcoreDevice::<_, TokioFutures>::new(connection.to_owned())
+            .await
+            .unwrap();
+        let state = device.state().await;
+        assert_ne!(None, state.serial_number);
+
+        sender
+            .send(A3931_STATE_UPDATE_PACKET_BYTES.to_vec())
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        let new_state = device.state().await;
+        assert_eq!(state.serial_number, new_state.serial_number);
+        assert_eq!(state.dual_firmware_version, new_state.dual_firmware_version);
+    }
 }
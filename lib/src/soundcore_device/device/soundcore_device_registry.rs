@@ -1,6 +1,7 @@
 use std::{
     marker::PhantomData,
     rc::{Rc, Weak},
+    time::Duration,
 };
 
 use macaddr::MacAddr6;
@@ -15,55 +16,110 @@ use crate::{
     futures::Futures,
 };
 
-use super::soundcore_device::SoundcoreDevice;
+use super::{soundcore_device::SoundcoreDevice, ReconnectPolicy, RetryPolicy};
 
 pub struct SoundcoreDeviceRegistry<RegistryType, FuturesType>
 where
     RegistryType: ConnectionRegistry,
     FuturesType: Futures,
 {
-    conneciton_registry: RegistryType,
+    conneciton_registry: Rc<RegistryType>,
     devices: Mutex<
         WeakValueHashMap<
             MacAddr6,
             Weak<SoundcoreDevice<RegistryType::ConnectionType, FuturesType>>,
         >,
     >,
+    retry_policy: RetryPolicy,
+    reconnect_policy: Option<ReconnectPolicy>,
+    battery_polling_interval: Option<Duration>,
+    verify_writes: bool,
     futures: PhantomData<FuturesType>,
 }
 
 impl<RegistryType, FuturesType> SoundcoreDeviceRegistry<RegistryType, FuturesType>
 where
-    RegistryType: ConnectionRegistry,
-    FuturesType: Futures,
+    RegistryType: ConnectionRegistry + 'static,
+    RegistryType::ConnectionType: 'static,
+    FuturesType: Futures + 'static,
 {
     pub async fn new(connection_registry: RegistryType) -> crate::Result<Self> {
         Ok(Self {
-            conneciton_registry: connection_registry,
+            conneciton_registry: Rc::new(connection_registry),
             devices: Mutex::new(WeakValueHashMap::new()),
+            retry_policy: RetryPolicy::default(),
+            reconnect_policy: None,
+            battery_polling_interval: None,
+            verify_writes: false,
             futures: PhantomData,
         })
     }
 
+    /// Devices created by this registry will use `retry_policy`. Devices that were already created
+    /// are not affected.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Devices created by this registry will have a reconnect supervisor running, so that they
+    /// survive the connection being lost. See [`SoundcoreDevice::start_reconnect_supervisor`].
+    ///
+    /// The supervisor is started by [`device`](DeviceRegistry::device), so that must then be
+    /// called from somewhere that [`Futures::spawn_local`] works. With
+    /// [`TokioFutures`](crate::futures::TokioFutures), that means within a
+    /// [`tokio::task::LocalSet`], and it panics otherwise.
+    pub fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = Some(reconnect_policy);
+        self
+    }
+
+    /// Devices created by this registry will poll their battery every `interval`. See
+    /// [`SoundcoreDevice::start_battery_polling`], including where
+    /// [`device`](DeviceRegistry::device) must then be called from.
+    pub fn with_battery_polling(mut self, interval: Duration) -> Self {
+        self.battery_polling_interval = Some(interval);
+        self
+    }
+
+    /// Devices created by this registry will check that changes were actually applied. See
+    /// [`SoundcoreDevice::set_verify_writes`].
+    pub fn with_verify_writes(mut self, verify_writes: bool) -> Self {
+        self.verify_writes = verify_writes;
+        self
+    }
+
     async fn new_device(
         &self,
         mac_address: MacAddr6,
     ) -> crate::Result<Option<SoundcoreDevice<RegistryType::ConnectionType, FuturesType>>> {
         let connection = self.conneciton_registry.connection(mac_address).await?;
 
-        if let Some(connection) = connection {
-            SoundcoreDevice::new(connection).await.map(Option::Some)
-        } else {
-            Ok(None)
+        let Some(connection) = connection else {
+            return Ok(None);
+        };
+        let device = SoundcoreDevice::new_with_retry_policy(connection, self.retry_policy).await?;
+        device.set_verify_writes(self.verify_writes);
+        if let Some(reconnect_policy) = self.reconnect_policy {
+            device.start_reconnect_supervisor(
+                self.conneciton_registry.to_owned(),
+                mac_address,
+                reconnect_policy,
+            );
         }
+        if let Some(interval) = self.battery_polling_interval {
+            device.start_battery_polling(interval);
+        }
+        Ok(Some(device))
     }
 }
 
 impl<RegistryType, FuturesType> DeviceRegistry
     for SoundcoreDeviceRegistry<RegistryType, FuturesType>
 where
-    RegistryType: ConnectionRegistry,
-    FuturesType: Futures,
+    RegistryType: ConnectionRegistry + 'static,
+    RegistryType::ConnectionType: 'static,
+    FuturesType: Futures + 'static,
 {
     type DeviceType = SoundcoreDevice<RegistryType::ConnectionType, FuturesType>;
     type DescriptorType = GenericDeviceDescriptor;
@@ -106,12 +162,13 @@ mod tests {
 
     use crate::{
         api::{
-            connection::{ConnectionDescriptor, GenericConnectionDescriptor},
+            connection::{ConnectionDescriptor, ConnectionStatus, GenericConnectionDescriptor},
             device::{Device, DeviceDescriptor, DeviceRegistry},
         },
         devices::standard::packets::inbound::{FirmwareVersionUpdatePacket, InboundPacket},
+        emulator::EmulatedConnectionRegistry,
         futures::TokioFutures,
-        soundcore_device::device::Packet,
+        soundcore_device::device::{Packet, ReconnectPolicy},
         stub::connection::{StubConnection, StubConnectionRegistry},
     };
 
@@ -227,4 +284,92 @@ mod tests {
 
         assert!(maybe_device.is_none());
     }
+
+    #[test]
+    fn test_battery_polling_in_local_set() {
+        // Set up the same way as the cli
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .start_paused(true)
+            .build()
+            .unwrap();
+        tokio::task::LocalSet::new().block_on(&runtime, async {
+            let device_registry = SoundcoreDeviceRegistry::<_, TokioFutures>::new(
+                EmulatedConnectionRegistry::with_all_models(),
+            )
+            .await
+            .unwrap()
+            .with_battery_polling(Duration::from_secs(60));
+            let descriptor = device_registry
+                .device_descriptors()
+                .await
+                .unwrap()
+                .remove(0);
+            let device = device_registry
+                .device(descriptor.mac_address())
+                .await
+                .unwrap()
+                .unwrap();
+
+            tokio::time::sleep(Duration::from_secs(120)).await;
+            assert_eq!(
+                descriptor.mac_address(),
+                device.mac_address().await.unwrap()
+            );
+        });
+    }
+
+    async fn reconnecting_registry(
+    ) -> SoundcoreDeviceRegistry<EmulatedConnectionRegistry, TokioFutures> {
+        SoundcoreDeviceRegistry::new(EmulatedConnectionRegistry::with_all_models())
+            .await
+            .unwrap()
+            .with_reconnect_policy(ReconnectPolicy::default())
+    }
+
+    #[test]
+    fn test_reconnect_policy_in_local_set() {
+        // Set up the same way as the cli
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .start_paused(true)
+            .build()
+            .unwrap();
+        tokio::task::LocalSet::new().block_on(&runtime, async {
+            let device_registry = reconnecting_registry().await;
+            let descriptor = device_registry
+                .device_descriptors()
+                .await
+                .unwrap()
+                .remove(0);
+            let device = device_registry
+                .device(descriptor.mac_address())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                ConnectionStatus::Connected,
+                *device.connection_status().borrow()
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "LocalSet")]
+    fn test_reconnect_policy_requires_local_set_with_tokio() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .start_paused(true)
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let device_registry = reconnecting_registry().await;
+            let descriptor = device_registry
+                .device_descriptors()
+                .await
+                .unwrap()
+                .remove(0);
+            let _ = device_registry.device(descriptor.mac_address()).await;
+        });
+    }
 }
@@ -12,6 +12,7 @@ pub struct StubConnection {
     mac_address_return: RwLock<Option<crate::Result<MacAddr6>>>,
     write_return_queue: Mutex<VecDeque<crate::Result<()>>>,
     inbound_packets_channel: Mutex<Option<crate::Result<mpsc::Receiver<Vec<u8>>>>>,
+    connection_status_sender: watch::Sender<ConnectionStatus>,
 }
 
 impl StubConnection {
@@ -21,6 +22,7 @@ impl StubConnection {
             mac_address_return: RwLock::new(None),
             write_return_queue: Mutex::new(VecDeque::new()),
             inbound_packets_channel: Mutex::new(None),
+            connection_status_sender: watch::channel(ConnectionStatus::Connected).0,
         }
     }
 
@@ -51,6 +53,11 @@ impl StubConnection {
         let mut lock = self.inbound_packets_channel.lock().await;
         *lock = Some(receiver);
     }
+
+    pub fn set_connection_status(&self, connection_status: ConnectionStatus) {
+        self.connection_status_sender
+            .send_replace(connection_status);
+    }
 }
 
 impl Connection for StubConnection {
@@ -63,8 +70,7 @@ impl Connection for StubConnection {
     }
 
     fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
-        let (_sender, receiver) = watch::channel(ConnectionStatus::Connected);
-        receiver
+        self.connection_status_sender.subscribe()
     }
 
     fn service_uuid(&self) -> Uuid {
@@ -9,10 +9,12 @@ use openscq30_lib::{
         structures::{
             AdaptiveNoiseCanceling as LibAdaptiveNoiseCanceling,
             AmbientSoundMode as LibAmbientSoundMode,
-            AmbientSoundModeCycle as LibAmbientSoundModeCycle, BasicHearId as LibBasicHearId,
+            AmbientSoundModeCycle as LibAmbientSoundModeCycle, AutoPowerOff as LibAutoPowerOff,
+            AutoPowerOffDuration as LibAutoPowerOffDuration, BasicHearId as LibBasicHearId,
             Battery as LibBattery, ButtonAction as LibButtonAction,
             ButtonConfiguration as LibButtonConfiguration, CustomHearId as LibCustomHearId,
             CustomNoiseCanceling as LibCustomNoiseCanceling, DualBattery as LibDualBattery,
+            DualFirmwareVersion as LibDualFirmwareVersion,
             EqualizerConfiguration as LibEqualizerConfiguration,
             FirmwareVersion as LibFirmwareVersion, HearId as LibHearId,
             HearIdMusicType as LibHearIdMusicType, HearIdType as LibHearIdType,
@@ -23,7 +25,8 @@ use openscq30_lib::{
             PresetEqualizerProfile as LibPresetEqualizerProfile, SingleBattery as LibSingleBattery,
             SoundModes as LibSoundModes, SoundModesTypeTwo as LibSoundModesTypeTwo,
             StereoVolumeAdjustments as LibStereoVolumeAdjustments,
-            TransparencyMode as LibTransparencyMode, VolumeAdjustments,
+            TransparencyMode as LibTransparencyMode, VoicePrompt as LibVoicePrompt,
+            VolumeAdjustments,
         },
     },
 };
@@ -32,21 +35,86 @@ use crate::protobuf::*;
 
 impl From<LibDeviceState> for DeviceState {
     fn from(value: LibDeviceState) -> Self {
+        let firmware_version = value.firmware_version();
         Self {
             device_features: value.device_features.into(),
-            battery: value.battery.into(),
+            battery: Battery {
+                charging_case_level: value
+                    .charging_case_battery_level
+                    .map(|level| level.0.into()),
+                ..value.battery.into()
+            },
             equalizer_configuration: value.equalizer_configuration.into(),
+            right_equalizer_configuration: value.right_equalizer_configuration.map(Into::into),
             sound_modes: value.sound_modes.map(Into::into),
             age_range: value.age_range.map(|age_range| age_range.0.into()),
             gender: value.gender.map(|gender| gender.0.into()),
             hear_id: value.hear_id.map(Into::into),
-            firmware_version: value.firmware_version.map(Into::into),
+            firmware_version: firmware_version.map(Into::into),
+            dual_firmware_version: value.dual_firmware_version.map(Into::into),
             button_configuration: value.button_configuration.map(Into::into),
             serial_number: value
                 .serial_number
                 .map(|serial_number| serial_number.to_string()),
             ambient_sound_mode_cycle: value.ambient_sound_mode_cycle.map(Into::into),
             sound_modes_type_two: value.sound_modes_type_two.map(Into::into),
+            wear_detection: value.wear_detection,
+            touch_tone: value.touch_tone,
+            game_mode: value.game_mode,
+            ldac: value.ldac,
+            voice_prompt: value
+                .voice_prompt
+                .map(|voice_prompt| VoicePrompt::from(voice_prompt).into()),
+            auto_power_off: value.auto_power_off.map(Into::into),
+        }
+    }
+}
+
+impl From<LibVoicePrompt> for VoicePrompt {
+    fn from(value: LibVoicePrompt) -> Self {
+        match value {
+            LibVoicePrompt::English => Self::English,
+            LibVoicePrompt::Chinese => Self::Chinese,
+        }
+    }
+}
+
+impl From<LibAutoPowerOff> for AutoPowerOff {
+    fn from(value: LibAutoPowerOff) -> Self {
+        Self {
+            is_enabled: value.is_enabled,
+            duration: AutoPowerOffDuration::from(value.duration).into(),
+        }
+    }
+}
+
+impl From<AutoPowerOff> for LibAutoPowerOff {
+    fn from(value: AutoPowerOff) -> Self {
+        Self {
+            is_enabled: value.is_enabled,
+            duration: value.duration().into(),
+        }
+    }
+}
+
+impl From<LibAutoPowerOffDuration> for AutoPowerOffDuration {
+    fn from(value: LibAutoPowerOffDuration) -> Self {
+        match value {
+            LibAutoPowerOffDuration::ThirtyMinutes => Self::ThirtyMinutes,
+            LibAutoPowerOffDuration::SixtyMinutes => Self::SixtyMinutes,
+            LibAutoPowerOffDuration::NinetyMinutes => Self::NinetyMinutes,
+            LibAutoPowerOffDuration::OneHundredTwentyMinutes => Self::OneHundredTwentyMinutes,
+        }
+    }
+}
+
+impl From<AutoPowerOffDuration> for LibAutoPowerOffDuration {
+    fn from(value: AutoPowerOffDuration) -> Self {
+        match value {
+            AutoPowerOffDuration::ThirtyMinutes => Self::ThirtyMinutes,
+            AutoPowerOffDuration::SixtyMinutes => Self::SixtyMinutes,
+            AutoPowerOffDuration::NinetyMinutes => Self::NinetyMinutes,
+            AutoPowerOffDuration::OneHundredTwentyMinutes => Self::OneHundredTwentyMinutes,
         }
     }
 }
@@ -66,6 +134,9 @@ impl From<LibDeviceFeatures> for DeviceFeatures {
             dynamic_range_compression_min_firmware_version: value
                 .dynamic_range_compression_min_firmware_version
                 .map(Into::into),
+            has_game_mode: value.has_game_mode,
+            has_ldac: value.has_ldac,
+            has_voice_prompt: value.has_voice_prompt,
         }
     }
 }
@@ -127,14 +198,25 @@ impl From<LibFirmwareVersion> for FirmwareVersion {
     }
 }
 
+impl From<LibDualFirmwareVersion> for DualFirmwareVersion {
+    fn from(value: LibDualFirmwareVersion) -> Self {
+        Self {
+            left: value.left.into(),
+            right: value.right.into(),
+        }
+    }
+}
+
 impl From<LibBattery> for Battery {
     fn from(value: LibBattery) -> Self {
         match value {
             LibBattery::SingleBattery(battery) => Battery {
                 battery: Some(battery::Battery::SingleBattery(battery.into())),
+                charging_case_level: None,
             },
             LibBattery::DualBattery(battery) => Battery {
                 battery: Some(battery::Battery::DualBattery(battery.into())),
+                charging_case_level: None,
             },
         }
     }
@@ -1,8 +1,8 @@
 use openscq30_lib::devices::standard::{
     state::DeviceState,
     structures::{
-        AmbientSoundModeCycle, EqualizerConfiguration, HearId, MultiButtonConfiguration,
-        PresetEqualizerProfile, SoundModes, SoundModesTypeTwo,
+        AmbientSoundModeCycle, AutoPowerOff, EqualizerConfiguration, HearId,
+        MultiButtonConfiguration, PresetEqualizerProfile, SoundModes, SoundModesTypeTwo,
     },
 };
 use prost::{DecodeError, Message};
@@ -44,6 +44,14 @@ pub fn deserialize_ambient_sound_mode_cycle(
     protobuf::AmbientSoundModeCycle::decode(protobuf).map(Into::into)
 }
 
+pub fn serialize_auto_power_off(auto_power_off: AutoPowerOff) -> Vec<u8> {
+    protobuf::AutoPowerOff::from(auto_power_off).encode_to_vec()
+}
+
+pub fn deserialize_auto_power_off(protobuf: &[u8]) -> Result<AutoPowerOff, DecodeError> {
+    protobuf::AutoPowerOff::decode(protobuf).map(Into::into)
+}
+
 pub fn deserialize_equalizer_configuration(
     protobuf: &[u8],
 ) -> Result<EqualizerConfiguration, DecodeError> {
@@ -4,6 +4,7 @@ import {
   SoundcoreDeviceUtils,
 } from "../../wasm/pkg/openscq30_web_wasm";
 import {
+  AutoPowerOff,
   MultiButtonConfiguration,
   DeviceState,
   DeviceStateValidator,
@@ -14,9 +15,13 @@ import {
 import { UnmodifiableBehaviorSubject } from "../UnmodifiableBehaviorSubject";
 import { DeviceStateValidationError } from "./StateValidationError";
 
+export type ConnectionStatus = "connected" | "disconnected" | "reconnecting";
+
 export class Device {
   private libDevice: LibDevice;
   public readonly state: UnmodifiableBehaviorSubject<DeviceState>;
+  // "reconnecting" means the connection was lost but may come back, so the device should be kept
+  public readonly connectionStatus: UnmodifiableBehaviorSubject<ConnectionStatus>;
   public readonly name: string;
 
   public constructor(
@@ -38,6 +43,13 @@ export class Device {
         console.error(err);
       }
     });
+    const connectionStatusSubject = new BehaviorSubject<ConnectionStatus>(
+      "connected",
+    );
+    this.connectionStatus = connectionStatusSubject;
+    libDevice.setConnectionStatusListener((status: ConnectionStatus) => {
+      connectionStatusSubject.next(status);
+    });
   }
 
   public static async new(libDevice: LibDevice) {
@@ -62,10 +74,23 @@ export class Device {
     );
   }
 
+  public async setStereoEqualizerConfiguration(
+    left: EqualizerConfiguration,
+    right: EqualizerConfiguration,
+  ) {
+    await this.libDevice.setStereoEqualizerConfiguration(
+      JSON.stringify({ left, right }),
+    );
+  }
+
   public async setMultiButtonConfiguration(buttons: MultiButtonConfiguration) {
     await this.libDevice.setMultiButtonConfiguration(JSON.stringify(buttons));
   }
 
+  public async setAutoPowerOff(autoPowerOff: AutoPowerOff) {
+    await this.libDevice.setAutoPowerOff(JSON.stringify(autoPowerOff));
+  }
+
   public destroy() {
     this.libDevice.free();
   }
@@ -1,5 +1,5 @@
 import { Box, Container, Toolbar, useTheme } from "@mui/material";
-import { useCallback, useState } from "react";
+import { useCallback, useEffect, useState } from "react";
 import { useUpdateAvailableToast } from "../hooks/useUpdateAvailableToast";
 import { ConnectedAppBar } from "./ConnectedAppBar";
 import { DisconnectedAppBar } from "./DisconnectedAppBar";
@@ -44,6 +44,15 @@ export function AppContents() {
     setLoading(false);
   }, [device]);
 
+  useEffect(() => {
+    const subscription = device?.connectionStatus.subscribe((status) => {
+      if (status == "disconnected") {
+        disconnect();
+      }
+    });
+    return () => subscription?.unsubscribe();
+  }, [device, disconnect]);
+
   return (
     <>
       <Box
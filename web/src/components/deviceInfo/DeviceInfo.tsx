@@ -41,8 +41,13 @@ interface DeviceStateProps {
 function FirmwareVersion({ deviceState }: DeviceStateProps) {
   const { t } = useTranslation();
 
-  if (deviceState.firmwareVersion) {
-    const firmwareVersion = formatFirmwareVersion(deviceState.firmwareVersion);
+  if (deviceState.dualFirmwareVersion) {
+    const { left, right } = deviceState.dualFirmwareVersion;
+    // Models with a single firmware version report it for both sides
+    const firmwareVersion =
+      left.major == right.major && left.minor == right.minor
+        ? formatFirmwareVersion(left)
+        : `${formatFirmwareVersion(left)} / ${formatFirmwareVersion(right)}`;
     return (
       <TableRow>
         <TableCell>
@@ -57,7 +62,7 @@ function FirmwareVersion({ deviceState }: DeviceStateProps) {
 }
 
 function formatFirmwareVersion(
-  firmwareVersion: NonNullable<DeviceState["firmwareVersion"]>,
+  firmwareVersion: NonNullable<DeviceState["dualFirmwareVersion"]>["left"],
 ) {
   return `${firmwareVersion.major
     .toString()
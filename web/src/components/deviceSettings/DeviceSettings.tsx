@@ -1,5 +1,5 @@
 import { Masonry } from "@mui/lab";
-import { Box } from "@mui/material";
+import { Box, Stack, Typography } from "@mui/material";
 import { Dispatch, SetStateAction, useCallback, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { EqualizerHelper } from "../../../wasm/pkg/openscq30_web_wasm";
@@ -16,6 +16,7 @@ import {
 import { SoundModeSelection as SoundModeTypeTwoSelection } from "../soundModeTypeTwo/SoundModeSelection";
 import { ButtonSettings } from "../buttonSettings/ButtonSettings";
 import { DeviceInfo } from "../deviceInfo/DeviceInfo";
+import { Equalizer, PRESET_PROFILE_BANDS } from "../equalizer/Equalizer";
 import { EqualizerSettings } from "../equalizer/EqualizerSettings";
 import { NewCustomProfileDialog } from "../equalizer/NewCustomProfileDialog";
 import { ImportExport } from "../importExport/ImportExport";
@@ -134,6 +135,7 @@ function EqualizerSection({
   displayState: DeviceState;
   setDisplayState: Dispatch<SetStateAction<DeviceState>>;
 }) {
+  const { t } = useTranslation();
   const setSelectedPresetProfile = useCallback(
     (presetProfile: PresetEqualizerProfile | "custom") => {
       const newEqualizerConfiguration: EqualizerConfiguration =
@@ -144,6 +146,9 @@ function EqualizerSection({
                 ...EqualizerHelper.getPresetProfileVolumeAdjustments(
                   presetProfile,
                 ),
+                ...displayState.equalizerConfiguration.volumeAdjustments.slice(
+                  PRESET_PROFILE_BANDS,
+                ),
               ],
             }
           : {
@@ -178,6 +183,28 @@ function EqualizerSection({
     [setDisplayState],
   );
 
+  const setRightEqualizerValue = useCallback(
+    (changedIndex: number, newVolume: number) => {
+      setDisplayState((state) => {
+        if (!state.rightEqualizerConfiguration) {
+          return state;
+        }
+        const volumeAdjustments =
+          state.rightEqualizerConfiguration.volumeAdjustments.map(
+            (volume, index) => (index == changedIndex ? newVolume : volume),
+          );
+        return {
+          ...state,
+          rightEqualizerConfiguration: {
+            presetProfile: null,
+            volumeAdjustments: volumeAdjustments,
+          },
+        };
+      });
+    },
+    [setDisplayState],
+  );
+
   const openCreateCustomProfileDialog = useCallback(
     () => setCreateCustomProfileDialogOpen(true),
     [],
@@ -211,6 +238,19 @@ function EqualizerSection({
           onAddCustomProfile={openCreateCustomProfileDialog}
           onDeleteCustomProfile={deleteCustomProfile}
         />
+        {displayState.rightEqualizerConfiguration && (
+          <Stack spacing={2}>
+            <Typography component="h3" variant="subtitle1">
+              {t("equalizer.rightChannel")}
+            </Typography>
+            <Equalizer
+              values={
+                displayState.rightEqualizerConfiguration.volumeAdjustments
+              }
+              onValueChange={setRightEqualizerValue}
+            />
+          </Stack>
+        )}
         <NewCustomProfileDialog
           isOpen={isCreateCustomProfileDialogOpen}
           existingProfiles={customEqualizerProfiles}
@@ -40,6 +40,7 @@ function useUpdateDisplayFromActual(
     setDisplayState((state) => ({
       ...actualState,
       equalizerConfiguration: state.equalizerConfiguration,
+      rightEqualizerConfiguration: state.rightEqualizerConfiguration,
     }));
   }, [actualState, setDisplayState]);
 }
@@ -54,11 +55,26 @@ function useUpdateActualFromDisplay(
   // directly. Instead, pass the device. That way we will have a reference to the current actual state.
   const debouncedSetActualEqualizerConfiguration = useMemo(
     () =>
-      debounce((equalizerConfiguration: EqualizerConfiguration) => {
-        device
-          .setEqualizerConfiguration(equalizerConfiguration)
-          .catch(onBluetoothError);
-      }, 500),
+      debounce(
+        (
+          equalizerConfiguration: EqualizerConfiguration,
+          rightEqualizerConfiguration: EqualizerConfiguration | null,
+        ) => {
+          if (rightEqualizerConfiguration) {
+            device
+              .setStereoEqualizerConfiguration(
+                equalizerConfiguration,
+                rightEqualizerConfiguration,
+              )
+              .catch(onBluetoothError);
+          } else {
+            device
+              .setEqualizerConfiguration(equalizerConfiguration)
+              .catch(onBluetoothError);
+          }
+        },
+        500,
+      ),
     [device, onBluetoothError],
   );
 
@@ -66,9 +82,11 @@ function useUpdateActualFromDisplay(
   useEffect(() => {
     debouncedSetActualEqualizerConfiguration(
       displayState.equalizerConfiguration,
+      displayState.rightEqualizerConfiguration,
     );
   }, [
     displayState.equalizerConfiguration,
+    displayState.rightEqualizerConfiguration,
     debouncedSetActualEqualizerConfiguration,
   ]);
 
@@ -2,6 +2,10 @@ import React from "react";
 import { VolumeSlider } from "./VolumeSlider";
 import { Grid2 } from "@mui/material";
 
+// Preset profiles only cover the first 8 bands, so devices with more than that
+// keep their own values for the rest.
+export const PRESET_PROFILE_BANDS = 8;
+
 interface Props {
   disabled?: boolean;
   values: number[];
@@ -30,8 +34,13 @@ export const Equalizer = React.memo(function (props: Props) {
           <VolumeSlider
             disabled={props.disabled}
             key={index}
-            // Starts at 100hz
-            hz={100 * Math.pow(2, index)}
+            // Starts at 100hz. The official app only displays 8 bands, so the
+            // frequencies of any past that are unknown.
+            hz={
+              index < PRESET_PROFILE_BANDS
+                ? 100 * Math.pow(2, index)
+                : undefined
+            }
             value={value}
             onValueChange={onValueChange}
             index={index}
@@ -4,7 +4,7 @@ import { useTranslation } from "react-i18next";
 import { EqualizerHelper } from "../../../wasm/pkg/openscq30_web_wasm";
 
 interface Props {
-  hz: number;
+  hz?: number;
   disabled?: boolean;
   value: number;
   onValueChange: (index: number, newValue: number) => void;
@@ -13,7 +13,10 @@ interface Props {
 
 export const VolumeSlider = React.memo(function (props: Props) {
   const { t } = useTranslation();
-  const labelId = `${props.hz}-hz-label`;
+  const labelId =
+    props.hz != undefined
+      ? `${props.hz}-hz-label`
+      : `band-${props.index + 1}-label`;
   const step = 0.1;
   const minVolume = EqualizerHelper.MIN_VOLUME;
   const maxVolume = EqualizerHelper.MAX_VOLUME;
@@ -24,15 +27,20 @@ export const VolumeSlider = React.memo(function (props: Props) {
       <Grid2 size={{ xs: 3, sm: 2 }}>
         <div id={labelId}>
           <Typography>
-            {props.hz >= 1000
-              ? t("equalizer.khz", {
-                  defaultValue: "{{ hz }} kHz",
-                  replace: { hz: props.hz / 1000 },
+            {props.hz == undefined
+              ? t("equalizer.band", {
+                  defaultValue: "Band {{ band }}",
+                  replace: { band: props.index + 1 },
                 })
-              : t("equalizer.hz", {
-                  defaultValue: "{{ hz }} Hz",
-                  replace: { hz: props.hz },
-                })}
+              : props.hz >= 1000
+                ? t("equalizer.khz", {
+                    defaultValue: "{{ hz }} kHz",
+                    replace: { hz: props.hz / 1000 },
+                  })
+                : t("equalizer.hz", {
+                    defaultValue: "{{ hz }} Hz",
+                    replace: { hz: props.hz },
+                  })}
           </Typography>
         </div>
       </Grid2>
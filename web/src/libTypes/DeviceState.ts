@@ -137,6 +137,17 @@ export type MultiButtonConfiguration = Static<
   typeof multiButtonConfigurationSchema
 >;
 
+const autoPowerOffSchema = Type.Object({
+  isEnabled: Type.Boolean(),
+  duration: Type.Union([
+    Type.Literal("thirtyMinutes"),
+    Type.Literal("sixtyMinutes"),
+    Type.Literal("ninetyMinutes"),
+    Type.Literal("oneHundredTwentyMinutes"),
+  ]),
+});
+export type AutoPowerOff = Static<typeof autoPowerOffSchema>;
+
 const firmwareVersionSchema = Type.Object({
   major: Type.Number({ minimum: 0 }),
   minor: Type.Number({ minimum: 0 }),
@@ -182,6 +193,7 @@ const deviceStateSchema = Type.Object({
     }),
   ]),
   equalizerConfiguration: equalizerConfigurationSchema,
+  rightEqualizerConfiguration: Nullable(equalizerConfigurationSchema),
   soundModes: Nullable(soundModesSchema),
   soundModesTypeTwo: Nullable(soundModesTypeTwoSchema),
   ageRange: Nullable(Type.Number()),
@@ -216,10 +228,16 @@ const deviceStateSchema = Type.Object({
       }),
     ]),
   ),
-  firmwareVersion: Nullable(firmwareVersionSchema),
+  dualFirmwareVersion: Nullable(
+    Type.Object({
+      left: firmwareVersionSchema,
+      right: firmwareVersionSchema,
+    }),
+  ),
   buttonConfiguration: Nullable(multiButtonConfigurationSchema),
   serialNumber: Nullable(Type.String()),
   ambientSoundModeCycle: Nullable(ambientSoundModeCycleSchema),
+  autoPowerOff: Nullable(autoPowerOffSchema),
 });
 export type DeviceState = Static<typeof deviceStateSchema>;
 export const DeviceStateValidator = TypeCompiler.Compile(deviceStateSchema);
@@ -2,7 +2,7 @@ import { render } from "@testing-library/react";
 import userEvent from "@testing-library/user-event";
 import { BehaviorSubject } from "rxjs";
 import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
-import { Device } from "../../src/bluetooth/Device";
+import { ConnectionStatus, Device } from "../../src/bluetooth/Device";
 import App from "../../src/components/App";
 import {
   DeviceState,
@@ -19,6 +19,9 @@ describe("App", () => {
       return {
         async selectDevice() {
           const mockDevice = {
+            connectionStatus: new BehaviorSubject<ConnectionStatus>(
+              "connected",
+            ),
             state: new BehaviorSubject<DeviceState>({
               deviceFeatures: {
                 availableSoundModes: {
@@ -63,9 +66,10 @@ describe("App", () => {
               gender: null,
               buttonConfiguration: null,
               hearId: null,
-              firmwareVersion: null,
+              dualFirmwareVersion: null,
               serialNumber: null,
               ambientSoundModeCycle: null,
+              autoPowerOff: null,
             }),
             connect: vi.fn<() => void>(),
             async setSoundModes(soundModes: SoundModes) {
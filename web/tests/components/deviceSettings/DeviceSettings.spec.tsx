@@ -84,9 +84,10 @@ describe("Device Settings", () => {
         gender: null,
         buttonConfiguration: null,
         hearId: null,
-        firmwareVersion: null,
+        dualFirmwareVersion: null,
         serialNumber: null,
         ambientSoundModeCycle: null,
+        autoPowerOff: null,
       }),
       connect: vi.fn<() => void>(),
       async setSoundModes(soundModes: SoundModes) {
@@ -0,0 +1,19 @@
+import { render } from "@testing-library/react";
+import { describe, expect, it } from "vitest";
+import { Equalizer } from "../../../src/components/equalizer/Equalizer";
+
+describe("Equalizer", () => {
+  it("should label bands past the 8th by number", () => {
+    const renderResult = render(
+      <Equalizer
+        values={[0, 0, 0, 0, 0, 0, 0, 0, 0, 0]}
+        // eslint-disable-next-line @typescript-eslint/no-empty-function
+        onValueChange={() => {}}
+      />,
+    );
+    expect(renderResult.getAllByText("equalizer.hz")).toHaveLength(4);
+    expect(renderResult.getAllByText("equalizer.khz")).toHaveLength(4);
+    expect(renderResult.getAllByText("equalizer.band")).toHaveLength(2);
+    expect(renderResult.getAllByRole("slider")).toHaveLength(10);
+  });
+});
@@ -3,12 +3,13 @@ use std::sync::Arc;
 use js_sys::Function;
 use macaddr::MacAddr6;
 use openscq30_lib::{
-    api::device::Device as _,
+    api::{connection::ConnectionStatus, device::Device as _},
     demo::device::DemoDevice,
     devices::standard::{
         state::DeviceState,
         structures::{
-            EqualizerConfiguration, MultiButtonConfiguration, SoundModes, SoundModesTypeTwo,
+            AutoPowerOff, EqualizerConfiguration, MultiButtonConfiguration, SoundModes,
+            SoundModesTypeTwo, StereoEqualizerConfiguration,
         },
     },
     futures::WasmFutures,
@@ -89,6 +90,20 @@ impl Device {
         Ok(())
     }
 
+    #[wasm_bindgen(js_name = "setStereoEqualizerConfiguration")]
+    pub async fn set_stereo_equalizer_configuration(
+        &self,
+        configuration: String,
+    ) -> Result<(), JsValue> {
+        let configuration: StereoEqualizerConfiguration =
+            serde_json::from_str(&configuration).map_err(|err| format!("{err:?}"))?;
+        self.inner
+            .set_stereo_equalizer_configuration(configuration)
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+        Ok(())
+    }
+
     #[wasm_bindgen(js_name = "setMultiButtonConfiguration")]
     pub async fn set_multi_button_configuration(
         &self,
@@ -103,6 +118,17 @@ impl Device {
         Ok(())
     }
 
+    #[wasm_bindgen(js_name = "setAutoPowerOff")]
+    pub async fn set_auto_power_off(&self, auto_power_off: String) -> Result<(), JsValue> {
+        let auto_power_off: AutoPowerOff =
+            serde_json::from_str(&auto_power_off).map_err(|err| format!("{err:?}"))?;
+        self.inner
+            .set_auto_power_off(auto_power_off)
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+        Ok(())
+    }
+
     #[wasm_bindgen(js_name = "getState")]
     pub async fn state(&self) -> Result<String, JsValue> {
         let state = self.inner.state().await;
@@ -123,6 +149,23 @@ impl Device {
             }
         })
     }
+
+    #[wasm_bindgen(js_name = "setConnectionStatusListener")]
+    pub fn set_connection_status_listener(&self, callback: Function) {
+        let mut receiver = self.inner.connection_status();
+        wasm_bindgen_futures::spawn_local(async move {
+            while let Ok(()) = receiver.changed().await {
+                let connection_status = match *receiver.borrow_and_update() {
+                    ConnectionStatus::Connected => "connected",
+                    ConnectionStatus::Disconnected => "disconnected",
+                    ConnectionStatus::Reconnecting => "reconnecting",
+                };
+                callback
+                    .call1(&JsValue::null(), &connection_status.into())
+                    .expect("error handling should be done in javascript");
+            }
+        })
+    }
 }
 
 // Dynamic dispatch does not work with async functions in traits
@@ -139,6 +182,13 @@ impl DeviceImplementation {
         }
     }
 
+    pub fn connection_status(&self) -> watch::Receiver<ConnectionStatus> {
+        match self {
+            DeviceImplementation::WebBluetooth(device) => device.connection_status(),
+            DeviceImplementation::Demo(device) => device.connection_status(),
+        }
+    }
+
     pub async fn name(&self) -> openscq30_lib::Result<String> {
         match self {
             DeviceImplementation::WebBluetooth(device) => device.name().await,
@@ -188,6 +238,24 @@ impl DeviceImplementation {
         }
     }
 
+    pub async fn set_stereo_equalizer_configuration(
+        &self,
+        configuration: StereoEqualizerConfiguration,
+    ) -> openscq30_lib::Result<()> {
+        match self {
+            DeviceImplementation::WebBluetooth(device) => {
+                device
+                    .set_stereo_equalizer_configuration(configuration)
+                    .await
+            }
+            DeviceImplementation::Demo(device) => {
+                device
+                    .set_stereo_equalizer_configuration(configuration)
+                    .await
+            }
+        }
+    }
+
     pub async fn set_multi_button_configuration(
         &self,
         button_configuration: MultiButtonConfiguration,
@@ -205,4 +273,16 @@ impl DeviceImplementation {
             }
         }
     }
+
+    pub async fn set_auto_power_off(
+        &self,
+        auto_power_off: AutoPowerOff,
+    ) -> openscq30_lib::Result<()> {
+        match self {
+            DeviceImplementation::WebBluetooth(device) => {
+                device.set_auto_power_off(auto_power_off).await
+            }
+            DeviceImplementation::Demo(device) => device.set_auto_power_off(auto_power_off).await,
+        }
+    }
 }